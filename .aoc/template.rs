@@ -1,13 +1,23 @@
+use crate::solver::Solver;
+
 type ParsedInput = ();
 
-pub fn parse(_input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(())
-}
+pub struct Solution;
 
-pub fn part1(_: &ParsedInput) -> color_eyre::Result<u32> {
-    Ok(0)
-}
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(_input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(())
+    }
+
+    fn part1(_: &ParsedInput) -> color_eyre::Result<u32> {
+        Ok(0)
+    }
 
-pub fn part2(_: &ParsedInput) -> color_eyre::Result<u32> {
-    Ok(0)
+    fn part2(_: &ParsedInput) -> color_eyre::Result<u32> {
+        Ok(0)
+    }
 }
@@ -0,0 +1,71 @@
+use std::{
+    env,
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+// Generates the yearYYYY modules and the puzzle registry from the files in src/yearYYYY, so adding
+// a day only needs src/yearYYYY/dayDD.rs with a `Solution` that implements `Solver`
+fn main() -> io::Result<()> {
+    println!("cargo:rerun-if-changed=src");
+
+    let src_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    let mut years = String::new();
+    let mut puzzles = String::from("[\n");
+
+    for (year_name, year_dir) in sorted_entries(&src_dir, "year")? {
+        if !year_dir.is_dir() {
+            continue;
+        }
+
+        let year: u32 = year_name["year".len()..].parse().unwrap();
+
+        writeln!(years, "pub mod {year_name} {{").unwrap();
+        for (module, path) in sorted_entries(&year_dir, "")? {
+            let Some(module) = module.strip_suffix(".rs") else {
+                continue;
+            };
+
+            writeln!(years, "    #[path = {:?}]", path.display().to_string()).unwrap();
+            writeln!(years, "    pub mod {module};").unwrap();
+
+            if let Some(day) = module
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u32>().ok())
+            {
+                writeln!(
+                    puzzles,
+                    "    Puzzle::new::<crate::{year_name}::{module}::Solution>({year}, {day}),"
+                )
+                .unwrap();
+            }
+        }
+        writeln!(years, "}}").unwrap();
+    }
+
+    puzzles.push(']');
+
+    fs::write(out_dir.join("years.rs"), years)?;
+    fs::write(out_dir.join("puzzles.rs"), puzzles)?;
+
+    Ok(())
+}
+
+fn sorted_entries(dir: &Path, prefix: &str) -> io::Result<Vec<(String, PathBuf)>> {
+    let mut entries = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if name.starts_with(prefix) {
+            entries.push((name, entry.path()));
+        }
+    }
+
+    entries.sort();
+    Ok(entries)
+}
@@ -0,0 +1,230 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A puzzle's answer. Most are numbers, some are text, and a few are pictures drawn on a grid that
+/// have to be read by eye
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    Bitmap(Bitmap),
+}
+
+impl Answer {
+    // Whether this is the same answer as one written down as text, e.g. an accepted answer. Numbers
+    // are compared by value, and pictures by which pixels are lit whichever characters were used
+    pub fn matches(&self, text: &str) -> bool {
+        match self {
+            Self::Int(value) => text.trim().parse::<i128>() == Ok(*value),
+            Self::Str(value) => value == text,
+            Self::Bitmap(bitmap) => text.parse::<Bitmap>().is_ok_and(|text| text == *bitmap),
+        }
+    }
+
+    // A single line version for table cells. Pictures don't fit, so they're just described
+    pub fn cell(&self) -> String {
+        match self {
+            Self::Bitmap(bitmap) => format!("{}x{} bitmap", bitmap.width(), bitmap.height()),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Str(value) => write!(f, "{value}"),
+            Self::Bitmap(bitmap) => write!(f, "{bitmap}"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Self::Int(i128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, i64, i128, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Int(value as i128)
+    }
+}
+
+// Anything too big for an i128 is kept as its digits
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value).map_or_else(|_| Self::Str(value.to_string()), Self::Int)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Str(String::from(value))
+    }
+}
+
+impl From<Bitmap> for Answer {
+    fn from(value: Bitmap) -> Self {
+        Self::Bitmap(value)
+    }
+}
+
+// Written as the plain value, with pictures as a list of rows, e.g. 42, "abc" or ["#..", ".#."]
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Int(value) => serializer.serialize_i128(*value),
+            Self::Str(value) => serializer.serialize_str(value),
+            Self::Bitmap(bitmap) => serializer.collect_seq(bitmap.lines()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl<'de> de::Visitor<'de> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "an integer, a string or a list of bitmap rows")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Answer, E> {
+                Ok(Answer::from(value))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Answer, E> {
+                Ok(Answer::from(value))
+            }
+
+            fn visit_i128<E: de::Error>(self, value: i128) -> Result<Answer, E> {
+                Ok(Answer::from(value))
+            }
+
+            fn visit_u128<E: de::Error>(self, value: u128) -> Result<Answer, E> {
+                Ok(Answer::from(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Answer, E> {
+                Ok(Answer::from(value))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Answer, A::Error> {
+                let mut lines = Vec::new();
+                while let Some(line) = seq.next_element::<String>()? {
+                    lines.push(line);
+                }
+                lines
+                    .join("\n")
+                    .parse()
+                    .map(Answer::Bitmap)
+                    .map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+/// A picture made of lit and unlit pixels, drawn with '#' and '.'
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bitmap {
+    rows: Vec<Vec<bool>>,
+}
+
+impl Bitmap {
+    // Blank rows and columns around the edges are dropped, so pictures compare equal however much
+    // space was left around them
+    pub fn new(mut rows: Vec<Vec<bool>>) -> Self {
+        let top = rows
+            .iter()
+            .position(|row| row.contains(&true))
+            .unwrap_or(rows.len());
+        rows.drain(..top);
+        while rows.last().is_some_and(|row| !row.contains(&true)) {
+            rows.pop();
+        }
+
+        let left = rows
+            .iter()
+            .filter_map(|row| row.iter().position(|&lit| lit))
+            .min()
+            .unwrap_or(0);
+        let width = rows
+            .iter()
+            .filter_map(|row| row.iter().rposition(|&lit| lit))
+            .max()
+            .map_or(0, |last| last + 1);
+        for row in &mut rows {
+            row.resize(width, false);
+            row.drain(..left);
+        }
+
+        Self { rows }
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn rows(&self) -> &[Vec<bool>] {
+        &self.rows
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.rows
+            .iter()
+            .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+    }
+}
+
+impl Display for Bitmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.lines().collect::<Vec<_>>().join("\n"))
+    }
+}
+
+// Lit pixels can be '#' or '█', and unlit ones '.' or ' '
+impl FromStr for Bitmap {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|pixel| match pixel {
+                        '#' | '█' => Ok(true),
+                        '.' | ' ' => Ok(false),
+                        _ => Err(format!("'{pixel}' isn't a bitmap pixel")),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(rows))
+    }
+}
@@ -20,6 +20,8 @@ pub enum AdventError {
     UnexpectedValue(String, String),
     #[error("Unknown pattern: {0}")]
     UnknownPattern(String),
+    #[error("Unrecognised letters:\n{}", .0.join("\n"))]
+    UnknownGlyphs(Vec<String>),
     #[error("Failed to solve puzzle: {0}")]
     LogicError(String),
 }
@@ -1,78 +1,30 @@
+pub mod answer;
 pub mod error;
+pub mod registry;
+pub mod solver;
+
+pub mod runner {
+    pub mod answers;
+    pub mod bench;
+    pub mod client;
+    pub mod examples;
+    pub mod input;
+    pub mod output;
+    pub mod pool;
+    pub mod scaffold;
+    pub mod select;
+    pub mod solve;
+    pub mod submit;
+    pub mod worker;
+}
 
 pub mod util {
     pub mod direction;
     pub mod grid;
+    pub mod ocr;
     pub mod point;
+    pub mod pos;
 }
 
-pub mod year2019 {
-    pub mod day01;
-    pub mod day02;
-    pub mod day03;
-    pub mod day04;
-    pub mod day05;
-    pub mod day06;
-    pub mod day07;
-    pub mod day08;
-    pub mod day09;
-    pub mod day10;
-    pub mod day11;
-    pub mod int_code_computer;
-    pub mod op_code;
-}
-
-pub mod year2023 {
-    pub mod day01;
-    pub mod day02;
-    pub mod day03;
-    pub mod day04;
-    pub mod day05;
-    pub mod day06;
-    pub mod day07;
-    pub mod day08;
-    pub mod day09;
-    pub mod day10;
-    pub mod day11;
-    pub mod day12;
-    pub mod day13;
-    pub mod day14;
-    pub mod day15;
-    pub mod day16;
-    pub mod day17;
-    pub mod day18;
-    pub mod day19;
-    pub mod day20;
-    pub mod day21;
-    pub mod day22;
-    pub mod day23;
-}
-
-pub mod year2024 {
-    pub mod day01;
-    pub mod day02;
-    pub mod day03;
-    pub mod day04;
-    pub mod day05;
-    pub mod day06;
-    pub mod day07;
-    pub mod day08;
-    pub mod day09;
-    pub mod day10;
-    pub mod day11;
-    pub mod day12;
-    pub mod day13;
-    pub mod day14;
-    pub mod day15;
-    pub mod day16;
-    pub mod day17;
-    pub mod day18;
-    pub mod day19;
-    pub mod day20;
-    pub mod day21;
-}
-
-pub mod year2025 {
-    pub mod day01;
-    // NEXT
-}
+// The yearYYYY modules are generated by build.rs from the files in src/yearYYYY
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
@@ -1,272 +1,531 @@
-use std::{iter::empty, time::Instant};
-
-use advent_of_code::*;
-use clap::Parser;
-use colored::{ColoredString, Colorize};
+use std::{
+    env, fs,
+    ops::ControlFlow,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{
+    answer::Answer,
+    registry::{self, Puzzle},
+    runner::{
+        answers::{AnswerStore, Verdict},
+        bench::Baseline,
+        client::{Client, Config},
+        examples,
+        input::{fetch_input, read_input},
+        output::{BenchRecord, Formatter, OutputFormat, Record, Summary},
+        pool::run_ordered,
+        scaffold::{Scaffold, Status},
+        select::{select, select_days, Selector},
+        solve::Part,
+        submit::{parse_response, Response, SubmissionLog},
+        worker::{run_worker, Outcome, Progress, Worker},
+    },
+    year2019::{
+        int_code_ascii::run_interactive,
+        int_code_computer::{IcProgram, IntCodeComputer, RunState},
+        int_code_debugger::run_repl,
+        int_code_profile::Profile,
+    },
+};
+use clap::{Parser, Subcommand};
 use simple_logger::SimpleLogger;
 
 #[derive(Parser, Debug)]
 struct Args {
-    /// Run specific puzzle(s), e.g "year2023" or "year2023::day01"
+    /// Run specific puzzle(s), e.g. "2023", "2023:1-10", "2024:5,7,9", "*:25" or "2023/5". Can be
+    /// given more than once
     #[arg(short, long)]
-    puzzle: Option<String>,
+    puzzle: Vec<Selector>,
+
+    /// Only solve one part of each puzzle
+    #[arg(long, value_enum)]
+    part: Option<Part>,
 
     /// Enable debug output
     #[arg(short, long)]
     debug: bool,
+
+    /// Directory containing puzzle inputs, laid out as <dir>/<year>/<day>.txt
+    #[arg(long, default_value = "input")]
+    input_dir: PathBuf,
+
+    /// Read the input for a single puzzle from this file instead, or "-" for stdin
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t)]
+    format: OutputFormat,
+
+    /// Check the answers against the accepted answers in the answers directory
+    #[arg(long, conflicts_with = "bench")]
+    verify: bool,
+
+    /// Store the answers as the accepted answers in the answers directory
+    #[arg(long)]
+    record: bool,
+
+    /// Directory containing the accepted answers, laid out as <dir>/<year>.toml
+    #[arg(long, default_value = "answers")]
+    answers_dir: PathBuf,
+
+    /// Benchmark each puzzle by timing N runs of every stage after a warm-up run
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Baseline file to compare benchmarks against
+    #[arg(long, default_value = "bench_baseline.json")]
+    baseline: PathBuf,
+
+    /// Save the benchmark medians to the baseline file
+    #[arg(long, requires = "bench")]
+    save_baseline: bool,
+
+    /// Flag stages whose median is this many percent slower than the baseline
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    regression_threshold: f32,
+
+    /// Number of puzzles to solve in parallel
+    #[arg(short, long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: u32,
+
+    /// Stop any stage that runs for longer than this, overriding each puzzle's own timeout
+    #[arg(long, value_name = "SECS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Config file with the session token for the Advent of Code site
+    #[arg(long, default_value = ".aoc/config.toml")]
+    config: PathBuf,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Create a new day's solution, test and input placeholder from the templates in .aoc, e.g.
+    /// "2025/2"
+    Scaffold {
+        puzzle: Selector,
+        /// Saved puzzle description page to fill in the test's examples from
+        #[arg(long, value_name = "HTML")]
+        page: Option<PathBuf>,
+    },
+    /// Download the inputs of the given puzzles into the input directory, skipping any that have
+    /// already been downloaded
+    Fetch {
+        #[arg(required = true)]
+        puzzles: Vec<Selector>,
+    },
+    /// Solve one part of a puzzle and submit the answer, unless it's already known to be wrong
+    Submit {
+        puzzle: Selector,
+        #[arg(long, value_enum)]
+        part: Part,
+    },
+    /// Step through an Intcode program in an interactive debugger, e.g.
+    /// "input/year2019/day09.txt"
+    Intcode {
+        program: PathBuf,
+        /// Play the program through its ASCII interface instead, one line of input at a time
+        #[arg(long)]
+        ascii: bool,
+    },
+    /// Run an Intcode program until it halts or runs out of input, then report which of its
+    /// instructions and loops were run the most
+    Profile {
+        program: PathBuf,
+        /// Values for the program to read, e.g. "1,-2,3"
+        #[arg(long, value_delimiter = ',', allow_negative_numbers = true)]
+        input: Vec<i64>,
+        /// Write every instruction that's run to the file, along with its operands and result
+        #[arg(long, value_name = "FILE")]
+        trace: Option<PathBuf>,
+        /// How many of the hottest addresses and loops to list
+        #[arg(long, value_name = "N", default_value_t = 10)]
+        top: usize,
+    },
+    /// Solve a single puzzle with its input on stdin, reporting back to the process that started it
+    #[command(hide = true)]
+    Worker {
+        year: u32,
+        day: u32,
+        #[arg(long)]
+        bench: Option<u32>,
+        #[arg(long, value_enum)]
+        part: Option<Part>,
+    },
 }
 
 fn main() -> color_eyre::eyre::Result<()> {
     color_eyre::install()?;
-    let (year, day, debug_enabled) = parse_args();
+    let args = Args::parse();
+    let debug_enabled = args.debug;
+
+    if let Some(Command::Worker {
+        year,
+        day,
+        bench,
+        part,
+    }) = args.command
+    {
+        if debug_enabled {
+            SimpleLogger::new()
+                .with_level(log::LevelFilter::Debug)
+                .init()?;
+        }
+        let puzzle = registry::find(year, day)
+            .ok_or_else(|| color_eyre::eyre::eyre!("No such puzzle: {year}::{day}"))?;
+        return run_worker(puzzle, bench, part);
+    }
+    match &args.command {
+        Some(Command::Scaffold { puzzle, page }) => return scaffold(puzzle, page.as_deref()),
+        Some(Command::Fetch { puzzles }) => return fetch(puzzles, &args),
+        Some(Command::Submit { puzzle, part }) => return submit(puzzle, *part, &args),
+        Some(Command::Intcode { program, ascii }) => return intcode(program, *ascii),
+        Some(Command::Profile {
+            program,
+            input,
+            trace,
+            top,
+        }) => return profile(program, input, trace.as_deref(), *top),
+        _ => {}
+    }
 
-    let puzzles = get_puzzles(year, day);
-    let mut total_time = 0f32;
+    let puzzles = select(&args.puzzle)?;
+    if args.input.is_some() && puzzles.len() != 1 {
+        return Err(color_eyre::eyre::eyre!(
+            "--input can only be used when exactly one puzzle is selected, {} selected",
+            puzzles.len()
+        ));
+    }
+    let mut summary = Summary::default();
+    let mut wrong = 0;
+    let mut regressions = 0;
+    let mut formatter = Formatter::new(args.format, args.verify);
+    let mut answers = AnswerStore::load(&args.answers_dir)?;
+    let mut baseline = Baseline::load(&args.baseline)?;
 
     if debug_enabled {
         SimpleLogger::new()
             .with_level(log::LevelFilter::Debug)
             .init()?;
+    } else if args.bench.is_some() {
+        print!("{}", formatter.bench_header());
     } else {
-        print_header();
+        print!("{}", formatter.header());
     }
 
-    for puzzle in &puzzles {
-        let result = (puzzle.solve)(puzzle.input)?;
+    let worker = Worker::new(&env::current_exe()?, args.bench, debug_enabled)
+        .with_timeout(args.timeout)
+        .with_part(args.part);
+    let run = |puzzle: &&Puzzle| match read_input(puzzle, args.input.as_deref(), &args.input_dir) {
+        Ok(Some(input)) => worker.run(puzzle, &input),
+        Ok(None) => Outcome::MissingInput,
+        Err(err) => Outcome::Failed {
+            error: format!("input: {err}"),
+            progress: Progress::default(),
+        },
+    };
+
+    let wall_start = Instant::now();
+    run_ordered(&puzzles, args.jobs as usize, run, |puzzle, outcome| {
+        let result = match outcome {
+            Outcome::MissingInput => {
+                if debug_enabled {
+                    log::warn!("Missing input for {puzzle}");
+                } else if args.bench.is_none() {
+                    print!("{}", formatter.record(&Record::missing_input(puzzle)));
+                }
+                summary.missing += 1;
+                return ControlFlow::Continue(());
+            }
+            Outcome::Solved(result) => result,
+            Outcome::Benched(bench) => {
+                let records = BenchRecord::from_bench(
+                    puzzle,
+                    &bench,
+                    baseline.get(puzzle),
+                    args.regression_threshold,
+                );
+                for record in records {
+                    if record.regression {
+                        regressions += 1;
+                    }
+                    if !debug_enabled {
+                        print!("{}", formatter.bench_record(&record));
+                    }
+                }
+                if args.save_baseline {
+                    baseline.insert(puzzle, &bench);
+                }
+                bench.result
+            }
+            Outcome::Failed { error, progress } => {
+                if debug_enabled {
+                    log::error!("{puzzle} failed: {error}");
+                } else if args.bench.is_some() {
+                    eprintln!("{puzzle} failed: {error}");
+                } else {
+                    print!(
+                        "{}",
+                        formatter.record(&Record::failed(puzzle, &progress, &error))
+                    );
+                }
+                summary.failed += 1;
+                return ControlFlow::Continue(());
+            }
+        };
+        summary.solved += 1;
+
+        let mut record = Record::solved(puzzle, &result);
+        if args.verify {
+            record = record.with_verdicts(answers.verify(puzzle, &result));
+            if record.verdict() == Some(Verdict::Wrong) {
+                wrong += 1;
+            }
+        }
+        if args.record {
+            answers.record(puzzle, &result);
+        }
 
         if !debug_enabled {
-            total_time += result.total_time();
-            print_puzzle_result(&puzzle.year, &puzzle.day, result);
+            summary.total_time += result.total_time();
+            if args.bench.is_none() {
+                print!("{}", formatter.record(&record));
+            }
+        } else if record.verdict() == Some(Verdict::Wrong) {
+            log::error!("Wrong answer for {puzzle}");
         }
-    }
 
-    if !debug_enabled && puzzles.len() > 1 {
-        println!();
-        println!("Solved {} puzzles in {total_time}s", puzzles.len());
+        ControlFlow::Continue(())
+    });
+    summary.wall_time = wall_start.elapsed().as_secs_f32();
+
+    if !debug_enabled {
+        print!("{}", formatter.footer(&summary));
     }
 
-    Ok(())
-}
+    answers.save()?;
+    if args.save_baseline {
+        baseline.save(&args.baseline)?;
+    }
 
-fn parse_args() -> (Option<String>, Option<String>, bool) {
-    let args = Args::parse();
+    let mut problems = Vec::new();
+    if wrong > 0 {
+        problems.push(format!(
+            "{wrong} puzzles didn't match their accepted answers"
+        ));
+    }
+    if regressions > 0 {
+        problems.push(format!(
+            "{regressions} stages regressed by more than {}% against the baseline",
+            args.regression_threshold
+        ));
+    }
 
-    if let Some(puzzle) = args.puzzle {
-        if let Some((year, day)) = puzzle.split_once("::") {
-            (Some(year.into()), Some(day.into()), args.debug)
-        } else {
-            (Some(puzzle), None, args.debug)
+    // Everything that went wrong is reported first. The exit code is the number of puzzles that
+    // failed, if any did
+    if summary.failed > 0 {
+        for problem in &problems {
+            eprintln!("Error: {problem}");
         }
-    } else {
-        (None, None, args.debug)
+        process::exit(summary.failed.min(255) as i32);
     }
-}
-
-fn get_puzzles(year: Option<String>, day: Option<String>) -> Vec<Puzzle> {
-    empty()
-        .chain(year2019())
-        .chain(year2023())
-        .chain(year2024())
-        .chain(year2025())
-        .filter(|puzzle| year.as_ref().map_or(true, |year| *year == puzzle.year))
-        .filter(|puzzle| day.as_ref().map_or(true, |day| *day == puzzle.day))
-        .collect::<Vec<_>>()
-}
 
-fn print_header() {
-    println!(
-        "{:^10}┃{:^7}┃{:^20}┃{:^20}┃{:^18}┃{:^18}┃{:^18}┃{:^15}",
-        "Year".bold(),
-        "Day".bold(),
-        "Part 1".bold(),
-        "Part 2".bold(),
-        "Parse Time(s)".bold(),
-        "Part 1 Time(s)".bold(),
-        "Part 2 Time(s)".bold(),
-        "Total Time(s)".bold()
-    );
-    println!(
-        "{:━^10}╋{:━^7}╋{:━^20}╋{:━^20}╋{:━^18}╋{:━^18}╋{:━^18}╋{:━^15}",
-        "━", "━", "━", "━", "━", "━", "━", "━"
-    );
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(color_eyre::eyre::eyre!(problems.join("\n")))
+    }
 }
 
-fn print_puzzle_result(year: &String, day: &String, result: PuzzleResult) {
-    let part1_time = colorize_time(result.part1.time_s);
-    let part2_time = colorize_time(result.part2.time_s);
-    let parse_time = colorize_time(result.parse_time_s);
-    let total_time = colorize_time(result.total_time());
+fn scaffold(puzzle: &Selector, page: Option<&Path>) -> color_eyre::eyre::Result<()> {
+    let (year, day) = puzzle
+        .single()
+        .ok_or_else(|| color_eyre::eyre::eyre!("{puzzle} isn't a single puzzle"))?;
+
+    let mut scaffold = Scaffold::new(Path::new("."), year, day);
+    if let Some(page) = page {
+        let html = fs::read_to_string(page)
+            .map_err(|err| color_eyre::eyre::eyre!("Failed to read {}: {err}", page.display()))?;
+        let examples = examples::extract(&html);
+
+        // The last highlighted value is only a guess at the answer, so show the rest too
+        for (name, example) in [("part1", &examples.part1), ("part2", &examples.part2)] {
+            let Some(example) = example else {
+                continue;
+            };
+            println!(
+                "{name}: {} example blocks, answer {} from {:?}",
+                example.blocks.len(),
+                example.answer().unwrap_or("-"),
+                example.answers
+            );
+        }
+        scaffold = scaffold.with_examples(examples);
+    }
 
-    println!(
-        "{:<10}┃ {:<6}┃ {:<19}┃ {:<19}┃ {:<17}┃ {:<17}┃ {:<17}┃ {:<14}",
-        year,
-        day,
-        result.part1.answer,
-        result.part2.answer,
-        parse_time,
-        part1_time,
-        part2_time,
-        total_time,
-    );
-}
+    for (path, status) in scaffold.run()? {
+        match status {
+            Status::Created | Status::Updated => println!("{} ✔️", path.display()),
+            Status::Exists => println!("{} ⏩", path.display()),
+        }
+    }
 
-fn colorize_time(time: f32) -> ColoredString {
-    if time < 0.5 {
-        format!("{}", time).green()
-    } else if time < 1.0 {
-        format!("{}", time).blue()
-    } else {
-        format!("{}", time).red()
+    if scaffold.is_changed() {
+        if let Err(err) = scaffold.check() {
+            scaffold.undo()?;
+            return Err(err);
+        }
     }
-}
 
-struct Puzzle {
-    year: String,
-    day: String,
-    input: &'static str,
-    solve: fn(&str) -> color_eyre::Result<PuzzleResult>,
+    println!("\nRun `fetch {year}/{day}` to download the input. Happy puzzling! 🎅");
+    Ok(())
 }
 
-struct PuzzleResult {
-    parse_time_s: f32,
-    part1: PartResult,
-    part2: PartResult,
-}
+fn fetch(puzzles: &[Selector], args: &Args) -> color_eyre::eyre::Result<()> {
+    let mut client = Client::new(&Config::load(&args.config)?)?;
+
+    let mut failed = 0;
+    for (year, day) in select_days(puzzles)? {
+        match fetch_input(&mut client, &args.input_dir, year, day) {
+            Ok(true) => println!("year{year}::day{day:02} ⬇️"),
+            Ok(false) => println!("year{year}::day{day:02} ⏩"),
+            Err(err) => {
+                println!("year{year}::day{day:02} ❌ {err}");
+                failed += 1;
+            }
+        }
+    }
 
-impl PuzzleResult {
-    fn total_time(&self) -> f32 {
-        self.parse_time_s + self.part1.time_s + self.part2.time_s
+    if failed > 0 {
+        return Err(color_eyre::eyre::eyre!("Failed to fetch {failed} inputs"));
     }
-}
 
-struct PartResult {
-    answer: String,
-    time_s: f32,
+    Ok(())
 }
 
-macro_rules! puzzle {
-    ($year:tt, $day:tt) => {
-        Puzzle {
-            year: stringify!($year).to_string(),
-            day: stringify!($day).to_string(),
-            input: include_str!(concat![
-                "../input/",
-                stringify!($year),
-                "/",
-                stringify!($day),
-                ".txt"
-            ]),
-            solve: |raw: &str| {
-                use $year::$day::*;
-
-                let (input, parse_time_s) = timed_fn(|| parse(raw))?;
-
-                let (answer, time_s) = timed_fn(|| part1(&input))?;
-                let part1 = PartResult {
-                    answer: answer.to_string(),
-                    time_s,
-                };
-
-                let (answer, time_s) = timed_fn(|| part2(&input))?;
-                let part2 = PartResult {
-                    answer: answer.to_string(),
-                    time_s,
-                };
-
-                Ok(PuzzleResult {
-                    parse_time_s,
-                    part1,
-                    part2,
-                })
-            },
+fn submit(selector: &Selector, part: Part, args: &Args) -> color_eyre::eyre::Result<()> {
+    let puzzle = selector
+        .single()
+        .and_then(|(year, day)| registry::find(year, day))
+        .ok_or_else(|| color_eyre::eyre::eyre!("No such puzzle: {selector}"))?;
+    let input = read_input(puzzle, args.input.as_deref(), &args.input_dir)?
+        .ok_or_else(|| color_eyre::eyre::eyre!("Missing input for {puzzle}"))?;
+
+    let worker = Worker::new(&env::current_exe()?, None, args.debug)
+        .with_timeout(args.timeout)
+        .with_part(Some(part));
+    let result = match worker.run(puzzle, &input) {
+        Outcome::Solved(result) => result,
+        Outcome::Failed { error, .. } => {
+            return Err(color_eyre::eyre::eyre!("{puzzle} failed: {error}"))
+        }
+        Outcome::MissingInput | Outcome::Benched(_) => {
+            return Err(color_eyre::eyre::eyre!("{puzzle} wasn't solved"))
         }
     };
-}
+    let answer = match part {
+        Part::One => &result.part1,
+        Part::Two => &result.part2,
+    }
+    .as_ref()
+    .map(|part| part.answer.clone())
+    .ok_or_else(|| color_eyre::eyre::eyre!("{puzzle} wasn't solved"))?;
+    let name = format!("{puzzle} part {}", part.number());
+    let answer = match answer {
+        Answer::Bitmap(bitmap) => {
+            let message = "the answer is a picture, which has to be read and submitted by hand";
+            return Err(color_eyre::eyre::eyre!("{name}: {message}\n{bitmap}"));
+        }
+        answer => answer.to_string(),
+    };
 
-fn timed_fn<F, T>(f: F) -> color_eyre::Result<(T, f32)>
-where
-    F: Fn() -> color_eyre::Result<T>,
-{
-    let start = Instant::now();
-    let result = f()?;
-    let elapsed_s = start.elapsed().as_secs_f32();
+    let log_path = args.answers_dir.join("submissions.toml");
+    let mut log = SubmissionLog::load(&log_path)?;
+    match log.known_response(&puzzle.to_string(), part, &answer) {
+        Some(Response::Correct) => {
+            println!("{name}: {answer} has already been accepted");
+            return Ok(());
+        }
+        Some(response) => {
+            return Err(color_eyre::eyre::eyre!(
+                "{name}: {answer} is already known to be {response}, so it wasn't submitted"
+            ))
+        }
+        None => {}
+    }
 
-    Ok((result, elapsed_s))
+    let mut client = Client::new(&Config::load(&args.config)?)?;
+    let response = parse_response(&client.answer(puzzle.year, puzzle.day, part, &answer)?)?;
+    log.record(&puzzle.to_string(), part, &answer, response);
+    log.save(&log_path)?;
+
+    if response != Response::Correct {
+        return Err(color_eyre::eyre::eyre!(
+            "{name}: {answer} wasn't accepted, {response}"
+        ));
+    }
+
+    println!("{name}: {answer} is correct ⭐");
+    let mut answers = AnswerStore::load(&args.answers_dir)?;
+    answers.record(puzzle, &result);
+    answers.save()
 }
 
-fn year2019() -> Vec<Puzzle> {
-    vec![
-        puzzle!(year2019, day01),
-        puzzle!(year2019, day02),
-        puzzle!(year2019, day03),
-        puzzle!(year2019, day04),
-        puzzle!(year2019, day05),
-        puzzle!(year2019, day06),
-        puzzle!(year2019, day07),
-        puzzle!(year2019, day08),
-        puzzle!(year2019, day09),
-        puzzle!(year2019, day10),
-        puzzle!(year2019, day11),
-    ]
+fn intcode(path: &Path, ascii: bool) -> color_eyre::eyre::Result<()> {
+    let program = read_program(path)?;
+
+    if ascii {
+        run_interactive(program, std::io::stdin().lock(), std::io::stdout())
+    } else {
+        run_repl(program, std::io::stdin().lock(), std::io::stdout())
+    }
 }
 
-fn year2023() -> Vec<Puzzle> {
-    vec![
-        puzzle!(year2023, day01),
-        puzzle!(year2023, day02),
-        puzzle!(year2023, day03),
-        puzzle!(year2023, day04),
-        puzzle!(year2023, day05),
-        puzzle!(year2023, day06),
-        puzzle!(year2023, day07),
-        puzzle!(year2023, day08),
-        puzzle!(year2023, day09),
-        puzzle!(year2023, day10),
-        puzzle!(year2023, day11),
-        puzzle!(year2023, day12),
-        puzzle!(year2023, day13),
-        puzzle!(year2023, day14),
-        puzzle!(year2023, day15),
-        puzzle!(year2023, day16),
-        puzzle!(year2023, day17),
-        puzzle!(year2023, day18),
-        puzzle!(year2023, day19),
-        puzzle!(year2023, day20),
-        //puzzle!(year2023, day21),
-        puzzle!(year2023, day22),
-        //puzzle!(year2023, day23),
-    ]
+fn profile(
+    path: &Path,
+    input: &[i64],
+    trace: Option<&Path>,
+    top: usize,
+) -> color_eyre::eyre::Result<()> {
+    let mut icc = IntCodeComputer::load(read_program(path)?);
+    icc.enable_profiling(match trace {
+        Some(trace) => Profile::with_trace(trace)?,
+        None => Profile::new(),
+    });
+    for value in input {
+        icc.push_input(*value);
+    }
+
+    if icc.run()? == RunState::WaitingForInput {
+        println!("Stopped waiting for input");
+    }
+    let output = icc
+        .get_output()
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>();
+    println!("output: {}", output.join(", "));
+    print!(
+        "{}",
+        icc.profile().expect("Profiling is enabled").report(top)
+    );
+
+    Ok(())
 }
 
-fn year2024() -> Vec<Puzzle> {
-    vec![
-        puzzle!(year2024, day01),
-        puzzle!(year2024, day02),
-        puzzle!(year2024, day03),
-        puzzle!(year2024, day04),
-        puzzle!(year2024, day05),
-        puzzle!(year2024, day06),
-        puzzle!(year2024, day07),
-        puzzle!(year2024, day08),
-        puzzle!(year2024, day09),
-        puzzle!(year2024, day10),
-        puzzle!(year2024, day11),
-        puzzle!(year2024, day12),
-        puzzle!(year2024, day13),
-        puzzle!(year2024, day14),
-        puzzle!(year2024, day15),
-        puzzle!(year2024, day16),
-        puzzle!(year2024, day17),
-        puzzle!(year2024, day18),
-        puzzle!(year2024, day19),
-        puzzle!(year2024, day20),
-        puzzle!(year2024, day21),
-    ]
+fn read_program(path: &Path) -> color_eyre::eyre::Result<IcProgram> {
+    let program = fs::read_to_string(path)
+        .map_err(|err| color_eyre::eyre::eyre!("Failed to read {}: {err}", path.display()))?;
+    IntCodeComputer::parse_program(&program)
 }
 
-fn year2025() -> Vec<Puzzle> {
-    vec![
-        puzzle!(year2025, day01),
-        // NEXT
-    ]
+fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    let secs = secs.parse::<f32>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f32(secs).map_err(|err| err.to_string())
 }
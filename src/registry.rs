@@ -0,0 +1,94 @@
+use std::{fmt::Display, marker::PhantomData, time::Duration};
+
+use crate::{answer::Answer, solver::Solver};
+
+// Generated by build.rs from the src/yearYYYY/dayDD.rs files
+static PUZZLES: &[Puzzle] = &include!(concat!(env!("OUT_DIR"), "/puzzles.rs"));
+
+pub fn puzzles() -> &'static [Puzzle] {
+    PUZZLES
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Puzzle> {
+    PUZZLES
+        .iter()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+/// A registered puzzle with its `Solver` type erased, so puzzles with different input and answer
+/// types can be listed and run side by side
+#[derive(Clone)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    pub timeout: Duration,
+    pub wip: bool,
+    parse: ParseFn,
+}
+
+type ParseFn = for<'a> fn(&'a str) -> color_eyre::Result<Box<dyn ParsedPuzzle + 'a>>;
+
+impl Puzzle {
+    pub const fn new<S: Solver + 'static>(year: u32, day: u32) -> Self {
+        Self {
+            year,
+            day,
+            timeout: S::TIMEOUT,
+            wip: S::WIP,
+            parse: parse_with::<S>,
+        }
+    }
+
+    // Module name of the year, e.g. "year2023"
+    pub fn year_name(&self) -> String {
+        format!("year{}", self.year)
+    }
+
+    // Module name of the day, e.g. "day01"
+    pub fn day_name(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    pub fn parse<'a>(&self, input: &'a str) -> color_eyre::Result<Box<dyn ParsedPuzzle + 'a>> {
+        (self.parse)(input)
+    }
+
+    pub fn solve(&self, input: &str) -> color_eyre::Result<(Answer, Answer)> {
+        let parsed = self.parse(input)?;
+        Ok((parsed.part1()?, parsed.part2()?))
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}::{}", self.year_name(), self.day_name())
+    }
+}
+
+/// Parsed input for a puzzle, ready to have either part solved
+pub trait ParsedPuzzle {
+    fn part1(&self) -> color_eyre::Result<Answer>;
+    fn part2(&self) -> color_eyre::Result<Answer>;
+}
+
+struct Parsed<'a, S: Solver> {
+    input: S::Input<'a>,
+    solver: PhantomData<S>,
+}
+
+impl<S: Solver> ParsedPuzzle for Parsed<'_, S> {
+    fn part1(&self) -> color_eyre::Result<Answer> {
+        Ok(S::part1(&self.input)?.into())
+    }
+
+    fn part2(&self) -> color_eyre::Result<Answer> {
+        Ok(S::part2(&self.input)?.into())
+    }
+}
+
+fn parse_with<S: Solver + 'static>(input: &str) -> color_eyre::Result<Box<dyn ParsedPuzzle + '_>> {
+    Ok(Box::new(Parsed::<S> {
+        input: S::parse(input)?,
+        solver: PhantomData,
+    }))
+}
@@ -0,0 +1,146 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::eyre;
+use serde::{Deserialize, Serialize};
+
+use crate::{answer::Answer, registry::Puzzle};
+
+use super::solve::{PartResult, PuzzleResult};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, strum_macros::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    Unknown,
+}
+
+// The accepted answers for one day, as stored in answers/<year>.toml, e.g.
+//
+// [day01]
+// part1 = "24000"
+// part2 = "45000"
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AcceptedAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+type YearAnswers = BTreeMap<String, AcceptedAnswers>;
+
+// Accepted answers for every year that has a file in the answers directory
+pub struct AnswerStore {
+    dir: PathBuf,
+    years: BTreeMap<u32, YearAnswers>,
+    changed: BTreeSet<u32>,
+}
+
+impl AnswerStore {
+    pub fn load(dir: &Path) -> color_eyre::Result<Self> {
+        let mut store = Self {
+            dir: dir.to_path_buf(),
+            years: BTreeMap::new(),
+            changed: BTreeSet::new(),
+        };
+
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(store),
+            Err(err) => return Err(eyre!("Failed to read {}: {err}", dir.display())),
+        };
+
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "toml") {
+                continue;
+            }
+            let Some(year) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<u32>().ok())
+            else {
+                continue;
+            };
+
+            let contents = fs::read_to_string(&path)?;
+            let answers = toml::from_str(&contents)
+                .map_err(|err| eyre!("Failed to parse {}: {err}", path.display()))?;
+            store.years.insert(year, answers);
+        }
+
+        Ok(store)
+    }
+
+    pub fn get(&self, puzzle: &Puzzle) -> Option<&AcceptedAnswers> {
+        self.years.get(&puzzle.year)?.get(&puzzle.day_name())
+    }
+
+    // Parts that weren't solved don't get a verdict
+    pub fn verify(
+        &self,
+        puzzle: &Puzzle,
+        result: &PuzzleResult,
+    ) -> (Option<Verdict>, Option<Verdict>) {
+        let accepted = self.get(puzzle).cloned().unwrap_or_default();
+        let verdict = |accepted: Option<&str>, part: &Option<PartResult>| {
+            part.as_ref().map(|part| verdict(accepted, &part.answer))
+        };
+
+        (
+            verdict(accepted.part1.as_deref(), &result.part1),
+            verdict(accepted.part2.as_deref(), &result.part2),
+        )
+    }
+
+    // Parts that weren't solved keep their accepted answers
+    pub fn record(&mut self, puzzle: &Puzzle, result: &PuzzleResult) {
+        let previous = self.get(puzzle).cloned().unwrap_or_default();
+        let answer = |part: &Option<PartResult>| part.as_ref().map(|part| part.answer.to_string());
+        let accepted = AcceptedAnswers {
+            part1: answer(&result.part1).or(previous.part1),
+            part2: answer(&result.part2).or(previous.part2),
+        };
+
+        let year = self.years.entry(puzzle.year).or_default();
+        if year.get(&puzzle.day_name()) != Some(&accepted) {
+            year.insert(puzzle.day_name(), accepted);
+            self.changed.insert(puzzle.year);
+        }
+    }
+
+    // Writes out the files for any years that have had answers recorded
+    pub fn save(&mut self) -> color_eyre::Result<()> {
+        if self.changed.is_empty() {
+            return Ok(());
+        }
+        fs::create_dir_all(&self.dir)?;
+
+        for year in std::mem::take(&mut self.changed) {
+            let path = self.path(year);
+            let contents = toml::to_string(&self.years[&year])?;
+            fs::write(&path, contents)
+                .map_err(|err| eyre!("Failed to write {}: {err}", path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    fn path(&self, year: u32) -> PathBuf {
+        self.dir.join(year.to_string()).with_extension("toml")
+    }
+}
+
+fn verdict(accepted: Option<&str>, answer: &Answer) -> Verdict {
+    match accepted {
+        Some(accepted) if answer.matches(accepted) => Verdict::Correct,
+        Some(_) => Verdict::Wrong,
+        None => Verdict::Unknown,
+    }
+}
@@ -0,0 +1,197 @@
+use std::{collections::BTreeMap, fs, io, path::Path, time::Instant};
+
+use color_eyre::eyre::eyre;
+use serde::{Deserialize, Serialize};
+
+use crate::{answer::Answer, registry::Puzzle};
+
+use super::solve::{PartResult, Parts, PuzzleResult};
+
+// Timings of a stage over every benchmarked run, in seconds
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BenchStats {
+    pub min: f32,
+    pub median: f32,
+    pub mean: f32,
+    pub std_dev: f32,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &[f32]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f32::total_cmp);
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().sum::<f32>() / n as f32;
+        // Sample standard deviation, which is 0 for a single run
+        let variance = if n > 1 {
+            sorted.iter().map(|time| (time - mean).powi(2)).sum::<f32>() / (n - 1) as f32
+        } else {
+            0.0
+        };
+
+        Self {
+            min: sorted[0],
+            median,
+            mean,
+            std_dev: variance.sqrt(),
+        }
+    }
+}
+
+// A part is None when it wasn't selected to be benchmarked
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PuzzleBench {
+    pub parse: BenchStats,
+    pub part1: Option<BenchStats>,
+    pub part2: Option<BenchStats>,
+    // Answers from the first run, with the median times
+    pub result: PuzzleResult,
+}
+
+impl PuzzleBench {
+    pub fn stages(&self) -> Vec<(&'static str, &BenchStats)> {
+        [
+            ("parse", Some(&self.parse)),
+            ("part1", self.part1.as_ref()),
+            ("part2", self.part2.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(stage, stats)| Some((stage, stats?)))
+        .collect()
+    }
+}
+
+// Runs every stage once to warm up, then `runs` more times, timing each one
+pub fn bench(
+    puzzle: &Puzzle,
+    raw: &str,
+    runs: u32,
+    parts: Parts,
+) -> color_eyre::Result<PuzzleBench> {
+    let warm_up = puzzle.parse(raw)?;
+    let part1 = parts.part1.then(|| warm_up.part1()).transpose()?;
+    let part2 = parts.part2.then(|| warm_up.part2()).transpose()?;
+    drop(warm_up);
+
+    let mut parse_times = Vec::new();
+    let mut part1_times = Vec::new();
+    let mut part2_times = Vec::new();
+    for _ in 0..runs {
+        let start = Instant::now();
+        let input = puzzle.parse(raw)?;
+        parse_times.push(start.elapsed().as_secs_f32());
+
+        if parts.part1 {
+            let start = Instant::now();
+            input.part1()?;
+            part1_times.push(start.elapsed().as_secs_f32());
+        }
+
+        if parts.part2 {
+            let start = Instant::now();
+            input.part2()?;
+            part2_times.push(start.elapsed().as_secs_f32());
+        }
+    }
+
+    let parse = BenchStats::from_samples(&parse_times);
+    let part1_stats = parts.part1.then(|| BenchStats::from_samples(&part1_times));
+    let part2_stats = parts.part2.then(|| BenchStats::from_samples(&part2_times));
+    let part_result = |answer: Option<Answer>, stats: Option<BenchStats>| {
+        Some(PartResult {
+            answer: answer?,
+            time_s: stats?.median,
+        })
+    };
+
+    Ok(PuzzleBench {
+        result: PuzzleResult {
+            parse_time_s: parse.median,
+            part1: part_result(part1, part1_stats),
+            part2: part_result(part2, part2_stats),
+        },
+        parse,
+        part1: part1_stats,
+        part2: part2_stats,
+    })
+}
+
+// Median time of each stage, as saved in a baseline. Parts that have never been benchmarked on
+// their own are None
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StageTimes {
+    pub parse: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<f32>,
+}
+
+impl StageTimes {
+    pub fn get(&self, stage: &str) -> Option<f32> {
+        match stage {
+            "parse" => Some(self.parse),
+            "part1" => self.part1,
+            "part2" => self.part2,
+            _ => None,
+        }
+    }
+}
+
+// Benchmark results from an earlier run to compare against, keyed by puzzle e.g.
+// "year2024::day16"
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    puzzles: BTreeMap<String, StageTimes>,
+}
+
+impl Baseline {
+    // A baseline that hasn't been saved yet is empty
+    pub fn load(path: &Path) -> color_eyre::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|err| eyre!("Failed to parse {}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(eyre!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn get(&self, puzzle: &Puzzle) -> Option<&StageTimes> {
+        self.puzzles.get(&puzzle.to_string())
+    }
+
+    // Parts that weren't benchmarked keep their previous times
+    pub fn insert(&mut self, puzzle: &Puzzle, bench: &PuzzleBench) {
+        let previous = self.get(puzzle).copied();
+        let times = StageTimes {
+            parse: bench.parse.median,
+            part1: bench
+                .part1
+                .map(|stats| stats.median)
+                .or(previous.and_then(|times| times.part1)),
+            part2: bench
+                .part2
+                .map(|stats| stats.median)
+                .or(previous.and_then(|times| times.part2)),
+        };
+        self.puzzles.insert(puzzle.to_string(), times);
+    }
+
+    pub fn save(&self, path: &Path) -> color_eyre::Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(path, contents + "\n")
+            .map_err(|err| eyre!("Failed to write {}: {err}", path.display()))
+    }
+}
+
+// Percentage change in the median from the baseline, positive meaning slower
+pub fn change_percent(baseline: f32, median: f32) -> f32 {
+    (median - baseline) / baseline * 100.0
+}
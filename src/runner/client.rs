@@ -0,0 +1,180 @@
+use std::{
+    env, fs, io,
+    path::Path,
+    thread,
+    time::{Duration, Instant},
+};
+
+use color_eyre::eyre::eyre;
+use serde::Deserialize;
+
+use super::solve::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Override the config file
+const SESSION_VAR: &str = "AOC_SESSION";
+const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+// The site asks automated tools to identify themselves, and where to find them
+const USER_AGENT: &str = concat!(
+    "github.com/AdamSmith89/advent_of_code ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+// Least time between two requests, so running over a whole year doesn't hammer the site
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+// Requests that fail with a server or connection error are tried this many times in total, waiting
+// twice as long before each retry. Answers are only ever posted once, as the site may have counted
+// them anyway
+const ATTEMPTS: u32 = 3;
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+// Settings for talking to the Advent of Code site, e.g. in .aoc/config.toml
+//
+// session = "53616c7465645f5f..."
+// base_url = "http://localhost:8080"
+//
+// The session token is the `session` cookie of a logged in browser
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    // A config file that doesn't exist is empty. The AOC_SESSION and AOC_BASE_URL environment
+    // variables take precedence over the file
+    pub fn load(path: &Path) -> color_eyre::Result<Self> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| eyre!("Failed to parse {}: {err}", path.display()))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(err) => return Err(eyre!("Failed to read {}: {err}", path.display())),
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            config.base_url = Some(base_url);
+        }
+
+        Ok(config)
+    }
+}
+
+// A logged in connection to the Advent of Code site
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    retry_delay: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> color_eyre::Result<Self> {
+        let session = config.session.clone().ok_or_else(|| {
+            eyre!("No session token, set `session` in the config file or {SESSION_VAR}")
+        })?;
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+
+        Ok(Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session: session.trim().to_string(),
+            min_interval: MIN_INTERVAL,
+            retry_delay: RETRY_DELAY,
+            last_request: None,
+        })
+    }
+
+    // Overrides how long to wait between requests and before retrying, e.g. for a local server
+    pub fn with_delays(mut self, min_interval: Duration, retry_delay: Duration) -> Self {
+        self.min_interval = min_interval;
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    pub fn input(&mut self, year: u32, day: u32) -> color_eyre::Result<String> {
+        self.send("GET", &format!("{year}/day/{day}/input"), None)
+    }
+
+    // Returns the page saying whether the answer was right, see `submit::parse_response`
+    pub fn answer(
+        &mut self,
+        year: u32,
+        day: u32,
+        part: Part,
+        answer: &str,
+    ) -> color_eyre::Result<String> {
+        let level = part.number().to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        self.send("POST", &format!("{year}/day/{day}/answer"), Some(&form))
+    }
+
+    // Sends a request to `path` under the base URL, posting the form if there is one. Waits for
+    // the throttle first, and retries anything that might succeed if tried again. Returns the body
+    // of the response
+    fn send(
+        &mut self,
+        method: &str,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> color_eyre::Result<String> {
+        let url = format!("{}/{path}", self.base_url);
+        let cookie = format!("session={}", self.session);
+
+        let attempts = if form.is_some() { 1 } else { ATTEMPTS };
+        let mut delay = self.retry_delay;
+        let mut attempt = 1;
+        loop {
+            if let Some(wait) = self
+                .last_request
+                .and_then(|last| self.min_interval.checked_sub(last.elapsed()))
+            {
+                thread::sleep(wait);
+            }
+            self.last_request = Some(Instant::now());
+
+            let request = self.agent.request(method, &url).set("Cookie", &cookie);
+            let response = match form {
+                Some(form) => request.send_form(form),
+                None => request.call(),
+            };
+
+            let error = match response {
+                Ok(response) => return Ok(response.into_string()?),
+                Err(ureq::Error::Status(400 | 401, _)) => {
+                    return Err(eyre!("The session token was rejected, it may have expired"))
+                }
+                Err(ureq::Error::Status(404, _)) => {
+                    return Err(eyre!(
+                        "{url} wasn't found, the puzzle may not be unlocked yet"
+                    ))
+                }
+                Err(ureq::Error::Status(status, _)) if status < 500 => {
+                    return Err(eyre!("{url} returned {status}"))
+                }
+                Err(err) => err,
+            };
+
+            if attempt == attempts {
+                return Err(eyre!("{error}"));
+            }
+            log::warn!("{error}, retrying in {}s", delay.as_secs_f32());
+            thread::sleep(delay);
+            delay *= 2;
+            attempt += 1;
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use regex::Regex;
+
+// Example inputs and answers pulled out of a saved puzzle description page. The page has an
+// <article> per part, once part 1 has been solved
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Examples {
+    pub part1: Option<Example>,
+    pub part2: Option<Example>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Example {
+    // Every <pre><code> block in the part, the first being the example input
+    pub blocks: Vec<String>,
+    // Every highlighted value in the part, the last being the example's answer
+    pub answers: Vec<String>,
+}
+
+impl Example {
+    pub fn input(&self) -> Option<&str> {
+        self.blocks.first().map(String::as_str)
+    }
+
+    pub fn answer(&self) -> Option<&str> {
+        self.answers.last().map(String::as_str)
+    }
+}
+
+pub fn extract(html: &str) -> Examples {
+    let mut parts = html
+        .split("<article")
+        .skip(1)
+        .filter_map(|article| article.split_once("</article>"))
+        .map(|(article, _)| extract_part(article));
+
+    Examples {
+        part1: parts.next(),
+        part2: parts.next(),
+    }
+}
+
+fn extract_part(article: &str) -> Example {
+    let blocks = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>")
+        .unwrap()
+        .captures_iter(article)
+        .map(|captures| {
+            let block = text(&captures[1]);
+            String::from(block.trim_end_matches('\n'))
+        })
+        .collect();
+
+    // Answers are written either way round
+    let answers = Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>")
+        .unwrap()
+        .captures_iter(article)
+        .filter_map(|captures| captures.get(1).or(captures.get(2)))
+        .map(|answer| text(answer.as_str()))
+        .collect();
+
+    Example { blocks, answers }
+}
+
+// Strips any markup, e.g. highlighting within an example, and decodes the entities the site uses
+fn text(html: &str) -> String {
+    Regex::new("<[^>]*>")
+        .unwrap()
+        .replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// Fills in the example input and the example tests' assertions of a test generated from
+// .aoc/test_template.rs. Part 2 gets its own EXAMPLE_INPUT_PART2 when its example differs
+pub fn fill_test(test: &str, examples: &Examples) -> String {
+    const EMPTY_INPUT: &str = "const EXAMPLE_INPUT: &str = \"\\\n\";";
+
+    let part1_input = examples.part1.as_ref().and_then(Example::input);
+    let part2_input = examples
+        .part2
+        .as_ref()
+        .and_then(Example::input)
+        .filter(|input| Some(*input) != part1_input);
+
+    let mut inputs = vec![example_const("EXAMPLE_INPUT", part1_input.unwrap_or(""))];
+    if let Some(input) = part2_input {
+        inputs.push(example_const("EXAMPLE_INPUT_PART2", input));
+    }
+    let mut test = test.replacen(EMPTY_INPUT, &inputs.join("\n"), 1);
+
+    for (name, example) in [("part1", &examples.part1), ("part2", &examples.part2)] {
+        let Some(answer) = example.as_ref().and_then(Example::answer) else {
+            continue;
+        };
+        // The template's answers are numbers, which anything else is compared to as text so it
+        // still compiles
+        let assertion = match answer.parse::<i64>() {
+            Ok(_) => format!("assert_eq!({answer}, actual);"),
+            Err(_) => format!("assert_eq!({answer:?}, actual.to_string());"),
+        };
+
+        test = replace_in_fn(&test, &format!("fn {name}_example()"), |body| {
+            let body = body
+                .replace("let _actual", "let actual")
+                .replace("//assert_eq!(expected, actual);", &assertion);
+            match (name, part2_input) {
+                ("part2", Some(_)) => body.replace("(EXAMPLE_INPUT)", "(EXAMPLE_INPUT_PART2)"),
+                _ => body,
+            }
+        });
+    }
+
+    test
+}
+
+// Written as a continued string like the rest of the tests, which would drop any leading whitespace
+// on the first line unless it's escaped
+fn example_const(name: &str, input: &str) -> String {
+    let escaped = input
+        .trim_end_matches('\n')
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+    let escaped = match escaped.chars().next() {
+        Some(first) if first.is_whitespace() => {
+            format!("\\u{{{:x}}}{}", first as u32, &escaped[first.len_utf8()..])
+        }
+        _ => escaped,
+    };
+
+    format!("const {name}: &str = \"\\\n{escaped}\n\";")
+}
+
+// Applies `f` to the body of the function with the signature, up to the closing brace at the start
+// of a line
+fn replace_in_fn<F>(source: &str, signature: &str, f: F) -> String
+where
+    F: FnOnce(&str) -> String,
+{
+    let Some(start) = source.find(signature) else {
+        return String::from(source);
+    };
+    let end = source[start..]
+        .find("\n}")
+        .map_or(source.len(), |end| start + end);
+
+    format!(
+        "{}{}{}",
+        &source[..start],
+        f(&source[start..end]),
+        &source[end..]
+    )
+}
@@ -0,0 +1,73 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::eyre;
+
+use crate::registry::Puzzle;
+
+use super::client::Client;
+
+// Where a puzzle's input lives by default, e.g. input/year2023/day01.txt
+pub fn input_path(input_dir: &Path, year: u32, day: u32) -> PathBuf {
+    input_dir
+        .join(format!("year{year}"))
+        .join(format!("day{day:02}"))
+        .with_extension("txt")
+}
+
+// Returns None when the puzzle's input file doesn't exist, so it can be reported as missing
+// rather than stopping the whole run. An empty file is the placeholder left by `scaffold` before
+// the input is fetched, so it's missing too
+pub fn read_input(
+    puzzle: &Puzzle,
+    input_override: Option<&Path>,
+    input_dir: &Path,
+) -> color_eyre::Result<Option<String>> {
+    let path = match input_override {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            return Ok(Some(input));
+        }
+        Some(path) => path.to_path_buf(),
+        None => input_path(input_dir, puzzle.year, puzzle.day),
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(input) if input.is_empty() => Ok(None),
+        Ok(input) => Ok(Some(input)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(eyre!("Failed to read {}: {err}", path.display())),
+    }
+}
+
+// Downloads a puzzle's input into the input directory, unless it's already there. Returns whether
+// it was downloaded
+pub fn fetch_input(
+    client: &mut Client,
+    input_dir: &Path,
+    year: u32,
+    day: u32,
+) -> color_eyre::Result<bool> {
+    let path = input_path(input_dir, year, day);
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(false);
+    }
+
+    let input = client.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    // Written alongside and then moved into place, so an interrupted download isn't mistaken for
+    // the input next time
+    let partial = path.with_extension("txt.partial");
+    fs::write(&partial, input)
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|err| eyre!("Failed to write {}: {err}", path.display()))?;
+
+    Ok(true)
+}
@@ -0,0 +1,535 @@
+use colored::{ColoredString, Colorize};
+use serde::Serialize;
+
+use crate::{answer::Answer, registry::Puzzle};
+
+use super::{
+    answers::Verdict,
+    bench::{change_percent, PuzzleBench, StageTimes},
+    solve::PuzzleResult,
+    worker::Progress,
+};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Colourised table for the terminal
+    #[default]
+    Table,
+    /// JSON array with one object per puzzle
+    Json,
+    /// CSV with a header row
+    Csv,
+    /// Markdown table, e.g. for a results README
+    Markdown,
+}
+
+const MISSING_INPUT: &str = "missing input";
+
+// One row of output. Anything that wasn't produced (e.g. because the input is missing) is None
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub parse_time: Option<f32>,
+    pub part1_time: Option<f32>,
+    pub part2_time: Option<f32>,
+    pub total_time: Option<f32>,
+    pub error: Option<String>,
+    // Only set when verifying against the answers store
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1_verdict: Option<Verdict>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2_verdict: Option<Verdict>,
+}
+
+impl Record {
+    pub fn solved(puzzle: &Puzzle, result: &PuzzleResult) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            part1: result.part1.as_ref().map(|part| part.answer.clone()),
+            part2: result.part2.as_ref().map(|part| part.answer.clone()),
+            parse_time: Some(result.parse_time_s),
+            part1_time: result.part1.as_ref().map(|part| part.time_s),
+            part2_time: result.part2.as_ref().map(|part| part.time_s),
+            total_time: Some(result.total_time()),
+            error: None,
+            part1_verdict: None,
+            part2_verdict: None,
+        }
+    }
+
+    pub fn missing_input(puzzle: &Puzzle) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            part1: None,
+            part2: None,
+            parse_time: None,
+            part1_time: None,
+            part2_time: None,
+            total_time: None,
+            error: Some(String::from(MISSING_INPUT)),
+            part1_verdict: None,
+            part2_verdict: None,
+        }
+    }
+
+    // Keeps the answers from any stages that finished before the failure
+    pub fn failed(puzzle: &Puzzle, progress: &Progress, error: &str) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            part1: progress.part1.as_ref().map(|part| part.answer.clone()),
+            part2: progress.part2.as_ref().map(|part| part.answer.clone()),
+            parse_time: progress.parse_time_s,
+            part1_time: progress.part1.as_ref().map(|part| part.time_s),
+            part2_time: progress.part2.as_ref().map(|part| part.time_s),
+            total_time: None,
+            error: Some(String::from(error)),
+            part1_verdict: None,
+            part2_verdict: None,
+        }
+    }
+
+    pub fn with_verdicts(mut self, (part1, part2): (Option<Verdict>, Option<Verdict>)) -> Self {
+        self.part1_verdict = part1;
+        self.part2_verdict = part2;
+        self
+    }
+
+    // The verdict for the puzzle as a whole, i.e. the worst of the parts that were verified
+    pub fn verdict(&self) -> Option<Verdict> {
+        let verdicts = [self.part1_verdict, self.part2_verdict];
+        [Verdict::Wrong, Verdict::Unknown, Verdict::Correct]
+            .into_iter()
+            .find(|verdict| verdicts.contains(&Some(*verdict)))
+    }
+
+    // What to show in the answer cells for the human readable formats. The error goes in the
+    // first cell without an answer, as that's the part that didn't finish
+    fn answer_cells(&self) -> (String, String) {
+        let error = self.error.clone().unwrap_or_else(|| String::from("-"));
+        match (&self.part1, &self.part2) {
+            (Some(part1), Some(part2)) => (part1.cell(), part2.cell()),
+            (Some(part1), None) => (part1.cell(), error),
+            (None, Some(part2)) => (error, part2.cell()),
+            (None, None) => (error, String::from("-")),
+        }
+    }
+}
+
+// One row of benchmark output, for a single stage of a puzzle. Times are in seconds and the change
+// from the baseline is a percentage of the baseline median
+#[derive(Debug, Serialize)]
+pub struct BenchRecord {
+    pub year: u32,
+    pub day: u32,
+    pub stage: &'static str,
+    pub min: f32,
+    pub median: f32,
+    pub mean: f32,
+    pub std_dev: f32,
+    pub baseline: Option<f32>,
+    pub change: Option<f32>,
+    pub regression: bool,
+}
+
+impl BenchRecord {
+    // A regression is a stage whose median is more than `threshold` percent slower than baseline
+    pub fn from_bench(
+        puzzle: &Puzzle,
+        bench: &PuzzleBench,
+        baseline: Option<&StageTimes>,
+        threshold: f32,
+    ) -> Vec<Self> {
+        bench
+            .stages()
+            .into_iter()
+            .map(|(stage, stats)| {
+                let baseline = baseline.and_then(|times| times.get(stage));
+                let change = baseline
+                    .filter(|baseline| *baseline > 0.0)
+                    .map(|baseline| change_percent(baseline, stats.median));
+
+                Self {
+                    year: puzzle.year,
+                    day: puzzle.day,
+                    stage,
+                    min: stats.min,
+                    median: stats.median,
+                    mean: stats.mean,
+                    std_dev: stats.std_dev,
+                    baseline,
+                    change,
+                    regression: change.is_some_and(|change| change > threshold),
+                }
+            })
+            .collect()
+    }
+}
+
+// Totals for the footer. The total time is the sum of every puzzle's time, whereas the wall time
+// is how long the run took, which is shorter when puzzles are solved in parallel
+#[derive(Debug, Default)]
+pub struct Summary {
+    pub solved: usize,
+    pub missing: usize,
+    pub failed: usize,
+    pub total_time: f32,
+    pub wall_time: f32,
+}
+
+// Formats the header, one line per record and the footer for the chosen format. Each returned
+// string is complete lines, ready to be printed as they're produced
+pub struct Formatter {
+    format: OutputFormat,
+    verify: bool,
+    records: usize,
+    verdicts: Vec<Verdict>,
+    regressions: usize,
+}
+
+impl Formatter {
+    // When verifying, the verdicts are shown alongside the answers
+    pub fn new(format: OutputFormat, verify: bool) -> Self {
+        Self {
+            format,
+            verify,
+            records: 0,
+            verdicts: Vec::new(),
+            regressions: 0,
+        }
+    }
+
+    pub fn header(&self) -> String {
+        match self.format {
+            OutputFormat::Table => table_header(self.verify),
+            OutputFormat::Json => String::from("[\n"),
+            OutputFormat::Csv => {
+                let mut header = String::from(
+                    "year,day,part1,part2,parse_time,part1_time,part2_time,total_time,error",
+                );
+                if self.verify {
+                    header += ",part1_verdict,part2_verdict";
+                }
+                header + "\n"
+            }
+            OutputFormat::Markdown if self.verify => String::from(
+                "| Year | Day | Part 1 | Part 2 | Parse Time (s) | Part 1 Time (s) | Part 2 Time (s) | Total Time (s) | Verified |\n\
+                 |------|-----|--------|--------|----------------|-----------------|-----------------|----------------|----------|\n",
+            ),
+            OutputFormat::Markdown => String::from(
+                "| Year | Day | Part 1 | Part 2 | Parse Time (s) | Part 1 Time (s) | Part 2 Time (s) | Total Time (s) |\n\
+                 |------|-----|--------|--------|----------------|-----------------|-----------------|----------------|\n",
+            ),
+        }
+    }
+
+    pub fn record(&mut self, record: &Record) -> String {
+        let separator = self.json_separator();
+        self.verdicts.extend(record.verdict());
+
+        match self.format {
+            OutputFormat::Table => table_row(record, self.verify),
+            // The closing newline is written by the next record or the footer, as JSON doesn't
+            // allow a trailing comma. Serializing strings and numbers can't fail
+            OutputFormat::Json => {
+                format!("{separator}  {}", serde_json::to_string(record).unwrap())
+            }
+            OutputFormat::Csv => csv_row(record, self.verify),
+            OutputFormat::Markdown => markdown_row(record, self.verify),
+        }
+    }
+
+    pub fn bench_header(&self) -> String {
+        match self.format {
+            OutputFormat::Table => format!(
+                "{:^10}┃{:^7}┃{:^7}┃{:^14}┃{:^14}┃{:^14}┃{:^14}┃{:^14}┃{:^10}\n{:━^10}╋{:━^7}╋{:━^7}╋{:━^14}╋{:━^14}╋{:━^14}╋{:━^14}╋{:━^14}╋{:━^10}\n",
+                "Year".bold(),
+                "Day".bold(),
+                "Stage".bold(),
+                "Min(s)".bold(),
+                "Median(s)".bold(),
+                "Mean(s)".bold(),
+                "Std Dev(s)".bold(),
+                "Baseline(s)".bold(),
+                "Change".bold(),
+                "━", "━", "━", "━", "━", "━", "━", "━", "━"
+            ),
+            OutputFormat::Json => String::from("[\n"),
+            OutputFormat::Csv => String::from(
+                "year,day,stage,min,median,mean,std_dev,baseline,change,regression\n",
+            ),
+            OutputFormat::Markdown => String::from(
+                "| Year | Day | Stage | Min (s) | Median (s) | Mean (s) | Std Dev (s) | Baseline (s) | Change |\n\
+                 |------|-----|-------|---------|------------|----------|-------------|--------------|--------|\n",
+            ),
+        }
+    }
+
+    pub fn bench_record(&mut self, record: &BenchRecord) -> String {
+        let separator = self.json_separator();
+        if record.regression {
+            self.regressions += 1;
+        }
+
+        let change = record
+            .change
+            .map_or(String::from("-"), |change| format!("{change:+.1}%"));
+
+        match self.format {
+            OutputFormat::Table => format!(
+                "{:<10}┃ {:<6}┃ {:<6}┃ {:<13}┃ {:<13}┃ {:<13}┃ {:<13}┃ {:<13}┃ {:<9}\n",
+                format!("year{}", record.year),
+                format!("day{:02}", record.day),
+                record.stage,
+                format!("{:.9}", record.min),
+                match record.median {
+                    median if median < 0.5 => format!("{median:.9}").green(),
+                    median if median < 1.0 => format!("{median:.9}").blue(),
+                    median => format!("{median:.9}").red(),
+                },
+                format!("{:.9}", record.mean),
+                format!("{:.9}", record.std_dev),
+                record
+                    .baseline
+                    .map_or(String::from("-"), |time| format!("{time:.9}")),
+                match record.change {
+                    _ if record.regression => change.red(),
+                    Some(delta) if delta < 0.0 => change.green(),
+                    _ => change.normal(),
+                },
+            ),
+            OutputFormat::Json => {
+                format!("{separator}  {}", serde_json::to_string(record).unwrap())
+            }
+            OutputFormat::Csv => format!(
+                "{},{},{},{},{},{},{},{},{},{}\n",
+                record.year,
+                record.day,
+                record.stage,
+                record.min,
+                record.median,
+                record.mean,
+                record.std_dev,
+                csv_field(&record.baseline),
+                csv_field(&record.change),
+                record.regression,
+            ),
+            OutputFormat::Markdown => format!(
+                "| {} | {} | {} | {} | {} | {} | {} | {} | {}{} |\n",
+                record.year,
+                record.day,
+                record.stage,
+                record.min,
+                record.median,
+                record.mean,
+                record.std_dev,
+                record
+                    .baseline
+                    .map_or(String::from("-"), |time| time.to_string()),
+                change,
+                if record.regression {
+                    " (regression)"
+                } else {
+                    ""
+                },
+            ),
+        }
+    }
+
+    pub fn footer(&self, summary: &Summary) -> String {
+        match self.format {
+            OutputFormat::Table => {
+                let mut footer = String::new();
+                if summary.solved + summary.missing + summary.failed > 1 {
+                    footer += &format!(
+                        "\nSolved {} puzzles in {}s ({}s wall clock)\n",
+                        summary.solved, summary.total_time, summary.wall_time
+                    );
+                    if summary.missing > 0 {
+                        footer +=
+                            &format!("{} puzzles skipped due to missing input\n", summary.missing);
+                    }
+                }
+                if summary.failed > 0 {
+                    footer += &format!("{} puzzles failed\n", summary.failed.to_string().red());
+                }
+                if self.regressions > 0 {
+                    footer += &format!(
+                        "{} stages regressed against the baseline\n",
+                        self.regressions.to_string().red()
+                    );
+                }
+                if self.verify {
+                    let count = |verdict| self.verdicts.iter().filter(|v| **v == verdict).count();
+                    footer += &format!(
+                        "Verified {} correct, {} wrong, {} unknown\n",
+                        count(Verdict::Correct).to_string().green(),
+                        count(Verdict::Wrong).to_string().red(),
+                        count(Verdict::Unknown).to_string().yellow(),
+                    );
+                }
+                footer
+            }
+            OutputFormat::Json if self.records > 0 => String::from("\n]\n"),
+            OutputFormat::Json => String::from("]\n"),
+            _ => String::new(),
+        }
+    }
+
+    // JSON records are separated by commas, so every record after the first starts with one
+    fn json_separator(&mut self) -> &'static str {
+        self.records += 1;
+        if self.records > 1 {
+            ",\n"
+        } else {
+            ""
+        }
+    }
+}
+
+fn table_header(verify: bool) -> String {
+    let mut header = format!(
+        "{:^10}┃{:^7}┃{:^20}┃{:^20}┃{:^18}┃{:^18}┃{:^18}┃{:^15}",
+        "Year".bold(),
+        "Day".bold(),
+        "Part 1".bold(),
+        "Part 2".bold(),
+        "Parse Time(s)".bold(),
+        "Part 1 Time(s)".bold(),
+        "Part 2 Time(s)".bold(),
+        "Total Time(s)".bold(),
+    );
+    if verify {
+        header += &format!("┃{:^10}", "Verified".bold());
+    }
+
+    header += &format!(
+        "\n{:━^10}╋{:━^7}╋{:━^20}╋{:━^20}╋{:━^18}╋{:━^18}╋{:━^18}╋{:━^15}",
+        "━", "━", "━", "━", "━", "━", "━", "━"
+    );
+    if verify {
+        header += &format!("╋{:━^10}", "━");
+    }
+
+    header + "\n"
+}
+
+fn table_row(record: &Record, verify: bool) -> String {
+    let (part1, part2) = record.answer_cells();
+    let colorize_answer =
+        |cell: String, answer: &Option<Answer>, verdict: Option<Verdict>| match (answer, verdict) {
+            (None, _) if record.error.as_deref() == Some(MISSING_INPUT) => cell.yellow(),
+            (None, _) => cell.red(),
+            (Some(_), Some(Verdict::Correct)) => cell.green(),
+            (Some(_), Some(Verdict::Wrong)) => cell.red(),
+            (Some(_), _) => cell.normal(),
+        };
+
+    let mut row = format!(
+        "{:<10}┃ {:<6}┃ {:<19}┃ {:<19}┃ {:<17}┃ {:<17}┃ {:<17}┃ {:<14}",
+        format!("year{}", record.year),
+        format!("day{:02}", record.day),
+        colorize_answer(part1, &record.part1, record.part1_verdict),
+        colorize_answer(part2, &record.part2, record.part2_verdict),
+        colorize_time(record.parse_time),
+        colorize_time(record.part1_time),
+        colorize_time(record.part2_time),
+        colorize_time(record.total_time),
+    );
+    if verify {
+        row += &format!("┃ {:<9}", colorize_verdict(record.verdict()));
+    }
+    row += "\n";
+
+    // Pictures don't fit in a cell, so they're drawn under the row to be read
+    for answer in [&record.part1, &record.part2].into_iter().flatten() {
+        if let Answer::Bitmap(bitmap) = answer {
+            for line in bitmap.lines() {
+                row += &format!("{:<10}┃ {line}\n", "");
+            }
+        }
+    }
+
+    row
+}
+
+fn colorize_verdict(verdict: Option<Verdict>) -> ColoredString {
+    match verdict {
+        Some(verdict @ Verdict::Correct) => verdict.to_string().green(),
+        Some(verdict @ Verdict::Wrong) => verdict.to_string().red(),
+        Some(verdict @ Verdict::Unknown) => verdict.to_string().yellow(),
+        None => "-".normal(),
+    }
+}
+
+fn colorize_time(time: Option<f32>) -> ColoredString {
+    match time {
+        Some(time) if time < 0.5 => format!("{}", time).green(),
+        Some(time) if time < 1.0 => format!("{}", time).blue(),
+        Some(time) => format!("{}", time).red(),
+        None => "-".normal(),
+    }
+}
+
+fn csv_row(record: &Record, verify: bool) -> String {
+    let mut fields = vec![
+        record.year.to_string(),
+        record.day.to_string(),
+        csv_field(&record.part1),
+        csv_field(&record.part2),
+        csv_field(&record.parse_time),
+        csv_field(&record.part1_time),
+        csv_field(&record.part2_time),
+        csv_field(&record.total_time),
+        csv_field(&record.error),
+    ];
+    if verify {
+        fields.push(csv_field(&record.part1_verdict));
+        fields.push(csv_field(&record.part2_verdict));
+    }
+
+    format!("{}\n", fields.join(","))
+}
+
+fn csv_field<T: ToString>(value: &Option<T>) -> String {
+    let value = value.as_ref().map(T::to_string).unwrap_or_default();
+
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn markdown_row(record: &Record, verify: bool) -> String {
+    let time = |time: Option<f32>| time.map_or(String::from("-"), |time| time.to_string());
+    let escape = |cell: String| cell.replace('|', "\\|");
+    let (part1, part2) = record.answer_cells();
+
+    let mut row = format!(
+        "| {} | {} | {} | {} | {} | {} | {} | {} |",
+        record.year,
+        record.day,
+        escape(part1),
+        escape(part2),
+        time(record.parse_time),
+        time(record.part1_time),
+        time(record.part2_time),
+        time(record.total_time),
+    );
+    if verify {
+        row += &format!(
+            " {} |",
+            record
+                .verdict()
+                .map_or(String::from("-"), |verdict| verdict.to_string())
+        );
+    }
+
+    row + "\n"
+}
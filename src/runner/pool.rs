@@ -0,0 +1,58 @@
+use std::{
+    collections::BTreeMap,
+    ops::ControlFlow,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+// Runs `work` on every item using up to `jobs` threads, handing each result to `emit` on the
+// calling thread in the same order as `items`, however the work finishes. Once `emit` breaks no
+// more work is started, though work that's already running is waited for
+pub fn run_ordered<T, R, W, E>(items: &[T], jobs: usize, work: W, mut emit: E)
+where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    E: FnMut(&T, R) -> ControlFlow<()>,
+{
+    let next = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, stop, work) = (&next, &stop, &work);
+            scope.spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    if sender.send((index, work(item))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        // Only the workers hold senders now, so the loop below ends once they've all finished
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_emit = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+
+            while let Some(result) = pending.remove(&next_emit) {
+                if emit(&items[next_emit], result).is_break() {
+                    stop.store(true, Ordering::Relaxed);
+                    return;
+                }
+                next_emit += 1;
+            }
+        }
+    });
+}
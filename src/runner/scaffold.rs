@@ -0,0 +1,253 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use color_eyre::eyre::eyre;
+
+use super::examples::{fill_test, Examples};
+
+// Sets up a new day in the repo at `root`:
+//
+// src/yearYYYY/dayDD.rs          from .aoc/template.rs, registered by build.rs
+// tests/yearYYYY/dayDD_test.rs   from .aoc/test_template.rs, registered in tests/test.rs
+// input/yearYYYY/dayDD.txt       an empty placeholder until the input is fetched
+//
+// Anything that already exists is left alone, so it's safe to run again. The one exception is a
+// test that's still as generated, which has its examples filled in once they're known
+pub struct Scaffold {
+    root: PathBuf,
+    year: u32,
+    day: u32,
+    examples: Option<Examples>,
+    // What this run changed, so it can be undone if the result doesn't compile. Directories come
+    // before the files in them
+    created: Vec<PathBuf>,
+    replaced: Vec<(PathBuf, String)>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    Created,
+    Updated,
+    Exists,
+}
+
+impl Scaffold {
+    pub fn new(root: &Path, year: u32, day: u32) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            year,
+            day,
+            examples: None,
+            created: Vec::new(),
+            replaced: Vec::new(),
+        }
+    }
+
+    // Fills in the test's example input and assertions, see `examples::extract`
+    pub fn with_examples(mut self, examples: Examples) -> Self {
+        self.examples = Some(examples);
+        self
+    }
+
+    // Each step, with the file it applies to relative to the root
+    pub fn run(&mut self) -> color_eyre::Result<Vec<(PathBuf, Status)>> {
+        let year = format!("year{}", self.year);
+        let day = format!("day{:02}", self.day);
+        let root = self.root.clone();
+
+        let solution = Path::new("src").join(&year).join(&day).with_extension("rs");
+        let solution_status = self.create(&solution, || read_template(&root, "template.rs"))?;
+
+        let test = Path::new("tests")
+            .join(&year)
+            .join(format!("{day}_test.rs"));
+        let generated = read_template(&root, "test_template.rs")?
+            .replace("YYYY", &year)
+            .replace("DD", &day);
+        let filled = match &self.examples {
+            Some(examples) => fill_test(&generated, examples),
+            None => generated.clone(),
+        };
+        let test_status = match self.create(&test, || Ok(filled.clone()))? {
+            Status::Exists if filled != generated => self.replace(&test, |contents| {
+                Ok((contents == generated).then_some(filled))
+            })?,
+            status => status,
+        };
+
+        let registry = Path::new("tests").join("test.rs");
+        let (year_number, day_number) = (self.year, self.day);
+        let registry_status = self.replace(&registry, |contents| {
+            register_test(contents, year_number, day_number)
+                .map_err(|err| eyre!("Failed to register the test in tests/test.rs: {err}"))
+        })?;
+
+        let input = Path::new("input")
+            .join(&year)
+            .join(&day)
+            .with_extension("txt");
+        let input_status = self.create(&input, || Ok(String::new()))?;
+
+        Ok(vec![
+            (solution, solution_status),
+            (test, test_status),
+            (registry, registry_status),
+            (input, input_status),
+        ])
+    }
+
+    pub fn is_changed(&self) -> bool {
+        !self.created.is_empty() || !self.replaced.is_empty()
+    }
+
+    // Makes sure the new modules are picked up and compile, including the tests
+    pub fn check(&self) -> color_eyre::Result<()> {
+        let cargo = env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+        let output = Command::new(cargo)
+            .args([
+                "check",
+                "--all-targets",
+                "--quiet",
+                "--message-format=short",
+            ])
+            .current_dir(&self.root)
+            .output()
+            .map_err(|err| eyre!("Failed to run cargo check: {err}"))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(eyre!(
+                "The scaffolded puzzle doesn't compile:\n{}",
+                String::from_utf8_lossy(&output.stderr).trim_end()
+            ))
+        }
+    }
+
+    // Removes whatever this run created and restores whatever it changed
+    pub fn undo(&mut self) -> color_eyre::Result<()> {
+        for (path, contents) in self.replaced.drain(..) {
+            fs::write(self.root.join(path), contents)?;
+        }
+        for path in self.created.drain(..).rev() {
+            let full_path = self.root.join(&path);
+            let removed = if full_path.is_dir() {
+                fs::remove_dir(full_path)
+            } else {
+                fs::remove_file(full_path)
+            };
+            removed.map_err(|err| eyre!("Failed to remove {}: {err}", path.display()))?;
+        }
+
+        Ok(())
+    }
+
+    fn create<F>(&mut self, path: &Path, contents: F) -> color_eyre::Result<Status>
+    where
+        F: FnOnce() -> color_eyre::Result<String>,
+    {
+        let full_path = self.root.join(path);
+        if full_path.exists() {
+            return Ok(Status::Exists);
+        }
+
+        let mut missing_dirs = path
+            .ancestors()
+            .skip(1)
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .take_while(|dir| !self.root.join(dir).exists())
+            .map(Path::to_path_buf)
+            .collect::<Vec<_>>();
+        missing_dirs.reverse();
+        for dir in missing_dirs {
+            fs::create_dir(self.root.join(&dir))?;
+            self.created.push(dir);
+        }
+
+        fs::write(&full_path, contents()?)
+            .map_err(|err| eyre!("Failed to write {}: {err}", path.display()))?;
+        self.created.push(path.to_path_buf());
+
+        Ok(Status::Created)
+    }
+
+    // Rewrites the file with whatever `f` returns, leaving it alone if that's None
+    fn replace<F>(&mut self, path: &Path, f: F) -> color_eyre::Result<Status>
+    where
+        F: FnOnce(&str) -> color_eyre::Result<Option<String>>,
+    {
+        let full_path = self.root.join(path);
+        let contents = fs::read_to_string(&full_path)
+            .map_err(|err| eyre!("Failed to read {}: {err}", path.display()))?;
+
+        match f(&contents)? {
+            Some(replacement) => {
+                fs::write(&full_path, replacement)
+                    .map_err(|err| eyre!("Failed to write {}: {err}", path.display()))?;
+                self.replaced.push((path.to_path_buf(), contents));
+                Ok(Status::Updated)
+            }
+            None => Ok(Status::Exists),
+        }
+    }
+}
+
+fn read_template(root: &Path, name: &str) -> color_eyre::Result<String> {
+    let path = root.join(".aoc").join(name);
+    fs::read_to_string(&path).map_err(|err| eyre!("Failed to read {}: {err}", path.display()))
+}
+
+// Adds `mod dayDD_test;` to the `mod yearYYYY { ... }` block of tests/test.rs, keeping the days and
+// years in order and adding the year's block if there isn't one yet. Returns None when the test is
+// already registered
+pub fn register_test(contents: &str, year: u32, day: u32) -> color_eyre::Result<Option<String>> {
+    let year_line = format!("mod year{year} {{");
+    let day_line = format!("    mod day{day:02}_test;");
+    let mut lines = contents.lines().collect::<Vec<_>>();
+
+    let Some(start) = lines.iter().position(|line| *line == year_line) else {
+        // Year blocks are only ever followed by later years
+        let insert_at = lines
+            .iter()
+            .position(|line| {
+                line.strip_prefix("mod year")
+                    .and_then(|rest| rest.strip_suffix(" {"))
+                    .and_then(|other| other.parse::<u32>().ok())
+                    .is_some_and(|other| other > year)
+            })
+            .unwrap_or(lines.len());
+
+        let mut block = vec![year_line.as_str(), day_line.as_str(), "}"];
+        if insert_at > 0 && !lines[insert_at - 1].is_empty() {
+            block.insert(0, "");
+        }
+        if insert_at < lines.len() {
+            block.push("");
+        }
+        lines.splice(insert_at..insert_at, block);
+
+        return Ok(Some(lines.join("\n") + "\n"));
+    };
+
+    let end = lines[start..]
+        .iter()
+        .position(|line| *line == "}")
+        .map(|end| start + end)
+        .ok_or_else(|| eyre!("`{year_line}` isn't closed"))?;
+
+    if lines[start..end].contains(&day_line.as_str()) {
+        return Ok(None);
+    }
+
+    // After the last earlier day, so any comments at the end of the block stay there
+    let insert_at = lines[start + 1..end]
+        .iter()
+        .rposition(|line| line.starts_with("    mod day") && line.trim_end() < day_line.as_str())
+        .map_or(start + 1, |earlier| start + 1 + earlier + 1);
+    lines.insert(insert_at, &day_line);
+
+    Ok(Some(lines.join("\n") + "\n"))
+}
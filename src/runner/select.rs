@@ -0,0 +1,188 @@
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+use color_eyre::eyre::eyre;
+
+use crate::registry::{self, Puzzle};
+
+// Picks puzzles out of the registry, e.g.
+//
+// "2023"             every day of 2023
+// "2023:1-10"        days 1 to 10 of 2023
+// "2024:5,7,9"       days 5, 7 and 9 of 2024
+// "*:25"             day 25 of every year
+// "2023/5"           day 5 of 2023, also written "2023::5" or "year2023::day05"
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Selector {
+    text: String,
+    // None matches anything
+    years: Option<Vec<RangeInclusive<u32>>>,
+    days: Option<Vec<RangeInclusive<u32>>>,
+}
+
+impl Selector {
+    pub fn matches(&self, puzzle: &Puzzle) -> bool {
+        let contains = |ranges: &Option<Vec<RangeInclusive<u32>>>, value| {
+            ranges
+                .as_ref()
+                .is_none_or(|ranges| ranges.iter().any(|range| range.contains(&value)))
+        };
+
+        contains(&self.years, puzzle.year) && contains(&self.days, puzzle.day)
+    }
+
+    // The year and day when the selector names exactly one puzzle, e.g. "2023/5", whether or not
+    // it has been solved yet
+    pub fn single(&self) -> Option<(u32, u32)> {
+        match self.named_puzzles()[..] {
+            [puzzle] if self.years.as_ref()?.len() == 1 && self.days.as_ref()?.len() == 1 => {
+                Some(puzzle)
+            }
+            _ => None,
+        }
+    }
+
+    // Puzzles named individually, as opposed to through a range or wildcard, which must exist
+    fn named_puzzles(&self) -> Vec<(u32, u32)> {
+        let singles = |ranges: &Option<Vec<RangeInclusive<u32>>>| {
+            ranges
+                .iter()
+                .flatten()
+                .filter(|range| range.start() == range.end())
+                .map(|range| *range.start())
+                .collect::<Vec<_>>()
+        };
+
+        let days = singles(&self.days);
+        singles(&self.years)
+            .into_iter()
+            .flat_map(|year| days.iter().map(move |day| (year, *day)))
+            .collect()
+    }
+}
+
+impl FromStr for Selector {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (years, days) = match ["::", ":", "/"]
+            .into_iter()
+            .find_map(|separator| text.split_once(separator))
+        {
+            Some((years, days)) => (years, Some(days)),
+            None => (text, None),
+        };
+
+        let years = parse_ranges(years, "year", 2015..=9999)?;
+        let days = match days {
+            Some(days) => parse_ranges(days, "day", 1..=25)?,
+            None => None,
+        };
+
+        Ok(Self {
+            text: String::from(text),
+            years,
+            days,
+        })
+    }
+}
+
+impl Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+// Parses "*" or a comma separated list of numbers and ranges, e.g. "1-10" or "5,7,9". Each
+// number can have the module name's prefix, e.g. "year2023" or "day05"
+fn parse_ranges(
+    text: &str,
+    prefix: &str,
+    valid: RangeInclusive<u32>,
+) -> Result<Option<Vec<RangeInclusive<u32>>>, String> {
+    if text == "*" {
+        return Ok(None);
+    }
+
+    let parse = |number: &str| {
+        let number = number.trim();
+        let value = number
+            .strip_prefix(prefix)
+            .unwrap_or(number)
+            .parse::<u32>()
+            .map_err(|_| format!("'{number}' isn't a valid {prefix}"))?;
+
+        if valid.contains(&value) {
+            Ok(value)
+        } else {
+            Err(format!(
+                "{prefix} {value} isn't between {} and {}",
+                valid.start(),
+                valid.end()
+            ))
+        }
+    };
+
+    text.split(',')
+        .map(|item| match item.split_once('-') {
+            Some((start, end)) => Ok(parse(start)?..=parse(end)?),
+            None => parse(item).map(|value| value..=value),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
+
+// The puzzles matching any of the selectors, in registry order, or every puzzle when there are no
+// selectors. A selector that doesn't match anything is an error rather than an empty run. Work in
+// progress puzzles are only picked when a selector names them on their own
+pub fn select(selectors: &[Selector]) -> color_eyre::Result<Vec<&'static Puzzle>> {
+    if selectors.is_empty() {
+        return Ok(registry::puzzles()
+            .iter()
+            .filter(|puzzle| !puzzle.wip)
+            .collect());
+    }
+
+    for selector in selectors {
+        for (year, day) in selector.named_puzzles() {
+            if registry::find(year, day).is_none() {
+                return Err(eyre!("No such puzzle: year{year}::day{day:02}"));
+            }
+        }
+        if !registry::puzzles()
+            .iter()
+            .any(|puzzle| selector.matches(puzzle))
+        {
+            return Err(eyre!("No such puzzle: {selector}"));
+        }
+    }
+
+    let named = selectors
+        .iter()
+        .flat_map(Selector::named_puzzles)
+        .collect::<Vec<_>>();
+    Ok(registry::puzzles()
+        .iter()
+        .filter(|puzzle| selectors.iter().any(|selector| selector.matches(puzzle)))
+        .filter(|puzzle| !puzzle.wip || named.contains(&(puzzle.year, puzzle.day)))
+        .collect())
+}
+
+// The year and day of each puzzle the selectors pick out, including puzzles named on their own that
+// haven't been solved yet, e.g. to fetch their inputs
+pub fn select_days(selectors: &[Selector]) -> color_eyre::Result<Vec<(u32, u32)>> {
+    let mut days = Vec::new();
+    for selector in selectors {
+        match selector.single() {
+            Some(day) => days.push(day),
+            None => days.extend(
+                select(std::slice::from_ref(selector))?
+                    .iter()
+                    .map(|puzzle| (puzzle.year, puzzle.day)),
+            ),
+        }
+    }
+
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
@@ -0,0 +1,85 @@
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u32 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+// Which parts of a puzzle to solve
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Parts {
+    pub part1: bool,
+    pub part2: bool,
+}
+
+impl Parts {
+    pub const BOTH: Self = Self {
+        part1: true,
+        part2: true,
+    };
+}
+
+impl From<Option<Part>> for Parts {
+    fn from(part: Option<Part>) -> Self {
+        match part {
+            Some(Part::One) => Self {
+                part1: true,
+                part2: false,
+            },
+            Some(Part::Two) => Self {
+                part1: false,
+                part2: true,
+            },
+            None => Self::BOTH,
+        }
+    }
+}
+
+// A part is None when it wasn't selected to be solved
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PuzzleResult {
+    pub parse_time_s: f32,
+    pub part1: Option<PartResult>,
+    pub part2: Option<PartResult>,
+}
+
+impl PuzzleResult {
+    pub fn total_time(&self) -> f32 {
+        self.parse_time_s
+            + self.part1.as_ref().map_or(0.0, |part| part.time_s)
+            + self.part2.as_ref().map_or(0.0, |part| part.time_s)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PartResult {
+    pub answer: Answer,
+    pub time_s: f32,
+}
+
+pub fn timed_fn<F, T>(f: F) -> color_eyre::Result<(T, f32)>
+where
+    F: Fn() -> color_eyre::Result<T>,
+{
+    let start = Instant::now();
+    let result = f()?;
+    let elapsed_s = start.elapsed().as_secs_f32();
+
+    Ok((result, elapsed_s))
+}
@@ -0,0 +1,198 @@
+use std::{collections::BTreeMap, fs, io, path::Path, time::Duration};
+
+use color_eyre::eyre::eyre;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::solve::Part;
+
+// What the site said about a submitted answer
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // Answered too soon after the last wrong answer, so it wasn't checked
+    RateLimited { wait: Duration },
+    // The part has already been solved, or isn't unlocked yet
+    WrongLevel,
+}
+
+impl Response {
+    // Whether the answer was checked, so the response is worth logging
+    pub fn is_verdict(&self) -> bool {
+        matches!(
+            self,
+            Self::Correct | Self::TooHigh | Self::TooLow | Self::Wrong
+        )
+    }
+}
+
+impl std::fmt::Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "too high"),
+            Self::TooLow => write!(f, "too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited { wait } => {
+                let secs = wait.as_secs();
+                match secs / 60 {
+                    0 => write!(f, "rate limited, wait {}s", secs % 60),
+                    mins => write!(f, "rate limited, wait {mins}m {}s", secs % 60),
+                }
+            }
+            Self::WrongLevel => write!(f, "already solved or not unlocked yet"),
+        }
+    }
+}
+
+// Works out the response from the page the site returns after submitting an answer. Only the
+// <article> holds the message, the rest of the page is the site's header and footer
+pub fn parse_response(html: &str) -> color_eyre::Result<Response> {
+    let article = html
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let text = Regex::new("<[^>]*>")
+        .unwrap()
+        .replace_all(article, "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let response = if text.contains("That's the right answer") {
+        Response::Correct
+    } else if text.contains("your answer is too high") {
+        Response::TooHigh
+    } else if text.contains("your answer is too low") {
+        Response::TooLow
+    } else if text.contains("That's not the right answer") {
+        Response::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        // e.g. "You have 4m 37s left to wait", or "You have 37s left to wait"
+        let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")
+            .unwrap()
+            .captures(&text)
+            .map_or(0, |captures| {
+                let number = |i| {
+                    captures
+                        .get(i)
+                        .map_or(0, |m: regex::Match| m.as_str().parse::<u64>().unwrap())
+                };
+                number(1) * 60 + number(2)
+            });
+        Response::RateLimited {
+            wait: Duration::from_secs(wait),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Response::WrongLevel
+    } else {
+        return Err(eyre!("Unrecognised response: {text}"));
+    };
+
+    Ok(response)
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Submission {
+    pub answer: String,
+    pub response: Response,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+struct DaySubmissions {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Submission>,
+}
+
+// Every answer that's been checked by the site, keyed by puzzle e.g. "year2024::day16", so the same
+// wrong answer is never submitted twice
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct SubmissionLog {
+    puzzles: BTreeMap<String, DaySubmissions>,
+}
+
+impl SubmissionLog {
+    // A log that hasn't been saved yet is empty
+    pub fn load(path: &Path) -> color_eyre::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| eyre!("Failed to parse {}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(eyre!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn submissions(&self, puzzle: &str, part: Part) -> &[Submission] {
+        match (self.puzzles.get(puzzle), part) {
+            (Some(day), Part::One) => &day.part1,
+            (Some(day), Part::Two) => &day.part2,
+            (None, _) => &[],
+        }
+    }
+
+    // The response the answer would get, if it can be told from the earlier submissions. Numeric
+    // answers are also checked against the bounds that "too high" and "too low" give
+    pub fn known_response(&self, puzzle: &str, part: Part, answer: &str) -> Option<Response> {
+        let submissions = self.submissions(puzzle, part);
+        if let Some(submission) = submissions
+            .iter()
+            .find(|submission| submission.answer == answer)
+        {
+            return Some(submission.response);
+        }
+
+        let number = answer.parse::<i64>().ok();
+        submissions.iter().find_map(|submission| {
+            let bound = submission.answer.parse::<i64>().ok();
+            match (submission.response, number, bound) {
+                // The correct answer is known, so any other answer is wrong
+                (Response::Correct, Some(number), Some(correct)) if number > correct => {
+                    Some(Response::TooHigh)
+                }
+                (Response::Correct, Some(number), Some(correct)) if number < correct => {
+                    Some(Response::TooLow)
+                }
+                (Response::Correct, _, _) => Some(Response::Wrong),
+                (Response::TooHigh, Some(number), Some(high)) if number >= high => {
+                    Some(Response::TooHigh)
+                }
+                (Response::TooLow, Some(number), Some(low)) if number <= low => {
+                    Some(Response::TooLow)
+                }
+                _ => None,
+            }
+        })
+    }
+
+    // Only answers the site actually checked are kept
+    pub fn record(&mut self, puzzle: &str, part: Part, answer: &str, response: Response) {
+        if !response.is_verdict() {
+            return;
+        }
+
+        let day = self.puzzles.entry(String::from(puzzle)).or_default();
+        let submissions = match part {
+            Part::One => &mut day.part1,
+            Part::Two => &mut day.part2,
+        };
+        submissions.push(Submission {
+            answer: String::from(answer),
+            response,
+        });
+    }
+
+    pub fn save(&self, path: &Path) -> color_eyre::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let contents = toml::to_string(self)?;
+        fs::write(path, contents).map_err(|err| eyre!("Failed to write {}: {err}", path.display()))
+    }
+}
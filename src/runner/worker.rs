@@ -0,0 +1,362 @@
+use std::{
+    any::Any,
+    io::{self, BufRead, BufReader, Read, Write},
+    panic,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::Puzzle;
+
+use super::{
+    bench::{bench, PuzzleBench},
+    solve::{timed_fn, Part, PartResult, Parts, PuzzleResult},
+};
+
+// Each puzzle is solved in a child process (see `Worker`), so a panic or stack overflow only takes
+// down that puzzle rather than the whole run. The child reports back on stdout, one message per
+// line, with this prefix so anything a solver prints itself can be told apart
+const MESSAGE_PREFIX: &str = "aoc-worker: ";
+
+// Recursive solutions (e.g. the DFS in year2023::day23) need far more than the default stack
+const STACK_SIZE: usize = 512 * 1024 * 1024;
+
+// Set by the panic hook in the child, so the message and location can be reported
+static PANIC_MESSAGE: Mutex<Option<String>> = Mutex::new(None);
+
+#[derive(Debug, Deserialize, Serialize)]
+enum Message {
+    Parsed(f32),
+    Part1(PartResult),
+    Part2(PartResult),
+    Benched(PuzzleBench),
+    // The stage isn't known when the solver panics, in which case it's worked out from the
+    // messages before this one
+    Failed {
+        stage: Option<String>,
+        error: String,
+    },
+}
+
+// What happened to a puzzle
+#[derive(Debug)]
+pub enum Outcome {
+    MissingInput,
+    Solved(PuzzleResult),
+    Benched(PuzzleBench),
+    // The error is prefixed with the stage that failed, and whatever the stages before it
+    // produced is kept so it can still be shown
+    Failed { error: String, progress: Progress },
+}
+
+// The stages of a puzzle that finished before it failed
+#[derive(Debug, Default)]
+pub struct Progress {
+    pub parse_time_s: Option<f32>,
+    pub part1: Option<PartResult>,
+    pub part2: Option<PartResult>,
+}
+
+impl Progress {
+    // The stage that was running when the puzzle stopped
+    fn stage(&self, parts: Parts) -> &'static str {
+        if self.parse_time_s.is_none() {
+            "parse"
+        } else if parts.part1 && self.part1.is_none() {
+            "part1"
+        } else {
+            "part2"
+        }
+    }
+
+    fn is_finished(&self, parts: Parts) -> bool {
+        self.parse_time_s.is_some()
+            && (self.part1.is_some() || !parts.part1)
+            && (self.part2.is_some() || !parts.part2)
+    }
+}
+
+// Runs puzzles in child processes of the `exe` binary, which must handle the `worker` subcommand
+// by calling `run_worker`
+pub struct Worker {
+    exe: PathBuf,
+    bench_runs: Option<u32>,
+    debug: bool,
+    timeout: Option<Duration>,
+    part: Option<Part>,
+}
+
+impl Worker {
+    pub fn new(exe: &Path, bench_runs: Option<u32>, debug: bool) -> Self {
+        Self {
+            exe: exe.to_path_buf(),
+            bench_runs,
+            debug,
+            timeout: None,
+            part: None,
+        }
+    }
+
+    // Only solves the one part, rather than both
+    pub fn with_part(mut self, part: Option<Part>) -> Self {
+        self.part = part;
+        self
+    }
+
+    // Overrides the puzzles' own timeouts
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn run(&self, puzzle: &Puzzle, input: &str) -> Outcome {
+        let mut command = Command::new(&self.exe);
+        if self.debug {
+            command.arg("--debug");
+        }
+        command
+            .args(["worker", &puzzle.year.to_string(), &puzzle.day.to_string()])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if let Some(runs) = self.bench_runs {
+            command.args(["--bench", &runs.to_string()]);
+        }
+        match self.part {
+            Some(Part::One) => command.args(["--part", "1"]),
+            Some(Part::Two) => command.args(["--part", "2"]),
+            None => &mut command,
+        };
+
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(err) => return failed("worker", format!("Failed to start worker: {err}")),
+        };
+
+        // Both pipes are drained on their own threads, so the child can't block on a full pipe
+        // while it's being timed
+        let (sender, receiver) = mpsc::channel();
+        let stdout = child.stdout.take().map(BufReader::new);
+        thread::spawn(move || {
+            let messages = stdout
+                .into_iter()
+                .flat_map(|stdout| stdout.lines().map_while(Result::ok))
+                .filter_map(|line| {
+                    let message = line.strip_prefix(MESSAGE_PREFIX)?;
+                    serde_json::from_str::<Message>(message).ok()
+                });
+            for message in messages {
+                if sender.send(message).is_err() {
+                    break;
+                }
+            }
+        });
+        let mut stderr = child.stderr.take();
+        let stderr = thread::spawn(move || {
+            let mut contents = String::new();
+            if let Some(stderr) = stderr.as_mut() {
+                let _ = stderr.read_to_string(&mut contents);
+            }
+            contents
+        });
+
+        // The child reads all of its input before doing anything else. If it has already died,
+        // the reason is picked up from its exit status below
+        if let Some(mut stdin) = child.stdin.take() {
+            let _ = stdin.write_all(input.as_bytes());
+        }
+
+        // Each stage gets the full timeout, whereas a benchmark is one long stage covering every
+        // stage of every run, plus the warm-up
+        let timeout = self.timeout.unwrap_or(puzzle.timeout);
+        let stage_timeout = match self.bench_runs {
+            Some(runs) => {
+                let parts = Parts::from(self.part);
+                let stages = 1 + u32::from(parts.part1) + u32::from(parts.part2);
+                timeout
+                    .saturating_mul(stages)
+                    .saturating_mul(runs.saturating_add(1))
+            }
+            None => timeout,
+        };
+
+        let mut progress = Progress::default();
+        let mut outcome = None;
+        loop {
+            match receiver.recv_timeout(stage_timeout) {
+                Ok(Message::Parsed(time_s)) => progress.parse_time_s = Some(time_s),
+                Ok(Message::Part1(result)) => progress.part1 = Some(result),
+                Ok(Message::Part2(result)) => progress.part2 = Some(result),
+                Ok(Message::Benched(bench)) => outcome = Some(Outcome::Benched(bench)),
+                Ok(Message::Failed { stage, error }) => {
+                    let stage = stage.unwrap_or_else(|| String::from(self.stage(&progress)));
+                    outcome = Some(Outcome::Failed {
+                        error: format!("{stage}: {error}"),
+                        progress: std::mem::take(&mut progress),
+                    });
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Outcome::Failed {
+                        error: format!(
+                            "{}: TIMEOUT after {}s",
+                            self.stage(&progress),
+                            stage_timeout.as_secs_f32()
+                        ),
+                        progress,
+                    };
+                }
+                // The child has closed stdout, so it's finished
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        let status = child.wait();
+        let stderr = stderr.join().unwrap_or_default();
+        if self.debug {
+            eprint!("{stderr}");
+        }
+
+        if let Some(outcome) = outcome {
+            return outcome;
+        }
+        if let (true, Some(parse_time_s)) = (
+            progress.is_finished(self.part.into()),
+            progress.parse_time_s,
+        ) {
+            return Outcome::Solved(PuzzleResult {
+                parse_time_s,
+                part1: progress.part1,
+                part2: progress.part2,
+            });
+        }
+
+        // The child died without saying why, which happens when it overflows its stack
+        let stage = self.stage(&progress);
+        let error = match status {
+            _ if stderr.contains("has overflowed its stack") => String::from("stack overflow"),
+            Ok(status) => format!("worker exited with {status}"),
+            Err(err) => format!("Failed to wait for worker: {err}"),
+        };
+
+        Outcome::Failed {
+            error: format!("{stage}: {error}"),
+            progress,
+        }
+    }
+
+    // The stage that was running when the child stopped reporting
+    fn stage(&self, progress: &Progress) -> &'static str {
+        match self.bench_runs {
+            Some(_) => "bench",
+            None => progress.stage(self.part.into()),
+        }
+    }
+}
+
+fn failed(stage: &str, error: String) -> Outcome {
+    Outcome::Failed {
+        error: format!("{stage}: {error}"),
+        progress: Progress::default(),
+    }
+}
+
+// The child side of `Worker`. Reads the input from stdin and solves the puzzle on a thread with a
+// large stack, reporting each stage as it finishes
+pub fn run_worker(
+    puzzle: &'static Puzzle,
+    bench_runs: Option<u32>,
+    part: Option<Part>,
+) -> color_eyre::Result<()> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+
+    panic::set_hook(Box::new(|info| {
+        // Solvers are included by absolute path, so trim that back to the path in the repo
+        let message = info
+            .to_string()
+            .replace(concat!(env!("CARGO_MANIFEST_DIR"), "/"), "")
+            .replace('\n', " ");
+        *PANIC_MESSAGE.lock().unwrap() = Some(message);
+    }));
+
+    let solver = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || match bench_runs {
+            Some(runs) => run_bench(puzzle, &input, runs, part.into()),
+            None => run_stages(puzzle, &input, part.into()),
+        })?;
+
+    if let Err(payload) = solver.join() {
+        let message = PANIC_MESSAGE.lock().unwrap().take();
+        send(&Message::Failed {
+            stage: None,
+            error: message.unwrap_or_else(|| panic_payload(payload)),
+        });
+    }
+
+    Ok(())
+}
+
+fn run_stages(puzzle: &Puzzle, raw: &str, parts: Parts) {
+    let (input, time_s) = match timed_fn(|| puzzle.parse(raw)) {
+        Ok(parsed) => parsed,
+        Err(err) => return send_failed("parse", err),
+    };
+    send(&Message::Parsed(time_s));
+
+    if parts.part1 {
+        match timed_fn(|| input.part1()) {
+            Ok((answer, time_s)) => send(&Message::Part1(PartResult { answer, time_s })),
+            Err(err) => return send_failed("part1", err),
+        }
+    }
+
+    if parts.part2 {
+        match timed_fn(|| input.part2()) {
+            Ok((answer, time_s)) => send(&Message::Part2(PartResult { answer, time_s })),
+            Err(err) => send_failed("part2", err),
+        }
+    }
+}
+
+fn run_bench(puzzle: &Puzzle, raw: &str, runs: u32, parts: Parts) {
+    match bench(puzzle, raw, runs, parts) {
+        Ok(bench) => send(&Message::Benched(bench)),
+        Err(err) => send_failed("bench", err),
+    }
+}
+
+fn send_failed(stage: &str, err: color_eyre::Report) {
+    send(&Message::Failed {
+        stage: Some(String::from(stage)),
+        error: err.to_string(),
+    });
+}
+
+fn send(message: &Message) {
+    // Serializing strings and numbers can't fail
+    let message = serde_json::to_string(message).unwrap();
+    let mut stdout = io::stdout().lock();
+    let _ = writeln!(stdout, "{MESSAGE_PREFIX}{message}");
+    let _ = stdout.flush();
+}
+
+fn panic_payload(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("panicked")
+    }
+}
@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use crate::answer::Answer;
+
+/// Implemented by each day's `Solution` so it can be picked up by the puzzle registry
+pub trait Solver {
+    type Input<'a>;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    // How long each stage may run before it's stopped, unless overridden with --timeout
+    const TIMEOUT: Duration = Duration::from_secs(60);
+
+    // Work in progress solutions are left out of runs until they're finished, unless they're
+    // selected on their own
+    const WIP: bool = false;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> color_eyre::Result<Self::Answer1>;
+    fn part2(input: &Self::Input<'_>) -> color_eyre::Result<Self::Answer2>;
+}
@@ -7,7 +7,12 @@ use std::slice::{Iter, IterMut};
 use itertools::Itertools;
 use strum::IntoEnumIterator;
 
-use super::direction::{Direction, DirectionEx};
+use crate::error::AdventError;
+
+use super::{
+    direction::{Direction, DirectionEx},
+    pos::{GridIndex, Pos},
+};
 
 // Wrapper around grid::Grid that provides extended functionality
 #[derive(Clone, Eq, PartialEq)]
@@ -39,14 +44,27 @@ where
         self.inner.iter_row(row).enumerate()
     }
 
-    pub fn swap(&mut self, x: (usize, usize), y: (usize, usize)) -> color_eyre::Result<()> {
+    pub fn swap(&mut self, x: impl GridIndex, y: impl GridIndex) -> color_eyre::Result<()> {
+        let x = self.index_of(x)?;
+        let y = self.index_of(y)?;
+
         let temp = self.inner[x];
         self.inner[x] = self.inner[y];
         self.inner[y] = temp;
         Ok(())
     }
 
-    pub fn get_in_direction(&self, point: (usize, usize), direction: Direction) -> Option<&T> {
+    pub fn get_at(&self, point: impl GridIndex) -> Option<&T> {
+        let (row, col) = point.grid_index()?;
+        self.inner.get(row, col)
+    }
+
+    pub fn get_at_mut(&mut self, point: impl GridIndex) -> Option<&mut T> {
+        let (row, col) = point.grid_index()?;
+        self.inner.get_mut(row, col)
+    }
+
+    pub fn get_in_direction(&self, point: impl GridIndex, direction: Direction) -> Option<&T> {
         if let Some((_, out)) = self.get_in_direction_indexed(point, direction) {
             Some(out)
         } else {
@@ -54,7 +72,7 @@ where
         }
     }
 
-    pub fn get_in_direction_ex(&self, point: (usize, usize), direction: DirectionEx) -> Option<&T> {
+    pub fn get_in_direction_ex(&self, point: impl GridIndex, direction: DirectionEx) -> Option<&T> {
         if let Some((_, out)) = self.get_in_direction_ex_indexed(point, direction) {
             Some(out)
         } else {
@@ -64,7 +82,7 @@ where
 
     pub fn get_in_direction_indexed(
         &self,
-        point: (usize, usize),
+        point: impl GridIndex,
         direction: Direction,
     ) -> Option<((usize, usize), &T)> {
         let point = match direction {
@@ -79,7 +97,7 @@ where
 
     pub fn get_in_direction_indexed_mut(
         &mut self,
-        point: (usize, usize),
+        point: impl GridIndex,
         direction: Direction,
     ) -> Option<((usize, usize), &mut T)> {
         let point = match direction {
@@ -94,7 +112,7 @@ where
 
     pub fn get_in_direction_ex_indexed(
         &self,
-        point: (usize, usize),
+        point: impl GridIndex,
         direction: DirectionEx,
     ) -> Option<((usize, usize), &T)> {
         let point = match direction {
@@ -111,19 +129,21 @@ where
         point.map(|point| (point, self.get(point.0, point.1).unwrap()))
     }
 
-    pub fn is_in_bounds(&self, point: (usize, usize)) -> bool {
-        self.inner.get(point.0, point.1).is_some()
+    pub fn is_in_bounds(&self, point: impl GridIndex) -> bool {
+        self.get_at(point).is_some()
     }
 
-    pub fn north_of(&self, point: (usize, usize)) -> Option<(usize, usize)> {
+    pub fn north_of(&self, point: impl GridIndex) -> Option<(usize, usize)> {
+        let point = point.grid_index()?;
         point.0.checked_sub(1).map(|new_row| (new_row, point.1))
     }
 
-    pub fn north_east_of(&self, point: (usize, usize)) -> Option<(usize, usize)> {
+    pub fn north_east_of(&self, point: impl GridIndex) -> Option<(usize, usize)> {
         self.north_of(point).and_then(|point| self.east_of(point))
     }
 
-    pub fn east_of(&self, point: (usize, usize)) -> Option<(usize, usize)> {
+    pub fn east_of(&self, point: impl GridIndex) -> Option<(usize, usize)> {
+        let point = point.grid_index()?;
         if point.1 < (self.inner.cols() - 1) {
             Some((point.0, point.1 + 1))
         } else {
@@ -131,11 +151,12 @@ where
         }
     }
 
-    pub fn south_east_of(&self, point: (usize, usize)) -> Option<(usize, usize)> {
+    pub fn south_east_of(&self, point: impl GridIndex) -> Option<(usize, usize)> {
         self.south_of(point).and_then(|point| self.east_of(point))
     }
 
-    pub fn south_of(&self, point: (usize, usize)) -> Option<(usize, usize)> {
+    pub fn south_of(&self, point: impl GridIndex) -> Option<(usize, usize)> {
+        let point = point.grid_index()?;
         if point.0 < (self.inner.rows() - 1) {
             Some((point.0 + 1, point.1))
         } else {
@@ -143,21 +164,22 @@ where
         }
     }
 
-    pub fn south_west_of(&self, point: (usize, usize)) -> Option<(usize, usize)> {
+    pub fn south_west_of(&self, point: impl GridIndex) -> Option<(usize, usize)> {
         self.south_of(point).and_then(|point| self.west_of(point))
     }
 
-    pub fn west_of(&self, point: (usize, usize)) -> Option<(usize, usize)> {
+    pub fn west_of(&self, point: impl GridIndex) -> Option<(usize, usize)> {
+        let point = point.grid_index()?;
         point.1.checked_sub(1).map(|new_col| (point.0, new_col))
     }
 
-    pub fn north_west_of(&self, point: (usize, usize)) -> Option<(usize, usize)> {
+    pub fn north_west_of(&self, point: impl GridIndex) -> Option<(usize, usize)> {
         self.north_of(point).and_then(|point| self.west_of(point))
     }
 
     pub fn get_neighbours_of_indexed(
         &self,
-        point: (usize, usize),
+        point: impl GridIndex,
     ) -> Vec<(Direction, Option<((usize, usize), &T)>)> {
         Direction::iter()
             .map(|direction| (direction, self.get_in_direction_indexed(point, direction)))
@@ -166,13 +188,26 @@ where
 
     pub fn get_neighbours_of_ex_indexed(
         &self,
-        point: (usize, usize),
+        point: impl GridIndex,
     ) -> Vec<Option<((usize, usize), &T)>> {
         DirectionEx::iter()
             .map(|direction| self.get_in_direction_ex_indexed(point, direction))
             .collect_vec()
     }
 
+    // The neighbouring index in the direction, if it's on the grid
+    pub fn step(&self, point: impl GridIndex, direction: Direction) -> Option<(usize, usize)> {
+        let next = Pos::from(point.grid_index()?).step(direction);
+        next.to_index().filter(|&index| self.is_in_bounds(index))
+    }
+
+    fn index_of(&self, point: impl GridIndex) -> Result<(usize, usize), AdventError> {
+        point
+            .grid_index()
+            .filter(|&index| self.is_in_bounds(index))
+            .ok_or_else(|| AdventError::NotFound(String::from("Grid index")))
+    }
+
     pub fn position(&self, value: &T) -> Option<(usize, usize)> {
         if let Some((loc, _)) = self.indexed_iter().find(|(_, entry)| *entry == value) {
             Some(loc)
@@ -397,6 +432,29 @@ impl<T: std::cmp::Eq> IndexMut<(usize, usize)> for Grid<T> {
     }
 }
 
+// Panics if the position is negative, the same as it does when it's past the end of the grid
+impl<T: std::cmp::Eq> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, pos: Pos) -> &T {
+        let index = pos
+            .to_index()
+            .unwrap_or_else(|| panic!("Negative grid index {pos}"));
+        self.inner.index(index)
+    }
+}
+
+impl<T: std::cmp::Eq> IndexMut<Pos> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let index = pos
+            .to_index()
+            .unwrap_or_else(|| panic!("Negative grid index {pos}"));
+        self.inner.index_mut(index)
+    }
+}
+
 impl<T: Hash + std::cmp::Eq> std::hash::Hash for Grid<T> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.inner.iter().for_each(|entry| entry.hash(state));
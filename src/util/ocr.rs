@@ -0,0 +1,148 @@
+use itertools::Itertools;
+
+use crate::{
+    answer::{Answer, Bitmap},
+    error::AdventError,
+};
+
+use super::grid::Grid;
+
+// A pixel in an image of letters, which is either lit or not
+pub trait Pixel {
+    fn is_lit(&self) -> bool;
+}
+
+impl Pixel for bool {
+    fn is_lit(&self) -> bool {
+        *self
+    }
+}
+
+// Letters are usually drawn with '#', but '█' is used when printing them to be read by eye
+impl Pixel for char {
+    fn is_lit(&self) -> bool {
+        matches!(self, '#' | '█')
+    }
+}
+
+// The 4x6 font, used by most puzzles. Not every letter is 4 wide, e.g. 'I' and 'Y'
+const SMALL_FONT: (&str, &str) = (
+    "ABCEFGHIJKLOPRSUYZ",
+    "\
+.##..###...##..####.####..##..#..#.###...##.#..#.#.....##..###..###...###.#..#.#...#.####
+#..#.#..#.#..#.#....#....#..#.#..#..#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#....#
+#..#.###..#....###..###..#....####..#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#....#.
+####.#..#.#....#....#....#.##.#..#..#.....#.#.#..#....#..#.###..###...##..#..#...#....#..
+#..#.#..#.#..#.#....#....#..#.#..#..#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#...#...
+#..#.###...##..####.#.....###.#..#.###..##..#..#.####..##..#....#..#.###...##....#...####",
+);
+
+// The 6x10 font, used by a few of the later puzzles
+const LARGE_FONT: (&str, &str) = (
+    "ABCEFGHJKLNPRXZ",
+    "\
+..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######
+.#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#
+#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#
+#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#.
+#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#..
+######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#...
+#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#....
+#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.....
+#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.....
+#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######",
+);
+
+// Reads the letters drawn in the grid. Any blank rows and columns around the letters are ignored,
+// and the font is picked by how tall the letters are
+pub fn read<T>(grid: &Grid<T>) -> Result<String, AdventError>
+where
+    T: Pixel + Clone + Default + Eq,
+{
+    let lit_rows = (0..grid.rows())
+        .filter(|&row| grid.iter_row(row).any(Pixel::is_lit))
+        .collect_vec();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Err(AdventError::NotFound(String::from("Lit pixel")));
+    };
+
+    let (letters, pictures) = match bottom - top + 1 {
+        6 => SMALL_FONT,
+        10 => LARGE_FONT,
+        height => {
+            return Err(AdventError::UnknownPattern(format!(
+                "{height} pixel high letters"
+            )))
+        }
+    };
+    let font = pictures
+        .lines()
+        .map(|line| line.chars().collect_vec())
+        .collect_vec();
+    let font = letters
+        .chars()
+        .zip(glyphs(font.len(), font[0].len(), |row, col| {
+            font[row][col].is_lit()
+        }))
+        .collect_vec();
+
+    let mut text = String::new();
+    let mut unknown = Vec::new();
+    let lit = |row, col| grid.get(top + row, col).is_some_and(Pixel::is_lit);
+    for (index, glyph) in glyphs(bottom - top + 1, grid.cols(), lit)
+        .into_iter()
+        .enumerate()
+    {
+        match font.iter().find(|(_, pattern)| *pattern == glyph) {
+            Some((letter, _)) => text.push(*letter),
+            None => unknown.push(format!("Letter {}:\n{glyph}", index + 1)),
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(AdventError::UnknownGlyphs(unknown))
+    }
+}
+
+// The letters drawn in the grid, or the picture itself when they can't all be read so that it can
+// still be read by eye
+pub fn read_answer<T>(grid: &Grid<T>) -> Answer
+where
+    T: Pixel + Clone + Default + Eq,
+{
+    match read(grid) {
+        Ok(text) => Answer::Str(text),
+        Err(err) => {
+            log::debug!("Answering with the picture, as its letters can't be read: {err}");
+            let rows = (0..grid.rows())
+                .map(|row| grid.iter_row(row).map(Pixel::is_lit).collect())
+                .collect();
+            Answer::Bitmap(Bitmap::new(rows))
+        }
+    }
+}
+
+// Splits a picture into its letters, drawn with '#' and '.'. Each run of columns with something lit
+// in them is one letter
+fn glyphs<F>(rows: usize, cols: usize, lit: F) -> Vec<String>
+where
+    F: Fn(usize, usize) -> bool,
+{
+    (0..cols)
+        .chunk_by(|&col| (0..rows).any(|row| lit(row, col)))
+        .into_iter()
+        .filter(|(is_lit, _)| *is_lit)
+        .map(|(_, cols)| {
+            let cols = cols.collect_vec();
+            (0..rows)
+                .map(|row| {
+                    cols.iter()
+                        .map(|&col| if lit(row, col) { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .join("\n")
+        })
+        .collect_vec()
+}
@@ -0,0 +1,221 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use super::{
+    direction::{Direction, DirectionEx},
+    point::{Point, PointSig},
+};
+
+// A position on a grid in (row, col) order, the same as Grid. It's signed so that it can step off
+// the edge of a grid, and be checked afterwards
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos {
+    pub row: i64,
+    pub col: i64,
+}
+
+// The difference between two positions, in rows and cols
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Vec2 {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Pos {
+    pub fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+
+    // The neighbouring position in the direction, which can be a Direction, DirectionEx or Vec2
+    pub fn step(self, by: impl Into<Vec2>) -> Self {
+        self + by.into()
+    }
+
+    // The (row, col) index this is on a grid, unless it's negative
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((
+            usize::try_from(self.row).ok()?,
+            usize::try_from(self.col).ok()?,
+        ))
+    }
+
+    // Whether this is on a grid of the given size
+    pub fn is_within(self, rows: usize, cols: usize) -> bool {
+        self.to_index()
+            .is_some_and(|(row, col)| row < rows && col < cols)
+    }
+}
+
+impl Vec2 {
+    pub fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self::new(row as i64, col as i64)
+    }
+}
+
+// Points are taken to be (row, col) the same as their tuples are, so x is the row
+impl From<Point> for Pos {
+    fn from(point: Point) -> Self {
+        Self::new(point.x as i64, point.y as i64)
+    }
+}
+
+impl From<PointSig> for Pos {
+    fn from(point: PointSig) -> Self {
+        Self::new(point.x as i64, point.y as i64)
+    }
+}
+
+impl TryFrom<Pos> for (usize, usize) {
+    type Error = Pos;
+
+    // Fails with the position when it's negative
+    fn try_from(pos: Pos) -> Result<Self, Self::Error> {
+        pos.to_index().ok_or(pos)
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Vec2::new(-1, 0),
+            Direction::East => Vec2::new(0, 1),
+            Direction::South => Vec2::new(1, 0),
+            Direction::West => Vec2::new(0, -1),
+        }
+    }
+}
+
+impl From<DirectionEx> for Vec2 {
+    fn from(direction: DirectionEx) -> Self {
+        match direction {
+            DirectionEx::North => Vec2::new(-1, 0),
+            DirectionEx::NorthEast => Vec2::new(-1, 1),
+            DirectionEx::East => Vec2::new(0, 1),
+            DirectionEx::SouthEast => Vec2::new(1, 1),
+            DirectionEx::South => Vec2::new(1, 0),
+            DirectionEx::SouthWest => Vec2::new(1, -1),
+            DirectionEx::West => Vec2::new(0, -1),
+            DirectionEx::NorthWest => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl Add<Vec2> for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Vec2) -> Pos {
+        Pos::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Add<Direction> for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Direction) -> Pos {
+        self.step(rhs)
+    }
+}
+
+impl AddAssign<Vec2> for Pos {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vec2> for Pos {
+    type Output = Pos;
+
+    fn sub(self, rhs: Vec2) -> Pos {
+        Pos::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign<Vec2> for Pos {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+// How far it is from one position to the other
+impl Sub for Pos {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Pos) -> Vec2 {
+        Vec2::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: i64) -> Vec2 {
+        Vec2::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.row, -self.col)
+    }
+}
+
+// Anything that can index into a Grid. None if it's off the top or left of the grid
+pub trait GridIndex: Copy {
+    fn grid_index(self) -> Option<(usize, usize)>;
+}
+
+impl GridIndex for (usize, usize) {
+    fn grid_index(self) -> Option<(usize, usize)> {
+        Some(self)
+    }
+}
+
+impl GridIndex for Pos {
+    fn grid_index(self) -> Option<(usize, usize)> {
+        self.to_index()
+    }
+}
+
+impl GridIndex for Point {
+    fn grid_index(self) -> Option<(usize, usize)> {
+        Some(self.into())
+    }
+}
+
+impl GridIndex for PointSig {
+    fn grid_index(self) -> Option<(usize, usize)> {
+        Pos::from(self).to_index()
+    }
+}
@@ -2,63 +2,71 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = (Vec<(i32, i32)>, Vec<(i32, i32)>, Vec<(i32, i32)>);
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let (first, second) = input
-        .lines()
-        .collect_tuple()
-        .ok_or(AdventError::UnexpectedValue(
-            String::from("Two lines"),
-            input.to_string(),
-        ))?;
+pub struct Solution;
 
-    let first_path: Vec<PathStep> = first.split(',').map(PathStep::try_from).try_collect()?;
-    let second_path: Vec<PathStep> = second.split(',').map(PathStep::try_from).try_collect()?;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = usize;
 
-    let first_path = gen_path(&first_path);
-    let second_path = gen_path(&second_path);
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let (first, second) = input
+            .lines()
+            .collect_tuple()
+            .ok_or(AdventError::UnexpectedValue(
+                String::from("Two lines"),
+                input.to_string(),
+            ))?;
 
-    let intersections = intersection(&first_path, &second_path);
+        let first_path: Vec<PathStep> = first.split(',').map(PathStep::try_from).try_collect()?;
+        let second_path: Vec<PathStep> = second.split(',').map(PathStep::try_from).try_collect()?;
 
-    Ok((first_path, second_path, intersections))
-}
+        let first_path = gen_path(&first_path);
+        let second_path = gen_path(&second_path);
 
-pub fn part1(input: &ParsedInput) -> color_eyre::Result<u32> {
-    let nearest_intersection = input
-        .2
-        .iter()
-        .map(|(x, y)| x.abs_diff(0) + y.abs_diff(0))
-        .sorted()
-        .next()
-        .unwrap();
+        let intersections = intersection(&first_path, &second_path);
 
-    Ok(nearest_intersection)
-}
+        Ok((first_path, second_path, intersections))
+    }
+
+    fn part1(input: &ParsedInput) -> color_eyre::Result<u32> {
+        let nearest_intersection = input
+            .2
+            .iter()
+            .map(|(x, y)| x.abs_diff(0) + y.abs_diff(0))
+            .sorted()
+            .next()
+            .unwrap();
 
-pub fn part2(input: &ParsedInput) -> color_eyre::Result<usize> {
-    let first_path = &input.0;
-    let second_path = &input.1;
-    let intersections = &input.2;
-
-    let shortest_path_intersection = intersections
-        .iter()
-        .filter_map(|intersection| {
-            let first_path_steps = num_path_steps_to_point(first_path, intersection)?;
-            let second_path_steps = num_path_steps_to_point(second_path, intersection)?;
-
-            Some(first_path_steps + second_path_steps)
-        })
-        .sorted()
-        .next()
-        .ok_or(AdventError::LogicError(String::from(
-            "No intersections found",
-        )))?;
-
-    // +2 because the paths don't contain the starting point (to avoid it being treated as an intersection)
-    Ok(shortest_path_intersection + 2)
+        Ok(nearest_intersection)
+    }
+
+    fn part2(input: &ParsedInput) -> color_eyre::Result<usize> {
+        let first_path = &input.0;
+        let second_path = &input.1;
+        let intersections = &input.2;
+
+        let shortest_path_intersection = intersections
+            .iter()
+            .filter_map(|intersection| {
+                let first_path_steps = num_path_steps_to_point(first_path, intersection)?;
+                let second_path_steps = num_path_steps_to_point(second_path, intersection)?;
+
+                Some(first_path_steps + second_path_steps)
+            })
+            .sorted()
+            .next()
+            .ok_or(AdventError::LogicError(String::from(
+                "No intersections found",
+            )))?;
+
+        // +2 because the paths don't contain the starting point (to avoid it being treated as an intersection)
+        Ok(shortest_path_intersection + 2)
+    }
 }
 
 fn gen_path(steps: &Vec<PathStep>) -> Vec<(i32, i32)> {
@@ -1,55 +1,63 @@
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = ([u32; 6], [u32; 6]);
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let (start, end) = input
-        .split_once('-')
-        .ok_or(AdventError::SplitOnce(input.to_string(), '-'.to_string()))?;
+pub struct Solution;
 
-    Ok((
-        explode_number(&start.parse::<u32>()?),
-        explode_number(&end.parse::<u32>()?),
-    ))
-}
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-// It is a six-digit number.
-// The value is within the range given in your puzzle input.
-// Two adjacent digits are the same (like 22 in 122345).
-// Going from left to right, the digits never decrease; they only ever increase or stay the same (like 111123 or 135679).
-// How many different passwords within the range given in your puzzle input meet these criteria?
-pub fn part1((start, end): &ParsedInput) -> color_eyre::Result<u32> {
-    let mut valid_pwds = 0;
-
-    let mut next_num = next_non_dec_number(start);
-    while next_num <= *end {
-        if has_adjacent_digits(&next_num) {
-            valid_pwds += 1;
-        }
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let (start, end) = input
+            .split_once('-')
+            .ok_or(AdventError::SplitOnce(input.to_string(), '-'.to_string()))?;
 
-        next_num = next_non_dec_number(&next_num);
+        Ok((
+            explode_number(&start.parse::<u32>()?),
+            explode_number(&end.parse::<u32>()?),
+        ))
     }
 
-    Ok(valid_pwds)
-}
+    // It is a six-digit number.
+    // The value is within the range given in your puzzle input.
+    // Two adjacent digits are the same (like 22 in 122345).
+    // Going from left to right, the digits never decrease; they only ever increase or stay the same (like 111123 or 135679).
+    // How many different passwords within the range given in your puzzle input meet these criteria?
+    fn part1((start, end): &ParsedInput) -> color_eyre::Result<u32> {
+        let mut valid_pwds = 0;
 
-// the two adjacent matching digits are not part of a larger group of matching digits.
-// 111122 meets the criteria (even though 1 is repeated more than twice, it still contains a double 22).
-pub fn part2((start, end): &ParsedInput) -> color_eyre::Result<u32> {
-    let mut valid_pwds = 0;
+        let mut next_num = next_non_dec_number(start);
+        while next_num <= *end {
+            if has_adjacent_digits(&next_num) {
+                valid_pwds += 1;
+            }
 
-    let mut next_num = next_non_dec_number(start);
-    while next_num <= *end {
-        if has_two_adjacent_digits(&next_num) {
-            valid_pwds += 1;
+            next_num = next_non_dec_number(&next_num);
         }
 
-        next_num = next_non_dec_number(&next_num);
+        Ok(valid_pwds)
     }
 
-    Ok(valid_pwds)
+    // the two adjacent matching digits are not part of a larger group of matching digits.
+    // 111122 meets the criteria (even though 1 is repeated more than twice, it still contains a double 22).
+    fn part2((start, end): &ParsedInput) -> color_eyre::Result<u32> {
+        let mut valid_pwds = 0;
+
+        let mut next_num = next_non_dec_number(start);
+        while next_num <= *end {
+            if has_two_adjacent_digits(&next_num) {
+                valid_pwds += 1;
+            }
+
+            next_num = next_non_dec_number(&next_num);
+        }
+
+        Ok(valid_pwds)
+    }
 }
 
 fn explode_number(num: &u32) -> [u32; 6] {
@@ -1,29 +1,37 @@
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 use super::int_code_computer::{IcProgram, IntCodeComputer};
 
 type ParsedInput = IcProgram;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    IntCodeComputer::parse_program(input)
-}
+pub struct Solution;
 
-pub fn part1(input: &ParsedInput) -> color_eyre::Result<i64> {
-    let mut icc = IntCodeComputer::load(input.clone());
-    icc.push_input(1);
-    icc.run()?;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = i64;
+    type Answer2 = i64;
 
-    icc.last_output()
-        .ok_or(AdventError::LogicError(String::from("No diagnostic output found")).into())
-        .cloned()
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        IntCodeComputer::parse_program(input)
+    }
+
+    fn part1(input: &ParsedInput) -> color_eyre::Result<i64> {
+        let mut icc = IntCodeComputer::load(input.clone());
+        icc.push_input(1);
+        icc.run_to_halt()?;
+
+        icc.last_output()
+            .ok_or(AdventError::LogicError(String::from("No diagnostic output found")).into())
+            .cloned()
+    }
 
-pub fn part2(input: &ParsedInput) -> color_eyre::Result<i64> {
-    let mut icc = IntCodeComputer::load(input.clone());
-    icc.push_input(5);
-    icc.run()?;
+    fn part2(input: &ParsedInput) -> color_eyre::Result<i64> {
+        let mut icc = IntCodeComputer::load(input.clone());
+        icc.push_input(5);
+        icc.run_to_halt()?;
 
-    icc.last_output()
-        .ok_or(AdventError::LogicError(String::from("No diagnostic output found")).into())
-        .cloned()
+        icc.last_output()
+            .ok_or(AdventError::LogicError(String::from("No diagnostic output found")).into())
+            .cloned()
+    }
 }
@@ -2,25 +2,49 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Vec<(String, String)>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input
-        .lines()
-        .filter_map(|line| line.split_once(')'))
-        .map(|(s1, s2)| (s1.to_string(), s2.to_string()))
-        .collect_vec())
-}
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input
+            .lines()
+            .filter_map(|line| line.split_once(')'))
+            .map(|(s1, s2)| (s1.to_string(), s2.to_string()))
+            .collect_vec())
+    }
+
+    fn part1(orbit_map: &ParsedInput) -> color_eyre::Result<u32> {
+        let mut orbit_counts = HashMap::new();
+
+        Ok(orbit_map
+            .iter()
+            .map(|orbit| calc_num_orbits(orbit, orbit_map, &mut orbit_counts, None))
+            .sum())
+    }
+
+    fn part2(orbit_map: &ParsedInput) -> color_eyre::Result<usize> {
+        let you_orbit_chain = get_orbit_chain("YOU", orbit_map);
+        let san_orbit_chain = get_orbit_chain("SAN", orbit_map);
 
-pub fn part1(orbit_map: &ParsedInput) -> color_eyre::Result<u32> {
-    let mut orbit_counts = HashMap::new();
+        for (you_idx, name) in you_orbit_chain.iter().enumerate() {
+            if let Some(san_idx) = san_orbit_chain.iter().position(|e| e == name) {
+                return Ok(you_idx + san_idx);
+            }
+        }
 
-    Ok(orbit_map
-        .iter()
-        .map(|orbit| calc_num_orbits(orbit, orbit_map, &mut orbit_counts, None))
-        .sum())
+        Err(
+            AdventError::LogicError(String::from("No intersection found between YOU and SAN"))
+                .into(),
+        )
+    }
 }
 
 fn calc_num_orbits(
@@ -55,19 +79,6 @@ fn calc_num_orbits(
         .or_insert(num_orbits)
 }
 
-pub fn part2(orbit_map: &ParsedInput) -> color_eyre::Result<usize> {
-    let you_orbit_chain = get_orbit_chain("YOU", orbit_map);
-    let san_orbit_chain = get_orbit_chain("SAN", orbit_map);
-
-    for (you_idx, name) in you_orbit_chain.iter().enumerate() {
-        if let Some(san_idx) = san_orbit_chain.iter().position(|e| e == name) {
-            return Ok(you_idx + san_idx);
-        }
-    }
-
-    Err(AdventError::LogicError(String::from("No intersection found between YOU and SAN")).into())
-}
-
 fn get_orbit_chain(from: &str, orbit_map: &ParsedInput) -> Vec<String> {
     let mut orbit_chain = Vec::new();
 
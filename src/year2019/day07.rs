@@ -1,91 +1,88 @@
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
-use super::int_code_computer::{IcProgram, IntCodeComputer};
+use super::{
+    int_code_computer::{IcProgram, IntCodeComputer},
+    int_code_io::{Channel, Network},
+};
 
 type ParsedInput = IcProgram;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    IntCodeComputer::parse_program(input)
-}
+pub struct Solution;
 
-pub fn part1(code: &ParsedInput) -> color_eyre::Result<i64> {
-    let signals: Vec<_> = (0..5)
-        .permutations(5)
-        .map(|p| calc_thruster_signal(p, code))
-        .try_collect()?;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = i64;
+    type Answer2 = i64;
 
-    Ok(*signals.iter().max().ok_or(AdventError::EmptySlice)?)
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        IntCodeComputer::parse_program(input)
+    }
 
-fn calc_thruster_signal(phase_settings: Vec<i64>, code: &ParsedInput) -> color_eyre::Result<i64> {
-    let mut io = 0;
-    for phase in phase_settings {
-        let mut icc = IntCodeComputer::load(code.clone());
-        icc.push_input(phase);
-        icc.push_input(io);
-
-        icc.run()?;
-
-        io = *icc
-            .last_output()
-            .ok_or(AdventError::LogicError(String::from(
-                "No output from amplifier",
-            )))?;
+    fn part1(code: &ParsedInput) -> color_eyre::Result<i64> {
+        let signals: Vec<_> = (0..5)
+            .permutations(5)
+            .map(|p| calc_thruster_signal(p, code))
+            .try_collect()?;
+
+        Ok(*signals.iter().max().ok_or(AdventError::EmptySlice)?)
     }
 
-    Ok(io)
-}
+    fn part2(code: &ParsedInput) -> color_eyre::Result<i64> {
+        let signals: Vec<_> = (5..10)
+            .permutations(5)
+            .map(|p| calc_thruster_signal_with_feedback(p, code))
+            .try_collect()?;
 
-pub fn part2(code: &ParsedInput) -> color_eyre::Result<i64> {
-    let signals: Vec<_> = (5..10)
-        .permutations(5)
-        .map(|p| calc_thruster_signal_with_feedback(p, code))
-        .try_collect()?;
+        Ok(*signals.iter().max().ok_or(AdventError::EmptySlice)?)
+    }
+}
 
-    Ok(*signals.iter().max().ok_or(AdventError::EmptySlice)?)
+fn calc_thruster_signal(phase_settings: Vec<i64>, code: &ParsedInput) -> color_eyre::Result<i64> {
+    run_amplifiers(&phase_settings, code, false)
 }
 
 fn calc_thruster_signal_with_feedback(
     phase_settings: Vec<i64>,
     code: &ParsedInput,
 ) -> color_eyre::Result<i64> {
-    let mut amp_a = new_amplifier(code, phase_settings[0]);
-    let mut amp_b = new_amplifier(code, phase_settings[1]);
-    let mut amp_c = new_amplifier(code, phase_settings[2]);
-    let mut amp_d = new_amplifier(code, phase_settings[3]);
-    let mut amp_e = new_amplifier(code, phase_settings[4]);
-
-    let mut amp_a_input = 0;
-    loop {
-        let a_to_b = run_amplifier(&mut amp_a, amp_a_input)?;
-        let b_to_c = run_amplifier(&mut amp_b, a_to_b)?;
-        let c_to_d = run_amplifier(&mut amp_c, b_to_c)?;
-        let d_to_e = run_amplifier(&mut amp_d, c_to_d)?;
-        amp_a_input = run_amplifier(&mut amp_e, d_to_e)?;
-
-        if amp_e.has_halted() {
-            return amp_e
-                .last_output()
-                .cloned()
-                .ok_or(AdventError::LogicError(String::from("No output from amplifier")).into());
-        }
-    }
+    run_amplifiers(&phase_settings, code, true)
 }
 
-fn new_amplifier(code: &ParsedInput, phase_setting: i64) -> IntCodeComputer {
-    let mut amp = IntCodeComputer::load(code.clone());
-    amp.enable_input_yield();
-    amp.push_input(phase_setting);
+// Each amplifier reads from the channel before it and writes to the one after. With feedback the
+// last amplifier writes back to the first, otherwise to a channel of its own
+fn run_amplifiers(
+    phase_settings: &[i64],
+    code: &ParsedInput,
+    feedback: bool,
+) -> color_eyre::Result<i64> {
+    let channels = (0..=phase_settings.len())
+        .map(|_| Channel::new())
+        .collect_vec();
+    let last = if feedback { 0 } else { phase_settings.len() };
+
+    let mut network = Network::new();
+    for (index, phase) in phase_settings.iter().enumerate() {
+        channels[index].push(*phase);
+
+        let output = if index + 1 == phase_settings.len() {
+            last
+        } else {
+            index + 1
+        };
+        network.add(
+            IntCodeComputer::load(code.clone()),
+            channels[index].clone(),
+            channels[output].clone(),
+        );
+    }
 
-    amp
-}
+    channels[0].push(0);
+    network.run()?;
 
-fn run_amplifier(amp: &mut IntCodeComputer, input: i64) -> color_eyre::Result<i64> {
-    amp.push_input(input);
-    amp.run()?;
-    amp.last_output()
-        .cloned()
+    // With feedback the first amplifier has halted, so the last signal is left unread
+    channels[last]
+        .last()
         .ok_or(AdventError::LogicError(String::from("No output from amplifier")).into())
 }
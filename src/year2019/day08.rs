@@ -1,67 +1,78 @@
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{
+    answer::Answer,
+    error::AdventError,
+    solver::Solver,
+    util::{grid::Grid, ocr},
+};
 
 type ParsedInput = Vec<Layer>;
 
 const WIDTH: usize = 25;
 const HEIGHT: usize = 6;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let pixels: Vec<_> = input
-        .lines()
-        .next()
-        .ok_or(AdventError::LogicError(String::from(
-            "No first line in input",
-        )))?
-        .chars()
-        .map(|s| s.to_digit(10).ok_or(AdventError::ParseDigit(s)))
-        .try_collect()?;
+pub struct Solution;
 
-    let layer_len = WIDTH * HEIGHT;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = Answer;
 
-    Ok(pixels.chunks(layer_len).map_into().collect_vec())
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let pixels: Vec<_> = input
+            .lines()
+            .next()
+            .ok_or(AdventError::LogicError(String::from(
+                "No first line in input",
+            )))?
+            .chars()
+            .map(|s| s.to_digit(10).ok_or(AdventError::ParseDigit(s)))
+            .try_collect()?;
 
-pub fn part1(layers: &ParsedInput) -> color_eyre::Result<usize> {
-    let layer = layers
-        .iter()
-        .min_by(|lhs, rhs| lhs.filter_count(0).cmp(&rhs.filter_count(0)))
-        .ok_or(AdventError::LogicError(String::from(
-            "Failed to find layer with least zero's",
-        )))?;
+        let layer_len = WIDTH * HEIGHT;
 
-    Ok(layer.filter_count(1) * layer.filter_count(2))
-}
+        Ok(pixels.chunks(layer_len).map_into().collect_vec())
+    }
+
+    fn part1(layers: &ParsedInput) -> color_eyre::Result<usize> {
+        let layer = layers
+            .iter()
+            .min_by(|lhs, rhs| lhs.filter_count(0).cmp(&rhs.filter_count(0)))
+            .ok_or(AdventError::LogicError(String::from(
+                "Failed to find layer with least zero's",
+            )))?;
 
-pub fn part2(layers: &ParsedInput) -> color_eyre::Result<&str> {
-    for row in 0..HEIGHT {
-        for col in 0..WIDTH {
-            if let Some(layer) = layers.iter().find(|layer| {
-                layer
-                    .is_pixel_opaque(row, col)
-                    .is_some_and(|is_opaque| is_opaque)
-            }) {
-                let _pixel =
+        Ok(layer.filter_count(1) * layer.filter_count(2))
+    }
+
+    fn part2(layers: &ParsedInput) -> color_eyre::Result<Answer> {
+        let mut image = Grid::init(HEIGHT, WIDTH, false);
+        for row in 0..HEIGHT {
+            for col in 0..WIDTH {
+                if let Some(layer) = layers.iter().find(|layer| {
                     layer
-                        .get_pixel_at(row, col)
-                        .ok_or(AdventError::LogicError(format!(
-                            "Failed to get opaque pixel after find at {row} {col}"
-                        )))?;
-
-                // The actual answer is printed to the screen to view, but that messes up the tabular output
-                // so just returning the visually confirmed answer
-                // match pixel {
-                //     0 => print!(" "), // black
-                //     1 => print!("#"), // white
-                //     _ => return Err(AdventError::UnknownPattern(pixel.to_string()).into()),
-                // }
+                        .is_pixel_opaque(row, col)
+                        .is_some_and(|is_opaque| is_opaque)
+                }) {
+                    let pixel =
+                        layer
+                            .get_pixel_at(row, col)
+                            .ok_or(AdventError::LogicError(format!(
+                                "Failed to get opaque pixel after find at {row} {col}"
+                            )))?;
+
+                    image[(row, col)] = match pixel {
+                        0 => false, // black
+                        1 => true,  // white
+                        _ => return Err(AdventError::UnknownPattern(pixel.to_string()).into()),
+                    };
+                }
             }
         }
-        //println!();
-    }
 
-    Ok("CGEGE")
+        Ok(ocr::read_answer(&image))
+    }
 }
 
 #[derive(Debug, PartialEq)]
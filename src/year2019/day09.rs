@@ -1,29 +1,37 @@
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 use super::int_code_computer::{IcProgram, IntCodeComputer};
 
 type ParsedInput = IcProgram;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    IntCodeComputer::parse_program(input)
-}
+pub struct Solution;
 
-pub fn part1(code: &ParsedInput) -> color_eyre::Result<i64> {
-    let mut icc = IntCodeComputer::load(code.clone());
-    icc.push_input(1);
-    icc.run()?;
-    Ok(icc
-        .last_output()
-        .ok_or(AdventError::LogicError(String::from("No output from icc")))
-        .cloned()?)
-}
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        IntCodeComputer::parse_program(input)
+    }
+
+    fn part1(code: &ParsedInput) -> color_eyre::Result<i64> {
+        let mut icc = IntCodeComputer::load(code.clone());
+        icc.push_input(1);
+        icc.run_to_halt()?;
+        Ok(icc
+            .last_output()
+            .ok_or(AdventError::LogicError(String::from("No output from icc")))
+            .cloned()?)
+    }
 
-pub fn part2(code: &ParsedInput) -> color_eyre::Result<i64> {
-    let mut icc = IntCodeComputer::load(code.clone());
-    icc.push_input(2);
-    icc.run()?;
-    Ok(icc
-        .last_output()
-        .ok_or(AdventError::LogicError(String::from("No output from icc")))
-        .cloned()?)
+    fn part2(code: &ParsedInput) -> color_eyre::Result<i64> {
+        let mut icc = IntCodeComputer::load(code.clone());
+        icc.push_input(2);
+        icc.run_to_halt()?;
+        Ok(icc
+            .last_output()
+            .ok_or(AdventError::LogicError(String::from("No output from icc")))
+            .cloned()?)
+    }
 }
@@ -8,99 +8,108 @@ use log::{debug, info};
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{grid::Grid, point::Point},
 };
 
 type ParsedInput = Grid<char>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut grid = Grid::new(0, 0);
+pub struct Solution;
 
-    for line in input.lines() {
-        grid.push_row(line.chars().collect());
-    }
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    Ok(grid)
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut grid = Grid::new(0, 0);
 
-pub fn part1(grid: &ParsedInput) -> color_eyre::Result<usize> {
-    // This is an Euclid's orchard problem - https://en.wikipedia.org/wiki/Euclid%27s_orchard
-    // Should be able to work out the gradient of each line between asteroids
-    // and the number of unique gradients from a given asteroid is the number
-    // of other asteroids it can see
+        for line in input.lines() {
+            grid.push_row(line.chars().collect());
+        }
 
-    let (_, num_visible) = find_asteroid_with_largest_visible_set(grid)?;
+        Ok(grid)
+    }
 
-    Ok(num_visible)
-}
+    fn part1(grid: &ParsedInput) -> color_eyre::Result<usize> {
+        // This is an Euclid's orchard problem - https://en.wikipedia.org/wiki/Euclid%27s_orchard
+        // Should be able to work out the gradient of each line between asteroids
+        // and the number of unique gradients from a given asteroid is the number
+        // of other asteroids it can see
 
-pub fn part2(grid: &ParsedInput) -> color_eyre::Result<usize> {
-    // The Elves are placing bets on which will be the 200th asteroid to be vaporized.
-    // Win the bet by determining which asteroid that will be;
-    // what do you get if you multiply its X coordinate by 100 and then add its Y coordinate?
-    // (For example, 8,2 becomes 802.)
-
-    // My approach:
-    // Get visible set for origin
-    // Order by angle
-    // Remove each asteroid from the grid (in-order of angle)
-    // When 200th is removed then that is the answer
-
-    // Origin is the asteroid with the most visible asteroids from part 1
-    let (origin, _) = find_asteroid_with_largest_visible_set(grid)?;
-    let mut grid = grid.clone();
-    let mut asteroids_zapped = 0;
-
-    let asteroid_200 = 'outer: loop {
-        let asteroids = find_asteroids(&grid);
-
-        // Map of unique angle to (asteroid and distance from origin)
-        let mut visible_asteroids: HashMap<u64, (Point, u64, f64)> = HashMap::new();
-        for asteroid in asteroids {
-            if asteroid == origin {
-                continue;
-            }
+        let (_, num_visible) = find_asteroid_with_largest_visible_set(grid)?;
 
-            let angle = calc_angle_between_points(&origin, &asteroid);
-            let angle_bits = angle.to_bits(); // Convert to bits so can use as key in HashMap
-            let dist = calc_manhattan_distance(&origin, &asteroid);
-
-            visible_asteroids
-                .entry(angle_bits)
-                .and_modify(|(p, d, a)| {
-                    if dist < *d {
-                        *p = asteroid;
-                        *d = dist;
-                        *a = angle;
-                    }
-                })
-                .or_insert((asteroid, dist, angle));
-        }
+        Ok(num_visible)
+    }
 
-        // Sorting by the bits feels weird but seems to work - can't compare the actual angle as
-        // f64 can't be compared
-        for (_, (asteroid, _, _)) in visible_asteroids
-            .into_iter()
-            .sorted_by(|(bits1, _), (bits2, _)| bits1.cmp(bits2))
-        {
-            let raw_value = grid
-                .get_mut(asteroid.y, asteroid.x)
-                .ok_or(AdventError::LogicError(String::from(
-                    "Couldn't find known asteroid in grid",
-                )))?;
-
-            // Remove this asteroid from the grid for next iteration
-            *raw_value = '.';
-            asteroids_zapped += 1;
-            debug!("Zapping {asteroid:?} [{asteroids_zapped}]");
-
-            if asteroids_zapped == 200 {
-                break 'outer asteroid;
+    fn part2(grid: &ParsedInput) -> color_eyre::Result<usize> {
+        // The Elves are placing bets on which will be the 200th asteroid to be vaporized.
+        // Win the bet by determining which asteroid that will be;
+        // what do you get if you multiply its X coordinate by 100 and then add its Y coordinate?
+        // (For example, 8,2 becomes 802.)
+
+        // My approach:
+        // Get visible set for origin
+        // Order by angle
+        // Remove each asteroid from the grid (in-order of angle)
+        // When 200th is removed then that is the answer
+
+        // Origin is the asteroid with the most visible asteroids from part 1
+        let (origin, _) = find_asteroid_with_largest_visible_set(grid)?;
+        let mut grid = grid.clone();
+        let mut asteroids_zapped = 0;
+
+        let asteroid_200 = 'outer: loop {
+            let asteroids = find_asteroids(&grid);
+
+            // Map of unique angle to (asteroid and distance from origin)
+            let mut visible_asteroids: HashMap<u64, (Point, u64, f64)> = HashMap::new();
+            for asteroid in asteroids {
+                if asteroid == origin {
+                    continue;
+                }
+
+                let angle = calc_angle_between_points(&origin, &asteroid);
+                let angle_bits = angle.to_bits(); // Convert to bits so can use as key in HashMap
+                let dist = calc_manhattan_distance(&origin, &asteroid);
+
+                visible_asteroids
+                    .entry(angle_bits)
+                    .and_modify(|(p, d, a)| {
+                        if dist < *d {
+                            *p = asteroid;
+                            *d = dist;
+                            *a = angle;
+                        }
+                    })
+                    .or_insert((asteroid, dist, angle));
             }
-        }
-    };
 
-    Ok((asteroid_200.x * 100) + asteroid_200.y)
+            // Sorting by the bits feels weird but seems to work - can't compare the actual angle as
+            // f64 can't be compared
+            for (_, (asteroid, _, _)) in visible_asteroids
+                .into_iter()
+                .sorted_by(|(bits1, _), (bits2, _)| bits1.cmp(bits2))
+            {
+                let raw_value =
+                    grid.get_mut(asteroid.y, asteroid.x)
+                        .ok_or(AdventError::LogicError(String::from(
+                            "Couldn't find known asteroid in grid",
+                        )))?;
+
+                // Remove this asteroid from the grid for next iteration
+                *raw_value = '.';
+                asteroids_zapped += 1;
+                debug!("Zapping {asteroid:?} [{asteroids_zapped}]");
+
+                if asteroids_zapped == 200 {
+                    break 'outer asteroid;
+                }
+            }
+        };
+
+        Ok((asteroid_200.x * 100) + asteroid_200.y)
+    }
 }
 
 fn find_asteroid_with_largest_visible_set(grid: &Grid<char>) -> color_eyre::Result<(Point, usize)> {
@@ -5,105 +5,104 @@ use std::{
 };
 
 use crate::{
+    answer::Answer,
     error::AdventError,
-    util::{direction::Direction, grid::Grid, point::PointT},
+    solver::Solver,
+    util::{direction::Direction, grid::Grid, ocr, point::PointT},
 };
 
-use super::int_code_computer::{IcProgram, IntCodeComputer};
+use super::int_code_computer::{IcProgram, IntCodeComputer, RunState};
 
 type ParsedInput = IcProgram;
 const BLACK: char = '.';
 const WHITE: char = '#';
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    IntCodeComputer::parse_program(input)
-}
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = Answer;
 
-pub fn part1(code: &ParsedInput) -> color_eyre::Result<usize> {
-    let mut icc = IntCodeComputer::load(code.clone());
-    let mut cur_dir = Direction::North;
-    let mut cur_pos = PointT::from((0, 0));
-    let mut panels = HashMap::from([(cur_pos, '.')]);
-    let mut painted_panels = HashSet::new();
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        IntCodeComputer::parse_program(input)
+    }
 
-    // Starting panel is BLACK
-    icc.push_input(0);
-    icc.enable_input_yield();
+    fn part1(code: &ParsedInput) -> color_eyre::Result<usize> {
+        let mut icc = IntCodeComputer::load(code.clone());
+        let mut cur_dir = Direction::North;
+        let mut cur_pos = PointT::from((0, 0));
+        let mut panels = HashMap::from([(cur_pos, '.')]);
+        let mut painted_panels = HashSet::new();
 
-    while !icc.has_halted() {
-        icc.run()?;
+        // Starting panel is BLACK
+        icc.push_input(0);
 
-        if icc.is_yielding() {
+        while icc.run()? == RunState::WaitingForInput {
             if paint_panel(&mut icc, &mut panels, cur_pos)? {
                 painted_panels.insert(cur_pos);
             }
 
             move_robot(&mut icc, &mut cur_dir, &mut cur_pos, &mut panels);
             input_panel_to_robot(&cur_pos, &mut panels, &mut icc)?;
+
+            debug!("");
         }
 
-        debug!("");
+        debug!("Painted {} panels at least once", painted_panels.len());
+        Ok(painted_panels.len())
     }
 
-    debug!("Painted {} panels at least once", painted_panels.len());
-    Ok(painted_panels.len())
-}
-
-pub fn part2(code: &ParsedInput) -> color_eyre::Result<&str> {
-    let mut icc = IntCodeComputer::load(code.clone());
-    let mut cur_dir = Direction::North;
-    let mut cur_pos = PointT::from((0, 0));
-    let mut panels = HashMap::from([(cur_pos, '.')]);
-
-    // Starting panel is WHITE
-    icc.push_input(1);
-    icc.enable_input_yield();
+    fn part2(code: &ParsedInput) -> color_eyre::Result<Answer> {
+        let mut icc = IntCodeComputer::load(code.clone());
+        let mut cur_dir = Direction::North;
+        let mut cur_pos = PointT::from((0, 0));
+        let mut panels = HashMap::from([(cur_pos, '.')]);
 
-    while !icc.has_halted() {
-        icc.run()?;
+        // Starting panel is WHITE
+        icc.push_input(1);
 
-        if icc.is_yielding() {
+        while icc.run()? == RunState::WaitingForInput {
             paint_panel(&mut icc, &mut panels, cur_pos)?;
             move_robot(&mut icc, &mut cur_dir, &mut cur_pos, &mut panels);
             input_panel_to_robot(&cur_pos, &mut panels, &mut icc)?;
+
+            debug!("");
         }
 
-        debug!("");
-    }
+        let mut min_x = 0;
+        let mut min_y = 0;
+        let mut max_x = 0;
+        let mut max_y = 0;
+        for point in panels.keys() {
+            min_x = min_x.min(point.x);
+            min_y = min_y.min(point.y);
 
-    let mut min_x = 0;
-    let mut min_y = 0;
-    let mut max_x = 0;
-    let mut max_y = 0;
-    for point in panels.keys() {
-        min_x = min_x.min(point.x);
-        min_y = min_y.min(point.y);
+            max_x = max_x.max(point.x);
+            max_y = max_y.max(point.y);
+        }
 
-        max_x = max_x.max(point.x);
-        max_y = max_y.max(point.y);
-    }
+        let width = (max_x - min_x) + 1;
+        let height = (max_y - min_y) + 1;
+        debug!("Creating grid. Width={width}, Height={height}");
 
-    let width = (max_x - min_x) + 1;
-    let height = (max_y - min_y) + 1;
-    debug!("Creating grid. Width={width}, Height={height}");
+        let mut grid = Grid::init(height as usize, width as usize, '.');
+        for (point, colour) in panels {
+            let new_x = shift_value_in_range(point.x, min_x..max_x + 1, 0..width);
+            let new_y = shift_value_in_range(point.y, min_y..max_y + 1, 0..height);
 
-    let mut grid = Grid::init(height as usize, width as usize, '.');
-    for (point, colour) in panels {
-        let new_x = shift_value_in_range(point.x, min_x..max_x + 1, 0..width);
-        let new_y = shift_value_in_range(point.y, min_y..max_y + 1, 0..height);
+            let panel =
+                grid.get_mut(new_y as usize, new_x as usize)
+                    .ok_or(AdventError::LogicError(format!(
+                        "Failed to find {point} in grid",
+                    )))?;
 
-        let panel = grid
-            .get_mut(new_y as usize, new_x as usize)
-            .ok_or(AdventError::LogicError(format!(
-                "Failed to find {point} in grid",
-            )))?;
+            *panel = colour;
+        }
 
-        *panel = colour;
+        debug!("{grid:?}");
+        Ok(ocr::read_answer(&grid))
     }
-
-    // Actual answer is what gets printed to the screen
-    debug!("{grid:?}");
-    Ok("ZRZPKEZR")
 }
 
 fn paint_panel(
@@ -0,0 +1,163 @@
+use std::{
+    collections::VecDeque,
+    io::{BufRead, Write},
+};
+
+use super::{
+    int_code_computer::{IcProgram, IntCodeComputer},
+    int_code_io::OutputFn,
+};
+
+// Talks to a program that reads and writes ASCII text. Anything it outputs that isn't ASCII is
+// taken to be the answer, rather than part of the text
+pub struct AsciiComputer {
+    icc: IntCodeComputer,
+    input: VecDeque<i64>,
+    line: String,
+    answer: Option<i64>,
+}
+
+impl AsciiComputer {
+    pub fn load(program: IcProgram) -> Self {
+        Self {
+            icc: IntCodeComputer::load(program),
+            input: VecDeque::new(),
+            line: String::new(),
+            answer: None,
+        }
+    }
+
+    // Queues the line, followed by a newline, for the program to read
+    pub fn send_line(&mut self, line: &str) {
+        self.input.extend(line.bytes().map(i64::from));
+        self.input.push_back(i64::from(b'\n'));
+    }
+
+    // Runs until the program halts or has read everything that's been sent, returning the lines
+    // it's written. A line that's only partly written is kept back until it's finished, unless the
+    // program has halted
+    pub fn run(&mut self) -> color_eyre::Result<Vec<String>> {
+        let Self {
+            icc,
+            input,
+            line,
+            answer,
+        } = self;
+        let mut lines = Vec::new();
+
+        icc.run_io(
+            input,
+            &mut OutputFn(
+                |value| match u8::try_from(value).ok().filter(u8::is_ascii) {
+                    Some(b'\n') => lines.push(std::mem::take(line)),
+                    Some(ch) => line.push(char::from(ch)),
+                    None => *answer = Some(value),
+                },
+            ),
+        )?;
+
+        if icc.has_halted() && !line.is_empty() {
+            lines.push(std::mem::take(line));
+        }
+
+        Ok(lines)
+    }
+
+    // The last value the program output that wasn't ASCII
+    pub fn answer(&self) -> Option<i64> {
+        self.answer
+    }
+
+    pub fn has_halted(&self) -> bool {
+        self.icc.has_halted()
+    }
+}
+
+// Connects the program to `input` and `output`, so it can be played by hand. Stops when the
+// program halts or the input ends
+pub fn run_interactive<R, W>(program: IcProgram, input: R, mut output: W) -> color_eyre::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut ascii = AsciiComputer::load(program);
+    let mut lines = input.lines();
+
+    loop {
+        for line in ascii.run()? {
+            writeln!(output, "{line}")?;
+        }
+
+        if ascii.has_halted() {
+            if let Some(answer) = ascii.answer() {
+                writeln!(output, "answer: {answer}")?;
+            }
+            break;
+        }
+
+        write!(output, "> ")?;
+        output.flush()?;
+        let Some(line) = lines.next() else {
+            break;
+        };
+        ascii.send_line(line?.trim_end());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod ascii_tests {
+    use super::{run_interactive, AsciiComputer};
+    use crate::year2019::int_code_asm::assemble;
+
+    // Echoes each line it reads back in upper case, until it reads an empty line. Then it outputs
+    // 1000 plus the number of lines it read
+    const SHOUT: &str = "\
+        IN [50]
+        EQ [50], 10, [51]
+        JNZ [51], 22
+        ADD [50], -32, [52]
+        OUT [52]
+        ADD [53], 1, [53]
+        JNZ 1, 0
+        22: JZ [53], 38
+        OUT 10
+        ADD 0, 0, [53]
+        ADD [54], 1, [54]
+        JNZ 1, 0
+        38: ADD [54], 1000, [54]
+        OUT [54]
+        HLT";
+
+    fn program() -> Vec<i64> {
+        assemble(SHOUT).unwrap()
+    }
+
+    #[test]
+    fn ascii_lines() {
+        let mut ascii = AsciiComputer::load(program());
+        ascii.send_line("hello");
+        ascii.send_line("intcode");
+
+        assert_eq!(vec!["HELLO", "INTCODE"], ascii.run().unwrap());
+        assert!(!ascii.has_halted());
+        assert_eq!(None, ascii.answer());
+
+        ascii.send_line("");
+        assert!(ascii.run().unwrap().is_empty());
+        assert!(ascii.has_halted());
+        assert_eq!(Some(1002), ascii.answer());
+    }
+
+    #[test]
+    fn ascii_interactive() {
+        let mut output = Vec::new();
+        run_interactive(program(), "one\ntwo\n\n".as_bytes(), &mut output).unwrap();
+
+        assert_eq!(
+            "> ONE\n> TWO\n> answer: 1002\n",
+            String::from_utf8(output).unwrap()
+        );
+    }
+}
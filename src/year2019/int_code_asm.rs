@@ -0,0 +1,350 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+};
+
+use crate::error::AdventError;
+
+use super::{
+    int_code_computer::IcProgram,
+    op_code::{Mode, OpCode},
+};
+
+// How many values go on each DATA line of a listing
+const DATA_PER_LINE: usize = 8;
+
+// A decoded instruction along with its raw parameters
+#[derive(Debug, PartialEq)]
+pub struct Instruction {
+    pub op: OpCode,
+    pub params: Vec<i64>,
+}
+
+impl Instruction {
+    // None if the value at the address isn't an opcode, or the instruction runs off the end of the
+    // program. Values with extra mode digits aren't treated as instructions either, as they
+    // wouldn't assemble back to the same value
+    pub fn decode(program: &[i64], addr: usize) -> Option<Self> {
+        let raw_val = *program.get(addr)?;
+        let op = OpCode::try_from(raw_val).ok()?;
+        if i64::from(&op) != raw_val {
+            return None;
+        }
+
+        let params = program.get(addr + 1..addr + 1 + op.num_params())?.to_vec();
+        Some(Self { op, params })
+    }
+
+    // How many values the instruction takes up in memory
+    pub fn size(&self) -> usize {
+        1 + self.params.len()
+    }
+}
+
+// e.g. "ADD [9], 10, [rb-3]", with position mode parameters in brackets and relative mode ones
+// offset from the relative base
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.op.mnemonic())?;
+
+        for (index, (mode, value)) in self.op.modes().iter().zip(&self.params).enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+            match mode {
+                Mode::Pos => write!(f, "{separator}[{value}]")?,
+                Mode::Imm => write!(f, "{separator}{value}")?,
+                Mode::Rel if *value < 0 => write!(f, "{separator}[rb-{}]", value.unsigned_abs())?,
+                Mode::Rel => write!(f, "{separator}[rb+{value}]")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Produces a listing of the program with one instruction per line, prefixed by its address. Which
+// values are instructions is worked out by following the control flow from address 0, and
+// everything that isn't reached is listed as DATA
+pub fn disassemble(program: &[i64]) -> String {
+    let code = find_code(program);
+    let mut listing = String::new();
+
+    let mut addr = 0;
+    while addr < program.len() {
+        if let Some(instruction) = code.get(&addr) {
+            writeln!(listing, "{addr:>5}: {instruction}").unwrap();
+            addr += instruction.size();
+        } else {
+            let end = code
+                .range(addr..)
+                .next()
+                .map_or(program.len(), |(next, _)| *next)
+                .min(addr + DATA_PER_LINE);
+            let values = program[addr..end].iter().map(i64::to_string);
+
+            writeln!(
+                listing,
+                "{addr:>5}: DATA {}",
+                values.collect::<Vec<_>>().join(", ")
+            )
+            .unwrap();
+            addr = end;
+        }
+    }
+
+    listing
+}
+
+// Follows every path through the program from address 0, which ends at a halt, a jump that's always
+// taken or anything that isn't a valid instruction. Jumps to a position or relative address can't
+// be followed, but the return addresses that are pushed on to the stack with e.g.
+// "ADD 42, 0, [rb+1]" are
+fn find_code(program: &[i64]) -> BTreeMap<usize, Instruction> {
+    let mut code = BTreeMap::new();
+    let mut covered = vec![false; program.len()];
+    let mut entries = vec![0];
+
+    while let Some(mut addr) = entries.pop() {
+        while !covered.get(addr).copied().unwrap_or(true) {
+            let Some(instruction) = Instruction::decode(program, addr) else {
+                break;
+            };
+
+            let falls_through = match (&instruction.op, &instruction.params[..]) {
+                (OpCode::JNZ(cond_mode, target_mode), &[cond, target])
+                | (OpCode::JZ(cond_mode, target_mode), &[cond, target]) => {
+                    let taken = match instruction.op {
+                        OpCode::JNZ(_, _) => cond != 0,
+                        _ => cond == 0,
+                    };
+                    if *target_mode == Mode::Imm && (*cond_mode != Mode::Imm || taken) {
+                        entries.extend(usize::try_from(target));
+                    }
+                    *cond_mode != Mode::Imm || !taken
+                }
+                (OpCode::Add(Mode::Imm, Mode::Imm, Mode::Rel), &[lhs, rhs, _]) => {
+                    if let Some(addr) = lhs.checked_add(rhs) {
+                        entries.extend(usize::try_from(addr));
+                    }
+                    true
+                }
+                (OpCode::End, _) => false,
+                _ => true,
+            };
+
+            covered[addr..addr + instruction.size()].fill(true);
+            let next = addr + instruction.size();
+            code.insert(addr, instruction);
+
+            if !falls_through {
+                break;
+            }
+            addr = next;
+        }
+    }
+
+    code
+}
+
+// Parses a listing in the format `disassemble` produces back into a program. The addresses are
+// optional, but must be right when they're given, and anything after a ';' is a comment
+pub fn assemble(source: &str) -> color_eyre::Result<IcProgram> {
+    let mut program = Vec::new();
+
+    for (number, line) in source.lines().enumerate() {
+        let line = line.split(';').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = || AdventError::UnknownPattern(format!("line {}: {line}", number + 1));
+
+        let line = match line.split_once(':') {
+            Some((addr, rest)) => {
+                let addr = addr.trim().parse::<usize>().map_err(|_| error())?;
+                if addr != program.len() {
+                    return Err(AdventError::UnexpectedValue(
+                        format!("address {}", program.len()),
+                        format!("{addr} on line {}", number + 1),
+                    )
+                    .into());
+                }
+                rest.trim()
+            }
+            None => line,
+        };
+
+        let (mnemonic, params) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(mnemonic, params)| (mnemonic, params.trim()));
+        let params = params
+            .split(',')
+            .map(str::trim)
+            .filter(|param| !param.is_empty());
+        let mnemonic = mnemonic.to_uppercase();
+
+        if mnemonic == "DATA" {
+            for param in params {
+                program.push(param.parse().map_err(|_| error())?);
+            }
+            continue;
+        }
+
+        let (modes, values): (Vec<_>, Vec<_>) = params
+            .map(parse_param)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(error)?
+            .into_iter()
+            .unzip();
+        let op = OpCode::from_mnemonic(&mnemonic, &modes).ok_or_else(error)?;
+
+        program.push(i64::from(&op));
+        program.extend(values);
+    }
+
+    Ok(program)
+}
+
+// "[5]" is position mode, "5" immediate mode and "[rb+5]" relative mode
+fn parse_param(param: &str) -> Option<(Mode, i64)> {
+    let Some(inner) = param
+        .strip_prefix('[')
+        .and_then(|inner| inner.strip_suffix(']'))
+    else {
+        return param.parse().ok().map(|value| (Mode::Imm, value));
+    };
+
+    match inner.trim().strip_prefix("rb") {
+        Some(offset) if offset.trim().is_empty() => Some((Mode::Rel, 0)),
+        Some(offset) => offset
+            .replace(' ', "")
+            .parse()
+            .ok()
+            .map(|offset| (Mode::Rel, offset)),
+        None => inner.trim().parse().ok().map(|addr| (Mode::Pos, addr)),
+    }
+}
+
+#[cfg(test)]
+mod asm_tests {
+    use super::{assemble, disassemble, Instruction};
+    use crate::year2019::op_code::{Mode, OpCode};
+
+    // year2019::day05 part 2 big example, which outputs 999, 1000 or 1001 depending on whether the
+    // input is below, equal to or above 8
+    const BIG_EXAMPLE: [i64; 47] = [
+        3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0,
+        1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20,
+        1105, 1, 46, 98, 99,
+    ];
+
+    #[test]
+    fn asm_decode() {
+        assert_eq!(
+            Some(Instruction {
+                op: OpCode::Add(Mode::Rel, Mode::Imm, Mode::Pos),
+                params: vec![-3, 4, 5]
+            }),
+            Instruction::decode(&[1201, -3, 4, 5], 0)
+        );
+        // Runs off the end
+        assert_eq!(None, Instruction::decode(&[1201, -3, 4], 0));
+        // Unused mode digit
+        assert_eq!(None, Instruction::decode(&[10099], 0));
+        assert_eq!(None, Instruction::decode(&[-1], 0));
+    }
+
+    #[test]
+    fn asm_display() {
+        let instruction = Instruction::decode(&[21201, -3, 4, 0], 0).unwrap();
+
+        assert_eq!("ADD [rb-3], 4, [rb+0]", instruction.to_string());
+        assert_eq!("HLT", Instruction::decode(&[99], 0).unwrap().to_string());
+    }
+
+    #[test]
+    fn asm_disassemble() {
+        let listing = disassemble(&BIG_EXAMPLE);
+
+        assert_eq!(
+            "    0: IN [21]\n\
+             \x20   2: EQ [21], 8, [20]\n\
+             \x20   6: JNZ [20], 22\n\
+             \x20   9: LT 8, [21], [20]\n\
+             \x20  13: JZ [20], 31\n\
+             \x20  16: JZ 0, 36\n\
+             \x20  19: DATA 98, 0, 0\n\
+             \x20  22: MUL [21], 125, [20]\n\
+             \x20  26: OUT [20]\n\
+             \x20  28: JNZ 1, 46\n\
+             \x20  31: OUT 999\n\
+             \x20  33: JNZ 1, 46\n\
+             \x20  36: ADD 1000, 1, [20]\n\
+             \x20  40: OUT [20]\n\
+             \x20  42: JNZ 1, 46\n\
+             \x20  45: DATA 98\n\
+             \x20  46: HLT\n",
+            listing
+        );
+    }
+
+    #[test]
+    fn asm_disassemble_return_address() {
+        // Pushes 7 as the return address, calls the function at 9 which returns to it, then halts
+        let program = [21101, 7, 0, 0, 1105, 1, 9, 99, 0, 2106, 0, 0];
+
+        assert_eq!(
+            "    0: ADD 7, 0, [rb+0]\n\
+             \x20   4: JNZ 1, 9\n\
+             \x20   7: HLT\n\
+             \x20   8: DATA 0\n\
+             \x20   9: JZ 0, [rb+0]\n",
+            disassemble(&program)
+        );
+    }
+
+    #[test]
+    fn asm_disassemble_overflowing_return_address() {
+        let program = [21101, i64::MAX, 1, 0, 99];
+
+        assert_eq!(
+            "    0: ADD 9223372036854775807, 1, [rb+0]\n\
+             \x20   4: HLT\n",
+            disassemble(&program)
+        );
+    }
+
+    #[test]
+    fn asm_round_trip() {
+        let quine = [
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+
+        for program in [&BIG_EXAMPLE[..], &quine, &[1, 2, 3], &[]] {
+            assert_eq!(program, assemble(&disassemble(program)).unwrap());
+        }
+    }
+
+    #[test]
+    fn asm_assemble() {
+        let source = "\
+            ; Adds the input to itself
+            in [9]
+            add [9], [ 9 ], [rb - 1]   ; doubled
+            out [rb]
+            HLT
+            9: DATA 0";
+
+        assert_eq!(
+            vec![3, 9, 20001, 9, 9, -1, 204, 0, 99, 0],
+            assemble(source).unwrap()
+        );
+    }
+
+    #[test]
+    fn asm_assemble_errors() {
+        assert!(assemble("NOP").is_err());
+        assert!(assemble("ADD 1, 2").is_err());
+        assert!(assemble("OUT [x]").is_err());
+        assert!(assemble("DATA 1, two").is_err());
+        assert!(assemble("1: HLT").is_err());
+    }
+}
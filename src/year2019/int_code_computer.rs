@@ -1,42 +1,153 @@
 #![allow(clippy::zero_prefixed_literal)]
 
 use std::{
-    collections::VecDeque,
+    collections::{HashSet, VecDeque},
+    fs,
     ops::{Add, Mul},
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
 };
 
+use color_eyre::eyre::eyre;
 use itertools::Itertools;
-use simple_logger::SimpleLogger;
+use serde::{Deserialize, Serialize};
 
 use crate::error::AdventError;
 
-use super::op_code::{Mode, OpCode};
+use super::{
+    int_code_asm::Instruction,
+    int_code_io::{Input, Output},
+    int_code_profile::{Profile, Step},
+    op_code::{Mode, OpCode},
+};
 
 pub type IcProgram = Vec<i64>;
 
-#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
 pub enum IntCodeError {
-    // #[error("Yielding")]
-    // Yield,
-    #[error("Invalid memory address {0}")]
-    InvalidAccess(isize),
-    #[error("No input value provided")]
+    #[error("Unknown opcode at {0}")]
+    UnknownOpCode(Fault),
+    // Including writing in immediate mode, which is disallowed
+    #[error("Invalid parameter mode at {0}")]
+    InvalidMode(Fault),
+    #[error("Invalid memory address {addr} at {fault}")]
+    InvalidAccess { addr: i64, fault: Fault },
+    // Only from `run_to_halt`, otherwise the program waits for more input
+    #[error("Ran out of input at {0}")]
+    NoInput(Fault),
+}
+
+impl IntCodeError {
+    pub fn fault(&self) -> &Fault {
+        match self {
+            IntCodeError::UnknownOpCode(fault)
+            | IntCodeError::InvalidMode(fault)
+            | IntCodeError::InvalidAccess { fault, .. }
+            | IntCodeError::NoInput(fault) => fault,
+        }
+    }
+}
+
+// Where the computer was when an instruction failed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fault {
+    pub ip: usize,
+    // The instruction's value in memory, and what it decodes to if it's valid
+    pub raw: i64,
+    pub op: Option<OpCode>,
+    pub rel_base: usize,
+}
+
+impl std::fmt::Display for Fault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ip {}, instruction {}", self.ip, self.raw)?;
+        if let Some(op) = &self.op {
+            write!(f, " ({})", op.mnemonic())?;
+        }
+        write!(f, ", relative base {}", self.rel_base)
+    }
+}
+
+// Why the computer stopped running
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RunState {
+    // The instruction pointer is left on the halt instruction, so running it again halts again
+    Halted,
+    // The instruction pointer is left on the input instruction, to be run again once there's input
+    WaitingForInput,
+    // An output instruction has just been run, see `run_to_output`
+    OutputReady,
+}
+
+// What went wrong while running an instruction, before it's known where
+enum Trap {
+    UnknownOpCode,
+    InvalidMode,
+    InvalidAccess(i64),
     NoInput,
-    #[error("Writing in immediate mode is disallowed")]
-    ImmediateWrite,
 }
 
+// Clones share memory until one of them writes to it, so forking a machine to try different
+// inputs from the same point is cheap. The first write copies the whole of memory rather than just
+// what's written to, which is fine for programs the size of the puzzles'. Machines can be sent to
+// other threads, forks included
+#[derive(Clone)]
 pub struct IntCodeComputer {
-    memory: Vec<i64>,
+    // Grows as the program writes past the end of it. Anything that's never been written reads as 0
+    memory: Arc<Vec<i64>>,
     ip: usize,
     rel_base: usize,
 
     input_buffer: VecDeque<i64>,
     output_buffer: VecDeque<i64>,
 
-    yield_for_input: bool,
-    yielding: bool,
-    halted: bool,
+    // Debugging aids, see `run_to_break`
+    breakpoints: HashSet<usize>,
+    watchpoints: HashSet<usize>,
+    watch_hit: Option<usize>,
+
+    // Shared with any forks, so everything they run is counted together
+    profile: Option<Arc<Mutex<Profile>>>,
+
+    // Instructions that have been decoded, by address. Shared with forks like memory is
+    decoded: Arc<Vec<Option<Decoded>>>,
+}
+
+// Why `run_to_break` stopped
+#[derive(Debug, Eq, PartialEq)]
+pub enum Break {
+    // About to run the instruction at the address
+    Breakpoint(usize),
+    // The last instruction wrote to the address
+    Watchpoint(usize),
+    WaitingForInput,
+    Halted,
+}
+
+// The machine's state at a point in time, which can be saved to a file and restored later. Memory
+// is shared with the machine until it next writes to it
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Snapshot {
+    pub ip: usize,
+    pub rel_base: usize,
+    pub memory: Arc<Vec<i64>>,
+    pub input: VecDeque<i64>,
+    pub output: VecDeque<i64>,
+}
+
+impl Snapshot {
+    pub fn load(path: &Path) -> color_eyre::Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| eyre!("Failed to read {}: {err}", path.display()))?;
+        serde_json::from_str(&contents)
+            .map_err(|err| eyre!("Failed to parse {}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> color_eyre::Result<()> {
+        let contents = serde_json::to_string(self)?;
+        fs::write(path, contents + "\n")
+            .map_err(|err| eyre!("Failed to write {}: {err}", path.display()))
+    }
 }
 
 impl IntCodeComputer {
@@ -51,112 +162,294 @@ impl IntCodeComputer {
     }
 
     pub fn load(code: Vec<i64>) -> Self {
-        let mut memory = code;
-        memory.resize(2056, -1);
-
         Self {
-            memory,
+            memory: Arc::new(code),
             ip: 0,
             rel_base: 0,
             input_buffer: VecDeque::new(),
             output_buffer: VecDeque::new(),
-            yield_for_input: false,
-            yielding: false,
-            halted: false,
+            breakpoints: HashSet::new(),
+            watchpoints: HashSet::new(),
+            watch_hit: None,
+            profile: None,
+            decoded: Arc::default(),
         }
     }
 
-    pub fn enable_input_yield(&mut self) {
-        self.yield_for_input = true;
+    // Runs until the program halts, or needs input that isn't there yet
+    pub fn run(&mut self) -> Result<RunState, IntCodeError> {
+        log::trace!("{:?}", self.memory);
+
+        loop {
+            match self.step()? {
+                None | Some(RunState::OutputReady) => {}
+                Some(state) => return Ok(state),
+            }
+        }
     }
 
-    pub fn enable_debug_mode(&mut self) -> color_eyre::Result<()> {
-        Ok(SimpleLogger::new().init()?)
+    // Like `run`, but running out of input is an error, for a program that's given all of its input
+    // up front
+    pub fn run_to_halt(&mut self) -> Result<(), IntCodeError> {
+        match self.run()? {
+            RunState::Halted => Ok(()),
+            _ => Err(self.error(self.ip, Trap::NoInput)),
+        }
     }
 
-    pub fn run(&mut self) -> color_eyre::Result<()> {
-        log::trace!("{:?}", self.memory);
+    // Like `run`, but also stops after each value that's output
+    pub fn run_to_output(&mut self) -> Result<RunState, IntCodeError> {
+        loop {
+            if let Some(state) = self.step()? {
+                return Ok(state);
+            }
+        }
+    }
 
-        self.halted = false;
-        self.yielding = false;
+    // Runs until the program halts, or needs input that isn't there yet. Input is only read when
+    // the program asks for it, and everything the program outputs is written out. Once it's read a
+    // default, see `Input::read_default`, it stops the next time it needs input
+    pub fn run_io<I, O>(&mut self, input: &mut I, output: &mut O) -> Result<RunState, IntCodeError>
+    where
+        I: Input + ?Sized,
+        O: Output + ?Sized,
+    {
+        let mut read_default = false;
 
         loop {
-            match self.parse_instruction()? {
-                OpCode::Add(mode1, mode2, mode3) => {
-                    let param1 = self.next_with_mode(mode1, AccessType::Read)?;
-                    let param2 = self.next_with_mode(mode2, AccessType::Read)?;
-                    let write_addr = self.next_with_mode(mode3, AccessType::Write)? as usize;
-
-                    log::trace!("  {param1} + {param2}");
-                    self.arithmetic_op(param1, param2, write_addr, Add::add)?
-                }
-                OpCode::Mul(mode1, mode2, mode3) => {
-                    let param1 = self.next_with_mode(mode1, AccessType::Read)?;
-                    let param2 = self.next_with_mode(mode2, AccessType::Read)?;
-                    let write_addr = self.next_with_mode(mode3, AccessType::Write)? as usize;
+            let state = self.run()?;
+            while let Some(value) = self.next_output() {
+                output.write(value);
+            }
 
-                    log::trace!("  {param1} x {param2}");
-                    self.arithmetic_op(param1, param2, write_addr, Mul::mul)?
-                }
-                OpCode::In(mode) => {
-                    let addr = self.next_with_mode(mode, AccessType::Write)?;
+            if state == RunState::Halted || read_default {
+                return Ok(state);
+            }
+            match input.read() {
+                Some(value) => self.push_input(value),
+                None => return Ok(state),
+            }
+            read_default = input.read_default();
+        }
+    }
 
-                    self.input(addr as usize)?;
+    // Runs a single instruction, returning the state it's left the program in if it can't carry on
+    // or has just output a value
+    pub fn step(&mut self) -> Result<Option<RunState>, IntCodeError> {
+        self.execute()
+    }
 
-                    if self.yielding {
-                        return Ok(());
-                    }
-                }
-                OpCode::Out(mode) => {
-                    let value = self.next_with_mode(mode, AccessType::Read)?;
+    // Steps through the program until it's about to run an instruction with a breakpoint, an
+    // instruction has written to an address with a watchpoint, or it halts or needs input. The
+    // first instruction is always run, so a breakpoint doesn't stop it from being continued
+    pub fn run_to_break(&mut self) -> Result<Break, IntCodeError> {
+        loop {
+            self.watch_hit = None;
 
-                    log::trace!("  {value}");
-                    self.output(value)?
-                }
-                OpCode::JNZ(mode1, mode2) => {
-                    let value = self.next_with_mode(mode1, AccessType::Read)?;
-                    let jmp_addr = self.next_with_mode(mode2, AccessType::Read)?;
+            match self.step()? {
+                Some(RunState::Halted) => return Ok(Break::Halted),
+                Some(RunState::WaitingForInput) => return Ok(Break::WaitingForInput),
+                _ => {}
+            }
+            if let Some(addr) = self.watch_hit.take() {
+                return Ok(Break::Watchpoint(addr));
+            } else if self.breakpoints.contains(&self.ip) {
+                return Ok(Break::Breakpoint(self.ip));
+            }
+        }
+    }
 
-                    log::trace!("  if {value} != 0 then jmp {jmp_addr}");
-                    self.jmp_op(value, jmp_addr as usize, PartialEq::ne)?
-                }
-                OpCode::JZ(mode1, mode2) => {
-                    let value = self.next_with_mode(mode1, AccessType::Read)?;
-                    let jmp_addr = self.next_with_mode(mode2, AccessType::Read)?;
+    // Returns whether the breakpoint is now set, i.e. setting it again removes it
+    pub fn toggle_breakpoint(&mut self, addr: usize) -> bool {
+        toggle(&mut self.breakpoints, addr)
+    }
 
-                    log::trace!("  if {value} == 0 then jmp {jmp_addr}");
-                    self.jmp_op(value, jmp_addr as usize, PartialEq::eq)?
-                }
-                OpCode::LT(mode1, mode2, mode3) => {
-                    let param1 = self.next_with_mode(mode1, AccessType::Read)?;
-                    let param2 = self.next_with_mode(mode2, AccessType::Read)?;
-                    let write_addr = self.next_with_mode(mode3, AccessType::Write)? as usize;
+    pub fn toggle_watchpoint(&mut self, addr: usize) -> bool {
+        toggle(&mut self.watchpoints, addr)
+    }
 
-                    log::trace!("  {param1} < {param2}");
-                    self.cmp_op(param1, param2, write_addr, PartialOrd::lt)?
-                }
-                OpCode::EQ(mode1, mode2, mode3) => {
-                    let param1 = self.next_with_mode(mode1, AccessType::Read)?;
-                    let param2 = self.next_with_mode(mode2, AccessType::Read)?;
-                    let write_addr = self.next_with_mode(mode3, AccessType::Write)? as usize;
+    pub fn breakpoints(&self) -> &HashSet<usize> {
+        &self.breakpoints
+    }
 
-                    log::trace!("  {param1} == {param2}");
-                    self.cmp_op(param1, param2, write_addr, PartialEq::eq)?
-                }
-                OpCode::RBO(mode) => {
-                    let param = self.next_with_mode(mode, AccessType::Read)?;
+    pub fn watchpoints(&self) -> &HashSet<usize> {
+        &self.watchpoints
+    }
 
-                    log::trace!("  adjust rel_base by {param}");
-                    self.adj_rel_base(param)?
-                }
-                OpCode::End => {
-                    self.halted = true;
-                    break;
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn rel_base(&self) -> usize {
+        self.rel_base
+    }
+
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            ip: self.ip,
+            rel_base: self.rel_base,
+            memory: Arc::clone(&self.memory),
+            input: self.input_buffer.clone(),
+            output: self.output_buffer.clone(),
+        }
+    }
+
+    // Puts the machine back into the state it was in when the snapshot was taken. Breakpoints and
+    // watchpoints are kept as they are
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.ip = snapshot.ip;
+        self.rel_base = snapshot.rel_base;
+        self.memory = Arc::clone(&snapshot.memory);
+        self.decoded = Arc::default();
+        self.input_buffer = snapshot.input.clone();
+        self.output_buffer = snapshot.output.clone();
+        self.watch_hit = None;
+    }
+
+    // A copy of the machine that can be run separately, sharing memory until either writes to it,
+    // when the one writing takes a copy of all of it
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    // The instruction that'll be run next, if there's a valid one at the instruction pointer
+    pub fn current_instruction(&self) -> Option<Instruction> {
+        Instruction::decode(&self.memory, self.ip)
+    }
+
+    // Counts every instruction that's run from now on, see `Profile`
+    pub fn enable_profiling(&mut self, profile: Profile) {
+        self.profile = Some(Arc::new(Mutex::new(profile)));
+    }
+
+    pub fn profile(&self) -> Option<MutexGuard<'_, Profile>> {
+        self.profile
+            .as_ref()
+            .map(|profile| profile.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    fn execute(&mut self) -> Result<Option<RunState>, IntCodeError> {
+        let ip = self.ip;
+        let step = self.profile.as_ref().and_then(|_| Step::start(self));
+
+        let state = self.execute_op().map_err(|trap| self.error(ip, trap))?;
+
+        // An input instruction that's waiting for input hasn't been run yet
+        if let (Some(profile), Some(step)) = (&self.profile, step) {
+            if state != Some(RunState::WaitingForInput) {
+                profile
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .record(step, self);
+            }
+        }
+
+        Ok(state)
+    }
+
+    // Where the computer is now, for an error from the instruction at `ip`
+    fn error(&self, ip: usize, trap: Trap) -> IntCodeError {
+        let raw = self.read(ip);
+        let fault = Fault {
+            ip,
+            raw,
+            op: OpCode::try_from(raw).ok(),
+            rel_base: self.rel_base,
+        };
+
+        match trap {
+            Trap::UnknownOpCode => IntCodeError::UnknownOpCode(fault),
+            Trap::InvalidMode => IntCodeError::InvalidMode(fault),
+            Trap::InvalidAccess(addr) => IntCodeError::InvalidAccess { addr, fault },
+            Trap::NoInput => IntCodeError::NoInput(fault),
+        }
+    }
+
+    fn execute_op(&mut self) -> Result<Option<RunState>, Trap> {
+        let Decoded { op, params } = self.decode()?;
+        log::trace!("[{}] {op:?} ({params:?})", self.ip);
+
+        self.ip += 1 + op.num_params();
+        let [raw1, raw2, raw3] = params;
+
+        match op {
+            OpCode::Add(mode1, mode2, mode3) => {
+                let param1 = self.with_mode(mode1, raw1, AccessType::Read)?;
+                let param2 = self.with_mode(mode2, raw2, AccessType::Read)?;
+                let write_addr = self.with_mode(mode3, raw3, AccessType::Write)? as usize;
+
+                log::trace!("  {param1} + {param2}");
+                self.arithmetic_op(param1, param2, write_addr, Add::add);
+            }
+            OpCode::Mul(mode1, mode2, mode3) => {
+                let param1 = self.with_mode(mode1, raw1, AccessType::Read)?;
+                let param2 = self.with_mode(mode2, raw2, AccessType::Read)?;
+                let write_addr = self.with_mode(mode3, raw3, AccessType::Write)? as usize;
+
+                log::trace!("  {param1} x {param2}");
+                self.arithmetic_op(param1, param2, write_addr, Mul::mul);
+            }
+            OpCode::In(mode) => {
+                let addr = self.with_mode(mode, raw1, AccessType::Write)?;
+
+                if !self.input(addr as usize) {
+                    return Ok(Some(RunState::WaitingForInput));
                 }
             }
+            OpCode::Out(mode) => {
+                let value = self.with_mode(mode, raw1, AccessType::Read)?;
+
+                log::trace!("  {value}");
+                self.output(value);
+                return Ok(Some(RunState::OutputReady));
+            }
+            OpCode::JNZ(mode1, mode2) => {
+                let value = self.with_mode(mode1, raw1, AccessType::Read)?;
+                let jmp_addr = self.with_mode(mode2, raw2, AccessType::Read)?;
+
+                log::trace!("  if {value} != 0 then jmp {jmp_addr}");
+                self.jmp_op(value, jmp_addr, PartialEq::ne)?;
+            }
+            OpCode::JZ(mode1, mode2) => {
+                let value = self.with_mode(mode1, raw1, AccessType::Read)?;
+                let jmp_addr = self.with_mode(mode2, raw2, AccessType::Read)?;
+
+                log::trace!("  if {value} == 0 then jmp {jmp_addr}");
+                self.jmp_op(value, jmp_addr, PartialEq::eq)?;
+            }
+            OpCode::LT(mode1, mode2, mode3) => {
+                let param1 = self.with_mode(mode1, raw1, AccessType::Read)?;
+                let param2 = self.with_mode(mode2, raw2, AccessType::Read)?;
+                let write_addr = self.with_mode(mode3, raw3, AccessType::Write)? as usize;
+
+                log::trace!("  {param1} < {param2}");
+                self.cmp_op(param1, param2, write_addr, PartialOrd::lt);
+            }
+            OpCode::EQ(mode1, mode2, mode3) => {
+                let param1 = self.with_mode(mode1, raw1, AccessType::Read)?;
+                let param2 = self.with_mode(mode2, raw2, AccessType::Read)?;
+                let write_addr = self.with_mode(mode3, raw3, AccessType::Write)? as usize;
+
+                log::trace!("  {param1} == {param2}");
+                self.cmp_op(param1, param2, write_addr, PartialEq::eq);
+            }
+            OpCode::RBO(mode) => {
+                let param = self.with_mode(mode, raw1, AccessType::Read)?;
+
+                log::trace!("  adjust rel_base by {param}");
+                self.adj_rel_base(param)?;
+            }
+            OpCode::End => {
+                self.ip -= 1;
+                return Ok(Some(RunState::Halted));
+            }
         }
 
-        Ok(())
+        Ok(None)
     }
 
     pub fn push_input(&mut self, input: i64) {
@@ -175,96 +468,109 @@ impl IntCodeComputer {
         self.output_buffer.back()
     }
 
+    // Whether the instruction pointer is on a halt instruction
     pub fn has_halted(&self) -> bool {
-        self.halted
+        self.current_op() == Some(OpCode::End)
     }
 
-    pub fn is_yielding(&self) -> bool {
-        self.yielding
+    // Whether the instruction pointer is on an input instruction, with nothing to read
+    pub fn is_waiting_for_input(&self) -> bool {
+        matches!(self.current_op(), Some(OpCode::In(_))) && self.input_buffer.is_empty()
     }
 
-    pub fn read(&self, addr: usize) -> Result<i64, IntCodeError> {
-        self.memory
-            .get(addr)
-            .cloned()
-            .ok_or(IntCodeError::InvalidAccess(addr as isize))
+    pub fn read(&self, addr: usize) -> i64 {
+        self.memory.get(addr).cloned().unwrap_or(0)
     }
 
     // Currently only used for unit testing
-    fn _read_block(&self, addr: usize, len: usize) -> Result<&[i64], IntCodeError> {
-        let end = addr + len;
+    fn _read_block(&self, addr: usize, len: usize) -> Result<Vec<i64>, IntCodeError> {
+        let end = addr
+            .checked_add(len)
+            .ok_or_else(|| self.error(self.ip, Trap::InvalidAccess(addr as i64)))?;
 
-        self.memory
-            .get(addr..end)
-            .ok_or(IntCodeError::InvalidAccess(addr as isize))
+        Ok((addr..end).map(|addr| self.read(addr)).collect())
     }
 
-    fn write(&mut self, addr: usize, val: i64) -> Result<(), IntCodeError> {
-        if let Some(cur_val) = self.memory.get_mut(addr) {
-            log::trace!("  Writing {val} to {addr}");
-            *cur_val = val;
-            Ok(())
-        } else {
-            Err(IntCodeError::InvalidAccess(addr as isize))
-        }
+    fn current_op(&self) -> Option<OpCode> {
+        OpCode::try_from(self.read(self.ip)).ok()
     }
 
-    fn parse_instruction(&mut self) -> color_eyre::Result<OpCode> {
-        let raw_val = self.next()?;
-        let op = OpCode::try_from(raw_val)?;
+    fn write(&mut self, addr: usize, val: i64) {
+        if !self.watchpoints.is_empty() && self.watchpoints.contains(&addr) {
+            self.watch_hit = Some(addr);
+        }
+        // Any instruction that's been decoded from the address is out of date now
+        let start = addr.saturating_sub(MAX_INSTRUCTION_SIZE - 1);
+        let end = (addr + 1).min(self.decoded.len());
+        if self
+            .decoded
+            .get(start..end)
+            .is_some_and(|cached| cached.iter().any(Option::is_some))
+        {
+            Arc::make_mut(&mut self.decoded)[start..end].fill(None);
+        }
 
-        let upper = self.memory.len().min(self.ip + op.num_params());
-        let context = &self.memory[self.ip - 1..upper];
-        log::trace!("[{}] {op:?} ({context:?})", self.ip - 1);
+        let memory = Arc::make_mut(&mut self.memory);
+        if addr >= memory.len() {
+            memory.resize(addr + 1, 0);
+        }
 
-        Ok(op)
+        log::trace!("  Writing {val} to {addr}");
+        memory[addr] = val;
     }
 
-    // Reads value at instruction pointer then advances it one step
-    fn next(&mut self) -> Result<i64, IntCodeError> {
-        let value = self.read(self.ip);
-        self.ip += 1;
+    // The instruction at the instruction pointer, which is only decoded the first time it's run
+    // unless it's been written over since
+    fn decode(&mut self) -> Result<Decoded, Trap> {
+        if let Some(Some(decoded)) = self.decoded.get(self.ip) {
+            return Ok(*decoded);
+        }
+
+        let raw = self.read(self.ip);
+        let op = OpCode::try_from(raw).map_err(|_| match OpCode::try_from(raw % 100) {
+            Ok(_) => Trap::InvalidMode,
+            Err(_) => Trap::UnknownOpCode,
+        })?;
+        let mut params = [0; 3];
+        for (offset, param) in params.iter_mut().take(op.num_params()).enumerate() {
+            *param = self.read(self.ip + 1 + offset);
+        }
+        let decoded = Decoded { op, params };
+
+        let cache = Arc::make_mut(&mut self.decoded);
+        if self.ip >= cache.len() {
+            cache.resize(self.ip.max(self.memory.len()) + 1, None);
+        }
+        cache[self.ip] = Some(decoded);
 
-        value
+        Ok(decoded)
     }
 
-    // Read returns the value at the address specified by the next parameter based on the mode
-    // Write returns the value of the next parameter as an address based on the mode
-    fn next_with_mode(&mut self, mode: Mode, access_type: AccessType) -> Result<i64, IntCodeError> {
+    // Read returns the value at the address specified by the parameter based on the mode
+    // Write returns the value of the parameter as an address based on the mode
+    fn with_mode(&self, mode: Mode, value: i64, access_type: AccessType) -> Result<i64, Trap> {
         match access_type {
-            AccessType::Read => {
-                let value = self.next()?;
-                match mode {
-                    Mode::Pos => self.read(value as usize),
-                    Mode::Imm => Ok(value),
-                    Mode::Rel => {
-                        let addr = self.rel_base.checked_add_signed(value as isize).ok_or(
-                            IntCodeError::InvalidAccess(self.rel_base as isize - value as isize),
-                        )?;
-                        self.read(addr)
-                    }
-                }
-            }
+            AccessType::Read => match mode {
+                Mode::Pos => Ok(self.read(to_addr(value)?)),
+                Mode::Imm => Ok(value),
+                Mode::Rel => Ok(self.read(to_addr(self.relative(value)?)?)),
+            },
             AccessType::Write => match mode {
-                Mode::Pos => self.next(),
-                Mode::Imm => Err(IntCodeError::ImmediateWrite),
+                Mode::Pos => {
+                    to_write_addr(value)?;
+                    Ok(value)
+                }
+                Mode::Imm => Err(Trap::InvalidMode),
                 Mode::Rel => {
-                    let offset = self.next()?;
-                    Ok(self.rel_base.checked_add_signed(offset as isize).ok_or(
-                        IntCodeError::InvalidAccess(self.rel_base as isize - offset as isize),
-                    )? as i64)
+                    let addr = self.relative(value)?;
+                    to_write_addr(addr)?;
+                    Ok(addr)
                 }
             },
         }
     }
 
-    fn arithmetic_op<Op>(
-        &mut self,
-        param1: i64,
-        param2: i64,
-        write_addr: usize,
-        op: Op,
-    ) -> color_eyre::Result<()>
+    fn arithmetic_op<Op>(&mut self, param1: i64, param2: i64, write_addr: usize, op: Op)
     where
         Op: FnOnce(i64, i64) -> i64,
     {
@@ -274,12 +580,10 @@ impl IntCodeComputer {
         // the first two indicate the positions from which you should read the input values,
         // and the third indicates the position at which the output should be stored.
         let result = op(param1, param2);
-        self.write(write_addr, result)?;
-
-        Ok(())
+        self.write(write_addr, result);
     }
 
-    fn jmp_op<Op>(&mut self, value: i64, jmp_addr: usize, op: Op) -> color_eyre::Result<()>
+    fn jmp_op<Op>(&mut self, value: i64, jmp_addr: i64, op: Op) -> Result<(), Trap>
     where
         Op: FnOnce(&i64, &i64) -> bool,
     {
@@ -289,19 +593,13 @@ impl IntCodeComputer {
         // Otherwise, it does nothing.
 
         if op(&value, &0) {
-            self.ip = jmp_addr;
+            self.ip = to_addr(jmp_addr)?;
         }
 
         Ok(())
     }
 
-    fn cmp_op<Op>(
-        &mut self,
-        param1: i64,
-        param2: i64,
-        write_addr: usize,
-        op: Op,
-    ) -> color_eyre::Result<()>
+    fn cmp_op<Op>(&mut self, param1: i64, param2: i64, write_addr: usize, op: Op)
     where
         Op: FnOnce(&i64, &i64) -> bool,
     {
@@ -311,56 +609,91 @@ impl IntCodeComputer {
         // Otherwise, it stores 0.
 
         if op(&param1, &param2) {
-            self.write(write_addr, 1)?;
+            self.write(write_addr, 1);
         } else {
-            self.write(write_addr, 0)?;
+            self.write(write_addr, 0);
         }
-
-        Ok(())
     }
 
-    fn input(&mut self, addr: usize) -> Result<(), IntCodeError> {
+    // Returns whether there was any input to read
+    fn input(&mut self, addr: usize) -> bool {
         // Opcode 3 takes a single integer as input and saves it to the position given by its only parameter.
         // For example, the instruction 3,50 would take an input value and store it at address 50.
 
         if let Some(input) = self.input_buffer.pop_front() {
-            self.write(addr, input)?;
-            Ok(())
-        } else if self.yield_for_input {
-            // We are yielding so will need to re-run this input instruction
-            self.ip -= 2;
-            self.yielding = true;
-            Ok(())
-            //Err(IntCodeError::Yield)
+            self.write(addr, input);
+            true
         } else {
-            Err(IntCodeError::NoInput)
+            // We are waiting so will need to re-run this input instruction
+            self.ip -= 2;
+            false
         }
     }
 
-    fn output(&mut self, value: i64) -> color_eyre::Result<()> {
+    fn output(&mut self, value: i64) {
         // Opcode 4 outputs the value of its only parameter.
         // For example, the instruction 4,50 would output the value at address 50.
 
         // Is this the best way to represent output?
         self.output_buffer.push_back(value);
-
-        Ok(())
     }
 
-    fn adj_rel_base(&mut self, value: i64) -> color_eyre::Result<()> {
+    fn adj_rel_base(&mut self, value: i64) -> Result<(), Trap> {
         // Opcode 9 adjusts the relative base address by the value of its only parameter
         // For example, the instruction 109, -10 would dec
-        self.rel_base =
-            self.rel_base
-                .checked_add_signed(value as isize)
-                .ok_or(IntCodeError::InvalidAccess(
-                    self.rel_base as isize - value as isize,
-                ))?;
+        self.rel_base = to_addr(self.relative(value)?)?;
 
         Ok(())
     }
+
+    // The address `value` away from the relative base, which is invalid if it's too big to be one
+    fn relative(&self, value: i64) -> Result<i64, Trap> {
+        let rel_base = self.rel_base as i64;
+        rel_base
+            .checked_add(value)
+            .ok_or(Trap::InvalidAccess(rel_base.saturating_add(value)))
+    }
 }
 
+fn toggle(set: &mut HashSet<usize>, addr: usize) -> bool {
+    if set.remove(&addr) {
+        false
+    } else {
+        set.insert(addr)
+    }
+}
+
+// Addresses can't be negative, which would otherwise wrap around to a huge address that memory
+// would try to grow to
+fn to_addr(value: i64) -> Result<usize, Trap> {
+    usize::try_from(value).map_err(|_| Trap::InvalidAccess(value))
+}
+
+// Memory grows to fit whatever's written to it, so a write that's far past anything a real program
+// needs is taken to be a bug rather than something to allocate for
+fn to_write_addr(value: i64) -> Result<usize, Trap> {
+    to_addr(value).and_then(|addr| {
+        if addr < MAX_MEMORY {
+            Ok(addr)
+        } else {
+            Err(Trap::InvalidAccess(value))
+        }
+    })
+}
+
+// An opcode and its parameters, decoded ready to run. Parameters past the opcode's are 0
+#[derive(Clone, Copy, Debug)]
+struct Decoded {
+    op: OpCode,
+    params: [i64; 3],
+}
+
+// The opcode and up to 3 parameters
+const MAX_INSTRUCTION_SIZE: usize = 4;
+
+// How many cells memory can grow to, 128MB worth
+const MAX_MEMORY: usize = 1 << 24;
+
 enum AccessType {
     Read,
     Write,
@@ -368,33 +701,155 @@ enum AccessType {
 
 #[cfg(test)]
 mod icc_tests {
-    use std::collections::VecDeque;
+    use std::{collections::VecDeque, sync::Arc, thread};
 
-    use super::IntCodeComputer;
+    use super::{Break, Fault, IntCodeComputer, IntCodeError, RunState, Snapshot};
+    use crate::year2019::op_code::{Mode, OpCode};
 
     #[test]
     fn icc_read_block_ok() {
         let icc = IntCodeComputer::load(vec![1, 2, 3, 4, 5]);
 
-        assert_eq!(Ok(&[2, 3, 4][..]), icc._read_block(1, 3));
+        assert_eq!(Ok(vec![2, 3, 4]), icc._read_block(1, 3));
     }
 
     #[test]
-    fn icc_read_block_err_too_long() {
-        // ICC defaults to having 2056 memory slots filled with -1 - it doesn't yet have the
-        // concept of unintialized memory
+    fn icc_read_block_past_end() {
+        // Memory that's never been written is 0
         let icc = IntCodeComputer::load(vec![1, 2, 3, 4, 5]);
 
-        assert!(icc._read_block(1, 10000).is_err());
+        assert_eq!(Ok(vec![4, 5, 0, 0]), icc._read_block(3, 4));
+        assert_eq!(Ok(vec![0, 0, 0]), icc._read_block(10000, 3));
     }
 
     #[test]
-    fn icc_read_block_err_out_of_bounds() {
-        // ICC defaults to having 2056 memory slots filled with -1 - it doesn't yet have the
-        // concept of unintialized memory
+    fn icc_read_block_err_overflow() {
         let icc = IntCodeComputer::load(vec![1, 2, 3, 4, 5]);
 
-        assert!(icc._read_block(10000, 3).is_err());
+        assert!(icc._read_block(usize::MAX, 3).is_err());
+    }
+
+    #[test]
+    fn icc_write_grows_memory() {
+        // Add [10000](0) and 7, storing the result at 5000
+        let mut icc = IntCodeComputer::load(vec![1001, 10000, 7, 5000, 99]);
+
+        assert!(icc.run().is_ok());
+        assert_eq!(7, icc.read(5000));
+        assert_eq!(0, icc.read(4999));
+    }
+
+    #[test]
+    fn icc_write_too_far_err() {
+        let mut icc = IntCodeComputer::load(vec![1101, 1, 1, 1_000_000_000_000, 99]);
+        assert!(matches!(
+            icc.run(),
+            Err(IntCodeError::InvalidAccess {
+                addr: 1_000_000_000_000,
+                ..
+            })
+        ));
+
+        // Relative to a base that's in range
+        let mut icc = IntCodeComputer::load(vec![109, 1_000, 21101, 1, 1, 1 << 24, 99]);
+        assert!(matches!(icc.run(), Err(IntCodeError::InvalidAccess { .. })));
+        assert_eq!(7, icc.memory().len());
+    }
+
+    #[test]
+    fn icc_run_to_halt_no_input_err() {
+        let mut icc = IntCodeComputer::load(vec![3, 5, 3, 5, 99, 0]);
+        icc.push_input(1);
+        assert_eq!(
+            Err(IntCodeError::NoInput(Fault {
+                ip: 2,
+                raw: 3,
+                op: Some(OpCode::In(Mode::Pos)),
+                rel_base: 0
+            })),
+            icc.run_to_halt()
+        );
+
+        icc.push_input(2);
+        assert_eq!(Ok(()), icc.run_to_halt());
+    }
+
+    #[test]
+    fn icc_negative_address_err() {
+        let mut icc = IntCodeComputer::load(vec![1101, 1, 1, -1, 99]);
+        assert_eq!(
+            Err(IntCodeError::InvalidAccess {
+                addr: -1,
+                fault: Fault {
+                    ip: 0,
+                    raw: 1101,
+                    op: Some(OpCode::Add(Mode::Imm, Mode::Imm, Mode::Pos)),
+                    rel_base: 0
+                }
+            }),
+            icc.run()
+        );
+
+        let mut icc = IntCodeComputer::load(vec![4, -1, 99]);
+        assert!(matches!(
+            icc.run(),
+            Err(IntCodeError::InvalidAccess { addr: -1, .. })
+        ));
+    }
+
+    #[test]
+    fn icc_invalid_instruction_err() {
+        let mut icc = IntCodeComputer::load(vec![1101, 1, 1, 5, 42, 0]);
+        let err = icc.run().unwrap_err();
+
+        assert_eq!(
+            IntCodeError::UnknownOpCode(Fault {
+                ip: 4,
+                raw: 42,
+                op: None,
+                rel_base: 0
+            }),
+            err
+        );
+        assert_eq!(
+            "Unknown opcode at ip 4, instruction 42, relative base 0",
+            err.to_string()
+        );
+
+        // Writing in immediate mode
+        let mut icc = IntCodeComputer::load(vec![11101, 1, 1, 5, 99]);
+        assert!(matches!(
+            icc.run(),
+            Err(IntCodeError::InvalidMode(Fault {
+                ip: 0,
+                raw: 11101,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn icc_run_states() {
+        // Outputs each input doubled, until it reads 0
+        let mut icc = IntCodeComputer::load(vec![
+            3, 15, 1006, 15, 14, 1002, 15, 2, 16, 4, 16, 1105, 1, 0, 99, 0, 0,
+        ]);
+
+        assert_eq!(Ok(RunState::WaitingForInput), icc.run());
+        assert!(icc.is_waiting_for_input());
+
+        icc.push_input(3);
+        icc.push_input(4);
+        assert_eq!(Ok(RunState::OutputReady), icc.run_to_output());
+        assert_eq!(Some(6), icc.next_output());
+        assert_eq!(Ok(RunState::WaitingForInput), icc.run());
+        assert_eq!(Some(8), icc.next_output());
+
+        icc.push_input(0);
+        assert_eq!(Ok(RunState::Halted), icc.run());
+        assert!(icc.has_halted());
+        assert!(!icc.is_waiting_for_input());
+        assert_eq!(Ok(RunState::Halted), icc.run());
     }
 
     #[test]
@@ -402,7 +857,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![99]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[99][..]), icc._read_block(0, 1));
+        assert_eq!(Ok(vec![99]), icc._read_block(0, 1));
     }
 
     #[test]
@@ -410,7 +865,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1, 0, 0, 0, 99]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[2, 0, 0, 0, 99][..]), icc._read_block(0, 5));
+        assert_eq!(Ok(vec![2, 0, 0, 0, 99]), icc._read_block(0, 5));
     }
 
     #[test]
@@ -418,7 +873,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![2, 3, 0, 3, 99]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[2, 3, 0, 6, 99][..]), icc._read_block(0, 5));
+        assert_eq!(Ok(vec![2, 3, 0, 6, 99]), icc._read_block(0, 5));
     }
 
     #[test]
@@ -427,7 +882,7 @@ mod icc_tests {
         icc.push_input(8);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[3, 3, 99, 8][..]), icc._read_block(0, 4));
+        assert_eq!(Ok(vec![3, 3, 99, 8]), icc._read_block(0, 4));
     }
 
     #[test]
@@ -435,7 +890,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![4, 3, 104, 8, 99]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[4, 3, 104, 8, 99][..]), icc._read_block(0, 5));
+        assert_eq!(Ok(vec![4, 3, 104, 8, 99]), icc._read_block(0, 5));
         assert_eq!(&VecDeque::from([8, 8]), icc.get_output());
     }
 
@@ -445,7 +900,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1105, 1, 4, 0, 99]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1105, 1, 4, 0, 99][..]), icc._read_block(0, 5));
+        assert_eq!(Ok(vec![1105, 1, 4, 0, 99]), icc._read_block(0, 5));
     }
 
     #[test]
@@ -454,7 +909,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1105, 0, 4, 99, 0]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1105, 0, 4, 99, 0][..]), icc._read_block(0, 5));
+        assert_eq!(Ok(vec![1105, 0, 4, 99, 0]), icc._read_block(0, 5));
     }
 
     #[test]
@@ -463,7 +918,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1106, 0, 4, 0, 99]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1106, 0, 4, 0, 99][..]), icc._read_block(0, 5));
+        assert_eq!(Ok(vec![1106, 0, 4, 0, 99]), icc._read_block(0, 5));
     }
 
     #[test]
@@ -472,7 +927,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1106, 1, 4, 99, 0]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1106, 1, 4, 99, 0][..]), icc._read_block(0, 5));
+        assert_eq!(Ok(vec![1106, 1, 4, 99, 0]), icc._read_block(0, 5));
     }
 
     #[test]
@@ -481,7 +936,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1007, 5, 1, 6, 99, 0, 0]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1007, 5, 1, 6, 99, 0, 1][..]), icc._read_block(0, 7));
+        assert_eq!(Ok(vec![1007, 5, 1, 6, 99, 0, 1]), icc._read_block(0, 7));
     }
 
     #[test]
@@ -490,7 +945,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1007, 5, 0, 6, 99, 1, 1]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1007, 5, 0, 6, 99, 1, 0][..]), icc._read_block(0, 7));
+        assert_eq!(Ok(vec![1007, 5, 0, 6, 99, 1, 0]), icc._read_block(0, 7));
     }
 
     #[test]
@@ -499,7 +954,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1007, 5, 1, 6, 99, 1, 1]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1007, 5, 1, 6, 99, 1, 0][..]), icc._read_block(0, 7));
+        assert_eq!(Ok(vec![1007, 5, 1, 6, 99, 1, 0]), icc._read_block(0, 7));
     }
 
     #[test]
@@ -508,7 +963,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1008, 5, 1, 6, 99, 1, 0]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1008, 5, 1, 6, 99, 1, 1][..]), icc._read_block(0, 7));
+        assert_eq!(Ok(vec![1008, 5, 1, 6, 99, 1, 1]), icc._read_block(0, 7));
     }
 
     #[test]
@@ -517,7 +972,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1008, 5, 0, 6, 99, 1, 1]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1008, 5, 0, 6, 99, 1, 0][..]), icc._read_block(0, 7));
+        assert_eq!(Ok(vec![1008, 5, 0, 6, 99, 1, 0]), icc._read_block(0, 7));
     }
 
     #[test]
@@ -525,7 +980,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1101, -4, 3, 0, 99]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[-1, -4, 3, 0, 99][..]), icc._read_block(0, 5));
+        assert_eq!(Ok(vec![-1, -4, 3, 0, 99]), icc._read_block(0, 5));
     }
 
     #[test]
@@ -533,7 +988,7 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![1101, 2, 4, 5, 99, 0]);
 
         assert!(icc.run().is_ok());
-        assert_eq!(Ok(&[1101, 2, 4, 5, 99, 6][..]), icc._read_block(0, 6));
+        assert_eq!(Ok(vec![1101, 2, 4, 5, 99, 6]), icc._read_block(0, 6));
     }
 
     #[test]
@@ -557,7 +1012,47 @@ mod icc_tests {
     fn icc_rbo_dec_err() {
         let mut icc = IntCodeComputer::load(vec![109, -5, 99]);
 
-        assert!(icc.run().is_err());
+        assert!(matches!(
+            icc.run(),
+            Err(IntCodeError::InvalidAccess { addr: -5, .. })
+        ));
+    }
+
+    #[test]
+    fn icc_negative_jump_err() {
+        let mut icc = IntCodeComputer::load(vec![1105, 1, -1]);
+        assert_eq!(
+            Err(IntCodeError::InvalidAccess {
+                addr: -1,
+                fault: Fault {
+                    ip: 0,
+                    raw: 1105,
+                    op: Some(OpCode::JNZ(Mode::Imm, Mode::Imm)),
+                    rel_base: 0
+                }
+            }),
+            icc.run()
+        );
+
+        // Only taking the jump is an error
+        let mut icc = IntCodeComputer::load(vec![1106, 1, -1, 99]);
+        assert_eq!(Ok(RunState::Halted), icc.run());
+    }
+
+    #[test]
+    fn icc_rel_overflow_err() {
+        // Output [rb + i64::MAX] with a relative base of 1
+        let mut icc = IntCodeComputer::load(vec![109, 1, 204, i64::MAX, 99]);
+        assert!(matches!(
+            icc.run(),
+            Err(IntCodeError::InvalidAccess { addr: i64::MAX, .. })
+        ));
+
+        let mut icc = IntCodeComputer::load(vec![109, 1, 109, i64::MAX, 99]);
+        assert!(matches!(
+            icc.run(),
+            Err(IntCodeError::InvalidAccess { addr: i64::MAX, .. })
+        ));
     }
 
     #[test]
@@ -565,24 +1060,166 @@ mod icc_tests {
         let mut icc = IntCodeComputer::load(vec![2201, 1, 2, 5, 99, 0, 5, 6]);
         icc.rel_base = 5;
 
+        assert!(icc.run().is_ok());
+        assert_eq!(Ok(vec![2201, 1, 2, 5, 99, 11, 5, 6]), icc._read_block(0, 8));
+    }
+
+    #[test]
+    fn icc_step() {
+        let mut icc = IntCodeComputer::load(vec![1101, 2, 3, 7, 104, 5, 99, 0]);
+
+        assert_eq!(Ok(None), icc.step());
+        assert_eq!(4, icc.ip());
+        assert_eq!(5, icc.read(7));
+        assert!(icc.get_output().is_empty());
+
+        assert_eq!(Ok(Some(RunState::OutputReady)), icc.step());
+        assert_eq!(Ok(Some(RunState::Halted)), icc.step());
+        assert!(icc.has_halted());
+        assert_eq!(&VecDeque::from([5]), icc.get_output());
+
+        // Stepping once halted does nothing
+        assert_eq!(Ok(Some(RunState::Halted)), icc.step());
+        assert_eq!(6, icc.ip());
+    }
+
+    #[test]
+    fn icc_run_to_break() {
+        let mut icc = IntCodeComputer::load(vec![1101, 1, 2, 13, 1101, 3, 4, 14, 3, 13, 99]);
+        assert!(icc.toggle_breakpoint(4));
+        assert!(icc.toggle_watchpoint(14));
+
+        assert_eq!(Break::Breakpoint(4), icc.run_to_break().unwrap());
+        assert_eq!(Break::Watchpoint(14), icc.run_to_break().unwrap());
+        assert_eq!(Break::WaitingForInput, icc.run_to_break().unwrap());
+        assert_eq!(8, icc.ip());
+
+        icc.push_input(9);
+        assert_eq!(Break::Halted, icc.run_to_break().unwrap());
+        assert_eq!(Ok(vec![9, 7]), icc._read_block(13, 2));
+
+        assert!(!icc.toggle_breakpoint(4));
+        assert!(icc.breakpoints().is_empty());
+    }
+
+    #[test]
+    fn icc_snapshot() {
+        let mut icc = IntCodeComputer::load(vec![109, 5, 99]);
+
         assert!(icc.run().is_ok());
         assert_eq!(
-            Ok(&[2201, 1, 2, 5, 99, 11, 5, 6][..]),
-            icc._read_block(0, 8)
+            Snapshot {
+                ip: 2,
+                rel_base: 5,
+                memory: Arc::new(vec![109, 5, 99]),
+                input: VecDeque::new(),
+                output: VecDeque::new(),
+            },
+            icc.snapshot()
         );
     }
 
+    #[test]
+    fn icc_self_modifying() {
+        // Runs the ADD at 0 twice, changing its first parameter from 5 to 2 in between
+        let mut icc = IntCodeComputer::load(vec![
+            1101, 5, 0, 20, 1101, 2, 0, 1, 1001, 21, 1, 21, 1008, 21, 2, 22, 1006, 22, 0, 99, 0, 0,
+            0,
+        ]);
+
+        assert!(icc.run().is_ok());
+        assert_eq!(Ok(vec![2, 2, 1]), icc._read_block(20, 3));
+    }
+
+    #[test]
+    fn icc_restore() {
+        // Outputs each input doubled
+        let mut icc = IntCodeComputer::load(vec![3, 9, 1002, 9, 2, 9, 4, 9, 99, 0]);
+        icc.push_input(5);
+        let snapshot = icc.snapshot();
+
+        assert!(icc.run().is_ok());
+        assert_eq!(Some(&10), icc.last_output());
+        assert_eq!(10, icc.read(9));
+
+        icc.restore(&snapshot);
+        assert!(!icc.has_halted());
+        assert_eq!(0, icc.read(9));
+        assert!(icc.run().is_ok());
+        assert_eq!(vec![10], Vec::from(icc.get_output().clone()));
+    }
+
+    #[test]
+    fn icc_fork_copy_on_write() {
+        let mut icc = IntCodeComputer::load(vec![3, 9, 1002, 9, 2, 9, 4, 9, 99, 0]);
+        assert!(icc.run().is_ok());
+
+        let mut fork = icc.fork();
+        assert!(std::ptr::eq(icc.memory(), fork.memory()));
+
+        icc.push_input(1);
+        fork.push_input(2);
+        assert!(icc.run().is_ok());
+        assert!(!std::ptr::eq(icc.memory(), fork.memory()));
+        assert!(fork.run().is_ok());
+
+        assert_eq!(Some(&2), icc.last_output());
+        assert_eq!(Some(&4), fork.last_output());
+    }
+
+    #[test]
+    fn icc_fork_on_threads() {
+        let mut icc = IntCodeComputer::load(vec![3, 9, 1002, 9, 2, 9, 4, 9, 99, 0]);
+        assert!(icc.run().is_ok());
+
+        let outputs = thread::scope(|scope| {
+            let handles = (1..=3)
+                .map(|input| {
+                    let mut fork = icc.fork();
+                    scope.spawn(move || {
+                        fork.push_input(input);
+                        fork.run().map(|_| fork.next_output())
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(vec![Ok(Some(2)), Ok(Some(4)), Ok(Some(6))], outputs);
+    }
+
+    #[test]
+    fn icc_snapshot_file() {
+        let path = std::env::temp_dir().join("aoc_intcode_snapshot.json");
+        let mut icc = IntCodeComputer::load(vec![3, 9, 1002, 9, 2, 9, 4, 9, 99, 0]);
+        assert!(icc.run().is_ok());
+
+        assert!(icc.snapshot().save(&path).is_ok());
+        let snapshot = Snapshot::load(&path).unwrap();
+        assert_eq!(icc.snapshot(), snapshot);
+
+        let mut restored = IntCodeComputer::load(vec![]);
+        restored.restore(&snapshot);
+        restored.push_input(21);
+        assert!(restored.run().is_ok());
+        assert_eq!(Some(&42), restored.last_output());
+
+        assert!(Snapshot::load(&std::env::temp_dir().join("aoc_no_such_snapshot")).is_err());
+    }
+
     #[test]
     fn icc_day05_part2_example1() {
         // Using position mode, consider whether the input is equal to 8;
         // output 1 (if it is) or 0 (if it is not).
         let mut icc = IntCodeComputer::load(vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
-        //let _ = icc.enable_debug_mode();
         icc.push_input(8);
 
         assert!(icc.run().is_ok());
         assert_eq!(
-            Ok(&[3, 9, 8, 9, 10, 9, 4, 9, 99, 1, 8][..]),
+            Ok(vec![3, 9, 8, 9, 10, 9, 4, 9, 99, 1, 8]),
             icc._read_block(0, 11)
         );
         assert_eq!(&VecDeque::from([1]), icc.get_output());
@@ -597,7 +1234,7 @@ mod icc_tests {
 
         assert!(icc.run().is_ok());
         assert_eq!(
-            Ok(&[3, 9, 7, 9, 10, 9, 4, 9, 99, 0, 8][..]),
+            Ok(vec![3, 9, 7, 9, 10, 9, 4, 9, 99, 0, 8]),
             icc._read_block(0, 11)
         );
         assert_eq!(&VecDeque::from([0]), icc.get_output());
@@ -612,7 +1249,7 @@ mod icc_tests {
 
         assert!(icc.run().is_ok());
         assert_eq!(
-            Ok(&[3, 3, 1108, 0, 8, 3, 4, 3, 99][..]),
+            Ok(vec![3, 3, 1108, 0, 8, 3, 4, 3, 99]),
             icc._read_block(0, 9)
         );
         assert_eq!(&VecDeque::from([0]), icc.get_output());
@@ -627,7 +1264,7 @@ mod icc_tests {
 
         assert!(icc.run().is_ok());
         assert_eq!(
-            Ok(&[3, 3, 1107, 1, 8, 3, 4, 3, 99][..]),
+            Ok(vec![3, 3, 1107, 1, 8, 3, 4, 3, 99]),
             icc._read_block(0, 9)
         );
         assert_eq!(&VecDeque::from([1]), icc.get_output());
@@ -644,7 +1281,7 @@ mod icc_tests {
 
         assert!(icc.run().is_ok());
         assert_eq!(
-            Ok(&[3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, 0, 0, 1, 9][..]),
+            Ok(vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, 0, 0, 1, 9]),
             icc._read_block(0, 16)
         );
         assert_eq!(&VecDeque::from([0]), icc.get_output());
@@ -659,7 +1296,7 @@ mod icc_tests {
 
         assert!(icc.run().is_ok());
         assert_eq!(
-            Ok(&[3, 3, 1105, 5, 9, 1101, 0, 0, 12, 4, 12, 99, 1][..]),
+            Ok(vec![3, 3, 1105, 5, 9, 1101, 0, 0, 12, 4, 12, 99, 1]),
             icc._read_block(0, 13)
         );
         assert_eq!(&VecDeque::from([1]), icc.get_output());
@@ -717,13 +1354,13 @@ mod icc_tests {
     fn icc_day09_part1_more_memory() {
         // Takes no input and poduces a copy of itself as output
         let mut icc = IntCodeComputer::load(vec![
-            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 15, 101, 1006, 101, 0, 99,
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
         ]);
 
         assert!(icc.run().is_ok());
         assert_eq!(
             &VecDeque::from([
-                109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 15, 101, 1006, 101, 0, 99,
+                109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
             ]),
             icc.get_output()
         );
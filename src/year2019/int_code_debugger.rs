@@ -0,0 +1,303 @@
+use std::io::{BufRead, Write};
+
+use itertools::Itertools;
+
+use super::{
+    int_code_asm::Instruction,
+    int_code_computer::{Break, IcProgram, IntCodeComputer, RunState},
+};
+
+const HELP: &str = "\
+step [N]          (s) run the next N instructions, 1 by default
+continue          (c) run until a breakpoint, a watchpoint, input is needed or the program halts
+break ADDR        (b) set or clear a breakpoint on the instruction at ADDR
+watch ADDR        (w) set or clear a watchpoint on writes to ADDR
+input VALUE...    (i) queue values for the program to read
+mem ADDR [LEN]    (m) show LEN values of memory from ADDR, 8 by default
+dis [ADDR] [N]    (d) disassemble N instructions from ADDR, the instruction pointer and 10 by default
+regs              (r) show the instruction pointer, relative base, breakpoints and watchpoints
+help              (h) show this
+quit              (q) exit
+An empty line repeats the last command";
+
+// How many values `mem` shows on each line
+const MEM_PER_LINE: usize = 8;
+
+// An interactive debugger for Intcode programs, reading commands from `input` and writing to
+// `output` until it's told to quit or the input ends
+pub fn run_repl<R, W>(program: IcProgram, input: R, mut output: W) -> color_eyre::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut icc = IntCodeComputer::load(program);
+
+    writeln!(output, "Type help for a list of commands")?;
+    show_current(&icc, &mut output)?;
+
+    let mut last = String::new();
+    let mut lines = input.lines();
+    loop {
+        write!(output, "> ")?;
+        output.flush()?;
+
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line?;
+        let line = match line.trim() {
+            "" => last.clone(),
+            line => line.to_string(),
+        };
+
+        match command(&mut icc, &line, &mut output) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(err) => writeln!(output, "error: {err}")?,
+        }
+        last = line;
+
+        // Anything the program printed is shown straight away
+        let printed = std::iter::from_fn(|| icc.next_output()).collect_vec();
+        if !printed.is_empty() {
+            writeln!(output, "output: {}", printed.iter().join(", "))?;
+        }
+    }
+
+    Ok(())
+}
+
+// Runs a single command, returning whether to carry on
+fn command<W: Write>(
+    icc: &mut IntCodeComputer,
+    line: &str,
+    output: &mut W,
+) -> color_eyre::Result<bool> {
+    let mut words = line.split_whitespace();
+    let Some(name) = words.next() else {
+        return Ok(true);
+    };
+    let args: Vec<usize> = words.clone().map(str::parse).try_collect()?;
+
+    match name {
+        "step" | "s" => {
+            for _ in 0..args.first().copied().unwrap_or(1) {
+                match icc.step()? {
+                    Some(RunState::WaitingForInput) => writeln!(output, "waiting for input")?,
+                    Some(RunState::Halted) => writeln!(output, "halted")?,
+                    _ => continue,
+                }
+                break;
+            }
+            show_current(icc, output)?;
+        }
+        "continue" | "c" => {
+            match icc.run_to_break()? {
+                Break::Breakpoint(addr) => writeln!(output, "breakpoint at {addr}")?,
+                Break::Watchpoint(addr) => writeln!(output, "watchpoint on {addr} written")?,
+                Break::WaitingForInput => writeln!(output, "waiting for input")?,
+                Break::Halted => writeln!(output, "halted")?,
+            }
+            show_current(icc, output)?;
+        }
+        "break" | "b" => {
+            let addr = single_arg(&args)?;
+            let set = icc.toggle_breakpoint(addr);
+            writeln!(output, "breakpoint at {addr} {}", set_or_cleared(set))?;
+        }
+        "watch" | "w" => {
+            let addr = single_arg(&args)?;
+            let set = icc.toggle_watchpoint(addr);
+            writeln!(output, "watchpoint on {addr} {}", set_or_cleared(set))?;
+        }
+        "input" | "i" => {
+            // Values can be negative, unlike the other commands' arguments
+            for value in words {
+                icc.push_input(value.parse()?);
+            }
+        }
+        "mem" | "m" => {
+            let (addr, len) = match args[..] {
+                [addr] => (addr, MEM_PER_LINE),
+                [addr, len] => (addr, len),
+                _ => return Err(color_eyre::eyre::eyre!("expected ADDR [LEN]")),
+            };
+            // Stopping short of the end of memory rather than overflowing
+            let last = addr.saturating_add(len);
+            for start in (addr..last).step_by(MEM_PER_LINE) {
+                let end = start.saturating_add(MEM_PER_LINE).min(last);
+                let values = (start..end).map(|addr| icc.read(addr));
+                writeln!(output, "{start:>5}: {}", values.format(" "))?;
+            }
+        }
+        "dis" | "d" => {
+            let addr = args.first().copied().unwrap_or(icc.ip());
+            let count = args.get(1).copied().unwrap_or(10);
+            disassemble(icc.memory(), addr, count, output)?;
+        }
+        "regs" | "r" => {
+            let sorted = |set: &std::collections::HashSet<usize>| set.iter().sorted().join(", ");
+            writeln!(
+                output,
+                "ip={} rel_base={} breakpoints=[{}] watchpoints=[{}]",
+                icc.ip(),
+                icc.rel_base(),
+                sorted(icc.breakpoints()),
+                sorted(icc.watchpoints()),
+            )?;
+        }
+        "help" | "h" => writeln!(output, "{HELP}")?,
+        "quit" | "q" => return Ok(false),
+        _ => return Err(color_eyre::eyre::eyre!("unknown command {name}, try help")),
+    }
+
+    Ok(true)
+}
+
+fn single_arg(args: &[usize]) -> color_eyre::Result<usize> {
+    match args {
+        [arg] => Ok(*arg),
+        _ => Err(color_eyre::eyre::eyre!("expected ADDR")),
+    }
+}
+
+fn set_or_cleared(set: bool) -> &'static str {
+    if set {
+        "set"
+    } else {
+        "cleared"
+    }
+}
+
+// The instruction that'll run next, which stays on the halt instruction once it's halted
+fn show_current<W: Write>(icc: &IntCodeComputer, output: &mut W) -> color_eyre::Result<()> {
+    disassemble(icc.memory(), icc.ip(), 1, output)
+}
+
+// Decodes `count` instructions one after the other from the address, with anything that isn't an
+// instruction shown as a single DATA value. Stops short at the end of memory
+fn disassemble<W: Write>(
+    memory: &[i64],
+    mut addr: usize,
+    count: usize,
+    output: &mut W,
+) -> color_eyre::Result<()> {
+    for _ in 0..count {
+        let size = match Instruction::decode(memory, addr) {
+            Some(instruction) => {
+                writeln!(output, "{addr:>5}: {instruction}")?;
+                instruction.size()
+            }
+            None => {
+                let value = memory.get(addr).copied().unwrap_or(0);
+                writeln!(output, "{addr:>5}: DATA {value}")?;
+                1
+            }
+        };
+
+        let Some(next) = addr.checked_add(size) else {
+            break;
+        };
+        addr = next;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod repl_tests {
+    use super::run_repl;
+
+    fn session(program: Vec<i64>, commands: &str) -> String {
+        let mut output = Vec::new();
+        run_repl(program, commands.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn repl_step() {
+        // Doubles the input
+        let output = session(vec![3, 9, 1002, 9, 2, 9, 4, 9, 99, 0], "s\ni 21\ns\n\n\n");
+
+        assert_eq!(
+            "Type help for a list of commands\n\
+             \x20   0: IN [9]\n\
+             > waiting for input\n\
+             \x20   0: IN [9]\n\
+             > > \x20   2: MUL [9], 2, [9]\n\
+             > \x20   6: OUT [9]\n\
+             > \x20   8: HLT\n\
+             output: 42\n\
+             > ",
+            output
+        );
+    }
+
+    #[test]
+    fn repl_breakpoints() {
+        let program = vec![1101, 1, 2, 20, 1101, 3, 4, 21, 1101, 5, 6, 20, 99];
+        let output = session(program, "b 8\nw 20\nc\nc\nc\nregs\nm 19 4\nq\nc");
+
+        assert!(output.contains("breakpoint at 8 set\n"), "{output}");
+        assert!(output.contains("> watchpoint on 20 written\n    4: ADD 3, 4, [21]\n"));
+        assert!(output.contains("> breakpoint at 8\n    8: ADD 5, 6, [20]\n"));
+        assert!(output.contains("> watchpoint on 20 written\n   12: HLT\n"));
+        assert!(output.contains("ip=12 rel_base=0 breakpoints=[8] watchpoints=[20]\n"));
+        assert!(output.contains("   19: 0 11 7 0\n"));
+        assert!(
+            output.ends_with("> "),
+            "The last c should be ignored after quitting"
+        );
+    }
+
+    #[test]
+    fn repl_errors() {
+        let output = session(vec![99], "jump 5\nb\nm x\ns\ns\n");
+
+        assert!(output.contains("error: unknown command jump"), "{output}");
+        assert!(output.contains("error: expected ADDR\n"));
+        assert!(output.contains("error: invalid digit"));
+        assert!(output.contains("> halted\n    0: HLT\n> halted\n    0: HLT\n"));
+    }
+
+    #[test]
+    fn repl_negative_jump() {
+        let output = session(vec![1105, 1, -1], "s\n");
+
+        assert!(
+            output.contains("error: Invalid memory address -1 at ip 0, instruction 1105 (JNZ)"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn repl_dis_end_of_memory() {
+        let output = session(vec![99], "dis 18446744073709551614 5\n");
+
+        assert!(
+            output.ends_with(
+                "> 18446744073709551614: DATA 0\n\
+                 18446744073709551615: DATA 0\n> "
+            ),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn repl_mem_end_of_memory() {
+        let output = session(
+            vec![99],
+            "m 18446744073709551615 8
+m 18446744073709551613 8
+",
+        );
+
+        assert!(
+            output.ends_with(
+                "> > 18446744073709551613: 0 0
+> "
+            ),
+            "{output}"
+        );
+    }
+}
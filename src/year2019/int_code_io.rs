@@ -0,0 +1,374 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use super::int_code_computer::IntCodeComputer;
+
+// Where a computer's input comes from. None means there's nothing to read yet
+pub trait Input {
+    fn read(&mut self) -> Option<i64>;
+
+    // Whether the last value read was only a stand-in for there being nothing to read, like the -1
+    // a polling NIC is given. A computer gives up its turn once it's dealt with it
+    fn read_default(&self) -> bool {
+        false
+    }
+}
+
+// Where a computer's output goes
+pub trait Output {
+    fn write(&mut self, value: i64);
+}
+
+impl Input for VecDeque<i64> {
+    fn read(&mut self) -> Option<i64> {
+        self.pop_front()
+    }
+}
+
+impl Output for VecDeque<i64> {
+    fn write(&mut self, value: i64) {
+        self.push_back(value);
+    }
+}
+
+impl Output for Vec<i64> {
+    fn write(&mut self, value: i64) {
+        self.push(value);
+    }
+}
+
+// Input produced on demand, e.g. InputFn(|| joystick.pop()). A closure that never returns None
+// keeps the computer running until it halts
+pub struct InputFn<F>(pub F);
+
+impl<F: FnMut() -> Option<i64>> Input for InputFn<F> {
+    fn read(&mut self) -> Option<i64> {
+        (self.0)()
+    }
+}
+
+// Output handled as it's written, e.g. to route it between machines
+pub struct OutputFn<F>(pub F);
+
+impl<F: FnMut(i64)> Output for OutputFn<F> {
+    fn write(&mut self, value: i64) {
+        (self.0)(value)
+    }
+}
+
+// A queue shared between machines, with one writing to it and another reading from it. Clones
+// are handles to the same queue, and can be sent to other threads
+#[derive(Clone, Debug, Default)]
+pub struct Channel {
+    queue: Arc<Mutex<VecDeque<i64>>>,
+    // Read in place of nothing when the queue's empty, see `with_default`
+    default: Option<i64>,
+    defaulted: bool,
+}
+
+impl Channel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // A handle to the same queue that reads as `default` when it's empty, for a program that
+    // polls for input rather than waiting for it, like day 23's NICs reading -1
+    pub fn with_default(&self, default: i64) -> Self {
+        Self {
+            queue: Arc::clone(&self.queue),
+            default: Some(default),
+            defaulted: false,
+        }
+    }
+
+    pub fn push(&self, value: i64) {
+        self.queue().push_back(value);
+    }
+
+    pub fn pop(&self) -> Option<i64> {
+        self.queue().pop_front()
+    }
+
+    pub fn last(&self) -> Option<i64> {
+        self.queue().back().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue().is_empty()
+    }
+
+    // Pushing and popping can't leave the queue half changed, so it's still fine to use after a
+    // panic on another thread
+    fn queue(&self) -> MutexGuard<'_, VecDeque<i64>> {
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Input for Channel {
+    fn read(&mut self) -> Option<i64> {
+        let value = self.pop();
+        self.defaulted = value.is_none() && self.default.is_some();
+        value.or(self.default)
+    }
+
+    fn read_default(&self) -> bool {
+        self.defaulted
+    }
+}
+
+impl Output for Channel {
+    fn write(&mut self, value: i64) {
+        self.push(value);
+    }
+}
+
+struct Node {
+    icc: IntCodeComputer,
+    input: Box<dyn Input + Send>,
+    output: Box<dyn Output + Send>,
+}
+
+// Computers connected to each other, or anything else, through their inputs and outputs. They're
+// run one at a time, each until it halts, is waiting for input or has read a default. The inputs
+// and outputs must be `Send`, so a network can be moved to whichever thread solves the puzzle
+#[derive(Default)]
+pub struct Network {
+    nodes: Vec<Node>,
+}
+
+impl Network {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns the computer's index in the network
+    pub fn add<I, O>(&mut self, icc: IntCodeComputer, input: I, output: O) -> usize
+    where
+        I: Input + Send + 'static,
+        O: Output + Send + 'static,
+    {
+        self.nodes.push(Node {
+            icc,
+            input: Box::new(input),
+            output: Box::new(output),
+        });
+
+        self.nodes.len() - 1
+    }
+
+    pub fn computer(&self, index: usize) -> Option<&IntCodeComputer> {
+        self.nodes.get(index).map(|node| &node.icc)
+    }
+
+    // Runs the computers in turn until they've all halted, or are idle. A round is idle when none
+    // of them has read anything but defaults, or output anything
+    pub fn run(&mut self) -> color_eyre::Result<()> {
+        loop {
+            let mut progressed = false;
+
+            for node in self.nodes.iter_mut().filter(|node| !node.icc.has_halted()) {
+                // A computer that loops back to the input instruction it started on can still
+                // have got somewhere, so it's what goes in and out that counts rather than where
+                // it ends up
+                let mut input = Counted {
+                    inner: node.input.as_mut(),
+                    count: 0,
+                };
+                let mut output = Counted {
+                    inner: node.output.as_mut(),
+                    count: 0,
+                };
+                node.icc.run_io(&mut input, &mut output)?;
+
+                progressed |= node.icc.has_halted() || input.count > 0 || output.count > 0;
+            }
+
+            if !progressed {
+                return Ok(());
+            }
+        }
+    }
+}
+
+// Counts the values that go through it, other than defaults that are read
+struct Counted<T> {
+    inner: T,
+    count: usize,
+}
+
+impl<I: Input + ?Sized> Input for Counted<&mut I> {
+    fn read(&mut self) -> Option<i64> {
+        let value = self.inner.read();
+        self.count += usize::from(value.is_some() && !self.inner.read_default());
+        value
+    }
+
+    fn read_default(&self) -> bool {
+        self.inner.read_default()
+    }
+}
+
+impl<O: Output + ?Sized> Output for Counted<&mut O> {
+    fn write(&mut self, value: i64) {
+        self.count += 1;
+        self.inner.write(value);
+    }
+}
+
+#[cfg(test)]
+mod io_tests {
+    use std::collections::VecDeque;
+
+    use super::{Channel, Input, InputFn, Network, OutputFn};
+    use crate::year2019::{int_code_asm::assemble, int_code_computer::IntCodeComputer};
+
+    // Reads a value, outputs it doubled and goes back for the next, until it reads 0
+    const DOUBLER: [i64; 17] = [
+        3, 15, 1006, 15, 14, 1002, 15, 2, 16, 4, 16, 1105, 1, 0, 99, 0, 0,
+    ];
+
+    // A NIC that polls for input, going back to poll again when it reads -1. It passes on one more
+    // than each value it's sent, until it's sent 5, and keeps the last value it's sent at 53
+    const PASS_ON: &str = "\
+        IN [50]
+        EQ [50], -1, [51]
+        JNZ [51], 0
+        ADD [50], 0, [53]
+        LT [50], 5, [51]
+        JZ [51], 0
+        ADD [50], 1, [52]
+        OUT [52]
+        JNZ 1, 0";
+
+    #[test]
+    fn io_run_queues() {
+        let mut icc = IntCodeComputer::load(DOUBLER.to_vec());
+        let mut input = VecDeque::from([4, 0, 5]);
+        let mut output = Vec::new();
+
+        assert!(icc.run_io(&mut input, &mut output).is_ok());
+        assert_eq!(vec![8], output);
+        assert_eq!(VecDeque::from([5]), input);
+        assert!(icc.has_halted());
+    }
+
+    #[test]
+    fn io_run_waits_for_input() {
+        let mut icc = IntCodeComputer::load(DOUBLER.to_vec());
+        let mut input = VecDeque::from([1, 2]);
+        let mut output = Vec::new();
+
+        assert!(icc.run_io(&mut input, &mut output).is_ok());
+        assert_eq!(vec![2, 4], output);
+        assert!(!icc.has_halted());
+        assert!(icc.is_waiting_for_input());
+
+        input.push_back(0);
+        assert!(icc.run_io(&mut input, &mut output).is_ok());
+        assert!(icc.has_halted());
+    }
+
+    #[test]
+    fn io_run_closures() {
+        let mut icc = IntCodeComputer::load(DOUBLER.to_vec());
+        let mut next = 3;
+        let mut total = 0;
+
+        let input = &mut InputFn(|| {
+            next -= 1;
+            Some(next)
+        });
+        let output = &mut OutputFn(|value| total += value);
+        assert!(icc.run_io(input, output).is_ok());
+
+        assert!(icc.has_halted());
+        assert_eq!(6, total);
+    }
+
+    #[test]
+    fn io_channel_default() {
+        let channel = Channel::new();
+        let mut polling = channel.with_default(-1);
+        channel.push(7);
+
+        assert_eq!(Some(7), polling.read());
+        assert!(!polling.read_default());
+        assert_eq!(Some(-1), polling.read());
+        assert!(polling.read_default());
+        assert_eq!(None, channel.clone().read());
+    }
+
+    #[test]
+    fn io_run_gives_up_turn_on_default() {
+        let mut icc = IntCodeComputer::load(assemble(PASS_ON).unwrap());
+        let channel = Channel::new();
+        let mut output = Vec::new();
+
+        channel.push(1);
+        assert!(icc
+            .run_io(&mut channel.with_default(-1), &mut output)
+            .is_ok());
+        assert_eq!(vec![2], output);
+        assert!(icc.is_waiting_for_input());
+    }
+
+    #[test]
+    fn io_network_polling() {
+        let to_first = Channel::new();
+        let to_second = Channel::new();
+        let mut network = Network::new();
+        for (input, output) in [(&to_first, &to_second), (&to_second, &to_first)] {
+            network.add(
+                IntCodeComputer::load(assemble(PASS_ON).unwrap()),
+                input.with_default(-1),
+                output.clone(),
+            );
+        }
+
+        to_first.push(0);
+        assert!(network.run().is_ok());
+        assert_eq!(4, network.computer(0).unwrap().read(53));
+        assert_eq!(5, network.computer(1).unwrap().read(53));
+        assert!(to_first.is_empty() && to_second.is_empty());
+        assert!(!network.computer(0).unwrap().has_halted());
+    }
+
+    // Solvers are run on a pool of threads
+    #[test]
+    fn io_network_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Channel>();
+        assert_send::<Network>();
+    }
+
+    #[test]
+    fn io_network_chain() {
+        // Each computer doubles what the one before it outputs
+        let channels = (0..4).map(|_| Channel::new()).collect::<Vec<_>>();
+        let mut network = Network::new();
+        for pair in channels.windows(2) {
+            network.add(
+                IntCodeComputer::load(DOUBLER.to_vec()),
+                pair[0].clone(),
+                pair[1].clone(),
+            );
+        }
+
+        channels[0].push(5);
+        assert!(network.run().is_ok());
+        assert_eq!(Some(40), channels[3].pop());
+        assert!(!network.computer(0).unwrap().has_halted());
+
+        channels[0].push(0);
+        assert!(network.run().is_ok());
+        assert!(network.computer(0).unwrap().has_halted());
+        assert!(!network.computer(1).unwrap().has_halted());
+        assert!(channels[3].is_empty());
+    }
+}
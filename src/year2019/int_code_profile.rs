@@ -0,0 +1,295 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use color_eyre::eyre::eyre;
+use itertools::Itertools;
+
+use super::{
+    int_code_asm::Instruction,
+    int_code_computer::IntCodeComputer,
+    op_code::{Mode, OpCode},
+};
+
+// Counts of what a program spent its time doing, optionally with a trace of every instruction it
+// ran written to a file
+#[derive(Debug, Default)]
+pub struct Profile {
+    steps: u64,
+    addresses: BTreeMap<usize, u64>,
+    ops: BTreeMap<&'static str, u64>,
+    // Jumps that were taken, keyed by where they jumped from and to
+    jumps: BTreeMap<(usize, usize), u64>,
+    trace: Option<BufWriter<File>>,
+}
+
+// A loop found from a jump back to an earlier address, covering everything from the jump's target
+// to the jump itself
+#[derive(Debug, Eq, PartialEq)]
+pub struct Loop {
+    pub start: usize,
+    pub end: usize,
+    // How many times the jump back was taken
+    pub iterations: u64,
+    // Instructions run at the addresses the loop covers, whether or not it was looping at the time
+    pub steps: u64,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_trace(path: &Path) -> color_eyre::Result<Self> {
+        let file = File::create(path)
+            .map_err(|err| eyre!("Failed to create {}: {err}", path.display()))?;
+
+        Ok(Self {
+            trace: Some(BufWriter::new(file)),
+            ..Self::default()
+        })
+    }
+
+    // How many instructions have been run
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    // How many times the instruction at the address has been run
+    pub fn address_count(&self, addr: usize) -> u64 {
+        self.addresses.get(&addr).copied().unwrap_or(0)
+    }
+
+    // How many times instructions with the mnemonic, e.g. "ADD", have been run
+    pub fn op_count(&self, mnemonic: &str) -> u64 {
+        self.ops.get(mnemonic).copied().unwrap_or(0)
+    }
+
+    pub fn jumps(&self) -> &BTreeMap<(usize, usize), u64> {
+        &self.jumps
+    }
+
+    // Loops ordered by how many instructions were run in them, most first
+    pub fn hot_loops(&self) -> Vec<Loop> {
+        self.jumps
+            .iter()
+            .filter(|((from, to), _)| to <= from)
+            .map(|(&(end, start), &iterations)| Loop {
+                start,
+                end,
+                iterations,
+                steps: self
+                    .addresses
+                    .range(start..=end)
+                    .map(|(_, count)| count)
+                    .sum(),
+            })
+            .sorted_by(|a, b| b.steps.cmp(&a.steps).then(a.start.cmp(&b.start)))
+            .collect()
+    }
+
+    // A summary of the instructions run, and the `top` busiest addresses and loops
+    pub fn report(&self, top: usize) -> String {
+        let mut report = String::new();
+        writeln!(report, "{} instructions run", self.steps).unwrap();
+
+        let ops = self
+            .ops
+            .iter()
+            .sorted_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)))
+            .map(|(mnemonic, count)| format!("{mnemonic} {count}"));
+        writeln!(report, "ops: {}", ops.format(", ")).unwrap();
+
+        writeln!(report, "hottest addresses:").unwrap();
+        for (addr, count) in self
+            .addresses
+            .iter()
+            .sorted_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)))
+            .take(top)
+        {
+            writeln!(report, "{addr:>5}: {count}").unwrap();
+        }
+
+        writeln!(report, "hottest loops:").unwrap();
+        for hot in self.hot_loops().into_iter().take(top) {
+            writeln!(
+                report,
+                "{:>5}..={}: {} instructions over {} iterations",
+                hot.start, hot.end, hot.steps, hot.iterations
+            )
+            .unwrap();
+        }
+
+        report
+    }
+
+    // Counts the step now it's been run, and adds it to the trace. The trace is abandoned if it
+    // can't be written to
+    pub(super) fn record(&mut self, step: Step, icc: &IntCodeComputer) {
+        self.steps += 1;
+        *self.addresses.entry(step.ip).or_default() += 1;
+        *self.ops.entry(step.instruction.op.mnemonic()).or_default() += 1;
+
+        let next = step.ip + step.instruction.size();
+        let result = match step.instruction.op {
+            OpCode::JNZ(_, _) | OpCode::JZ(_, _) if icc.ip() != next => {
+                *self.jumps.entry((step.ip, icc.ip())).or_default() += 1;
+                format!("jmp {}", icc.ip())
+            }
+            OpCode::JNZ(_, _) | OpCode::JZ(_, _) => String::from("-"),
+            OpCode::Out(_) => format!("out {}", step.operands[0]),
+            OpCode::RBO(_) => format!("rb={}", icc.rel_base()),
+            OpCode::End => String::from("halt"),
+            _ => {
+                let addr = step.operands[step.operands.len() - 1];
+                let value = usize::try_from(addr).map_or(0, |addr| icc.read(addr));
+                format!("[{addr}]={value}")
+            }
+        };
+
+        if let Some(trace) = &mut self.trace {
+            let written = writeln!(
+                trace,
+                "{:>5}: {} ({}) -> {result}",
+                step.ip,
+                step.instruction,
+                step.operands.iter().join(", ")
+            );
+            if let Err(err) = written {
+                log::warn!("Stopped tracing, failed to write to the trace: {err}");
+                self.trace = None;
+            }
+        }
+    }
+}
+
+// An instruction as it was about to be run, with the values it read and the address it's writing
+// to in place of its parameters
+pub(super) struct Step {
+    ip: usize,
+    instruction: Instruction,
+    operands: Vec<i64>,
+}
+
+impl Step {
+    // None if there isn't a valid instruction at the instruction pointer
+    pub(super) fn start(icc: &IntCodeComputer) -> Option<Self> {
+        let ip = icc.ip();
+        let op = OpCode::try_from(icc.read(ip)).ok()?;
+        let params = (ip + 1..ip + 1 + op.num_params())
+            .map(|addr| icc.read(addr))
+            .collect_vec();
+
+        let writes = matches!(
+            op,
+            OpCode::Add(..) | OpCode::Mul(..) | OpCode::In(_) | OpCode::LT(..) | OpCode::EQ(..)
+        );
+        let read = |addr: i64| usize::try_from(addr).map_or(0, |addr| icc.read(addr));
+        let operands = op
+            .modes()
+            .into_iter()
+            .zip(&params)
+            .enumerate()
+            .map(|(index, (mode, &param))| {
+                let addr = match mode {
+                    Mode::Imm => return param,
+                    Mode::Pos => param,
+                    Mode::Rel => icc.rel_base() as i64 + param,
+                };
+                if writes && index + 1 == params.len() {
+                    addr
+                } else {
+                    read(addr)
+                }
+            })
+            .collect();
+
+        Some(Self {
+            ip,
+            instruction: Instruction { op, params },
+            operands,
+        })
+    }
+}
+
+#[cfg(test)]
+mod profile_tests {
+    use std::fs;
+
+    use super::{Loop, Profile};
+    use crate::year2019::int_code_computer::IntCodeComputer;
+
+    // Counts down from the input, outputting each value, until it reaches 0
+    const COUNTDOWN: [i64; 13] = [3, 12, 4, 12, 1001, 12, -1, 12, 1005, 12, 2, 99, 0];
+
+    #[test]
+    fn profile_counts() {
+        let mut icc = IntCodeComputer::load(COUNTDOWN.to_vec());
+        icc.enable_profiling(Profile::new());
+        icc.push_input(3);
+        assert!(icc.run().is_ok());
+
+        let profile = icc.profile().unwrap();
+        assert_eq!(11, profile.steps());
+        assert_eq!(3, profile.address_count(2));
+        assert_eq!(1, profile.address_count(11));
+        assert_eq!(3, profile.op_count("OUT"));
+        assert_eq!(3, profile.op_count("JNZ"));
+        assert_eq!(0, profile.op_count("MUL"));
+        assert_eq!(Some(&2), profile.jumps().get(&(8, 2)));
+        assert_eq!(
+            vec![Loop {
+                start: 2,
+                end: 8,
+                iterations: 2,
+                steps: 9
+            }],
+            profile.hot_loops()
+        );
+    }
+
+    #[test]
+    fn profile_report() {
+        let mut icc = IntCodeComputer::load(COUNTDOWN.to_vec());
+        icc.enable_profiling(Profile::new());
+        icc.push_input(3);
+        assert!(icc.run().is_ok());
+
+        assert_eq!(
+            "11 instructions run\n\
+             ops: ADD 3, JNZ 3, OUT 3, HLT 1, IN 1\n\
+             hottest addresses:\n\
+             \x20   2: 3\n\
+             \x20   4: 3\n\
+             hottest loops:\n\
+             \x20   2..=8: 9 instructions over 2 iterations\n",
+            icc.profile().unwrap().report(2)
+        );
+    }
+
+    #[test]
+    fn profile_trace() {
+        let path = std::env::temp_dir().join("aoc_intcode_trace.txt");
+        let mut icc = IntCodeComputer::load(COUNTDOWN.to_vec());
+        icc.enable_profiling(Profile::with_trace(&path).unwrap());
+        icc.push_input(2);
+        assert!(icc.run().is_ok());
+        drop(icc);
+
+        assert_eq!(
+            "    0: IN [12] (12) -> [12]=2\n\
+             \x20   2: OUT [12] (2) -> out 2\n\
+             \x20   4: ADD [12], -1, [12] (2, -1, 12) -> [12]=1\n\
+             \x20   8: JNZ [12], 2 (1, 2) -> jmp 2\n\
+             \x20   2: OUT [12] (1) -> out 1\n\
+             \x20   4: ADD [12], -1, [12] (1, -1, 12) -> [12]=0\n\
+             \x20   8: JNZ [12], 2 (0, 2) -> -\n\
+             \x20  11: HLT () -> halt\n",
+            fs::read_to_string(&path).unwrap()
+        );
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::error::AdventError;
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum OpCode {
     Add(Mode, Mode, Mode),
     Mul(Mode, Mode, Mode),
@@ -31,6 +31,78 @@ impl OpCode {
             OpCode::End => 0,
         }
     }
+
+    pub fn modes(&self) -> Vec<Mode> {
+        match *self {
+            OpCode::Add(mode1, mode2, mode3)
+            | OpCode::Mul(mode1, mode2, mode3)
+            | OpCode::LT(mode1, mode2, mode3)
+            | OpCode::EQ(mode1, mode2, mode3) => vec![mode1, mode2, mode3],
+            OpCode::JNZ(mode1, mode2) | OpCode::JZ(mode1, mode2) => vec![mode1, mode2],
+            OpCode::In(mode) | OpCode::Out(mode) | OpCode::RBO(mode) => vec![mode],
+            OpCode::End => vec![],
+        }
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            OpCode::Add(_, _, _) => "ADD",
+            OpCode::Mul(_, _, _) => "MUL",
+            OpCode::In(_) => "IN",
+            OpCode::Out(_) => "OUT",
+            OpCode::JNZ(_, _) => "JNZ",
+            OpCode::JZ(_, _) => "JZ",
+            OpCode::LT(_, _, _) => "LT",
+            OpCode::EQ(_, _, _) => "EQ",
+            OpCode::RBO(_) => "RBO",
+            OpCode::End => "HLT",
+        }
+    }
+
+    // The reverse of `mnemonic`, with the parameters' modes. None if the mnemonic isn't known or
+    // it's given the wrong number of modes
+    pub fn from_mnemonic(mnemonic: &str, modes: &[Mode]) -> Option<Self> {
+        let op = match (mnemonic, modes) {
+            ("ADD", &[mode1, mode2, mode3]) => OpCode::Add(mode1, mode2, mode3),
+            ("MUL", &[mode1, mode2, mode3]) => OpCode::Mul(mode1, mode2, mode3),
+            ("IN", &[mode]) => OpCode::In(mode),
+            ("OUT", &[mode]) => OpCode::Out(mode),
+            ("JNZ", &[mode1, mode2]) => OpCode::JNZ(mode1, mode2),
+            ("JZ", &[mode1, mode2]) => OpCode::JZ(mode1, mode2),
+            ("LT", &[mode1, mode2, mode3]) => OpCode::LT(mode1, mode2, mode3),
+            ("EQ", &[mode1, mode2, mode3]) => OpCode::EQ(mode1, mode2, mode3),
+            ("RBO", &[mode]) => OpCode::RBO(mode),
+            ("HLT", &[]) => OpCode::End,
+            _ => return None,
+        };
+
+        Some(op)
+    }
+}
+
+// Encodes the opcode and its parameter modes back into the value that's stored in memory
+impl From<&OpCode> for i64 {
+    fn from(op: &OpCode) -> Self {
+        let code = match op {
+            OpCode::Add(_, _, _) => 1,
+            OpCode::Mul(_, _, _) => 2,
+            OpCode::In(_) => 3,
+            OpCode::Out(_) => 4,
+            OpCode::JNZ(_, _) => 5,
+            OpCode::JZ(_, _) => 6,
+            OpCode::LT(_, _, _) => 7,
+            OpCode::EQ(_, _, _) => 8,
+            OpCode::RBO(_) => 9,
+            OpCode::End => 99,
+        };
+
+        op.modes()
+            .iter()
+            .rev()
+            .fold(0, |modes, mode| modes * 10 + i64::from(*mode))
+            * 100
+            + code
+    }
 }
 
 impl TryFrom<i64> for OpCode {
@@ -118,7 +190,7 @@ impl TryFrom<i64> for OpCode {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Mode {
     Pos,
     Imm,
@@ -138,6 +210,16 @@ impl TryFrom<i64> for Mode {
     }
 }
 
+impl From<Mode> for i64 {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Pos => 0,
+            Mode::Imm => 1,
+            Mode::Rel => 2,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{Mode, OpCode};
@@ -267,4 +349,27 @@ mod tests {
             result.unwrap()
         );
     }
+
+    #[test]
+    fn opcode_encode() {
+        assert_eq!(1, i64::from(&OpCode::Add(Mode::Pos, Mode::Pos, Mode::Pos)));
+        assert_eq!(
+            21001,
+            i64::from(&OpCode::Add(Mode::Pos, Mode::Imm, Mode::Rel))
+        );
+        assert_eq!(1105, i64::from(&OpCode::JNZ(Mode::Imm, Mode::Imm)));
+        assert_eq!(204, i64::from(&OpCode::Out(Mode::Rel)));
+        assert_eq!(99, i64::from(&OpCode::End));
+    }
+
+    #[test]
+    fn opcode_from_mnemonic() {
+        assert_eq!(
+            Some(OpCode::LT(Mode::Rel, Mode::Imm, Mode::Pos)),
+            OpCode::from_mnemonic("LT", &[Mode::Rel, Mode::Imm, Mode::Pos])
+        );
+        assert_eq!(Some(OpCode::End), OpCode::from_mnemonic("HLT", &[]));
+        assert_eq!(None, OpCode::from_mnemonic("LT", &[Mode::Pos]));
+        assert_eq!(None, OpCode::from_mnemonic("NOP", &[]));
+    }
 }
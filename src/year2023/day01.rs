@@ -1,76 +1,86 @@
 use std::collections::HashMap;
 
+use crate::solver::Solver;
+
 type ParsedInput<'a> = Vec<&'a str>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput<'_>> {
-    Ok(input.lines().collect())
-}
+pub struct Solution;
 
-pub fn part1(input: &ParsedInput) -> color_eyre::Result<u32> {
-    // On each line, the calibration value can be found by combining
-    // the first digit and the last digit (in that order) to form a single two-digit number.
-    // What is the sum of all of the calibration values?
-
-    let mut sum = 0;
-
-    for line in input {
-        let first = line
-            .chars()
-            .find(|ch| ch.is_ascii_digit())
-            .expect("Failed to find first digit")
-            .to_digit(10)
-            .unwrap();
-        let last = line
-            .chars()
-            .rfind(|ch| ch.is_ascii_digit())
-            .expect("Failed to find last digit")
-            .to_digit(10)
-            .unwrap();
-
-        sum += 10 * first + last;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput<'a>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput<'_>> {
+        Ok(input.lines().collect())
     }
 
-    Ok(sum)
-}
+    fn part1(input: &ParsedInput) -> color_eyre::Result<u32> {
+        // On each line, the calibration value can be found by combining
+        // the first digit and the last digit (in that order) to form a single two-digit number.
+        // What is the sum of all of the calibration values?
+
+        let mut sum = 0;
+
+        for line in input {
+            let first = line
+                .chars()
+                .find(|ch| ch.is_ascii_digit())
+                .expect("Failed to find first digit")
+                .to_digit(10)
+                .unwrap();
+            let last = line
+                .chars()
+                .rfind(|ch| ch.is_ascii_digit())
+                .expect("Failed to find last digit")
+                .to_digit(10)
+                .unwrap();
+
+            sum += 10 * first + last;
+        }
 
-pub fn part2(input: &ParsedInput) -> color_eyre::Result<u32> {
-    // It looks like some of the digits are actually spelled out with letters:
-    // one, two, three, four, five, six, seven, eight, and nine also count as valid "digits".
-
-    let numbers = vec![
-        "0".to_string(),
-        "1".to_string(),
-        "2".to_string(),
-        "3".to_string(),
-        "4".to_string(),
-        "5".to_string(),
-        "6".to_string(),
-        "7".to_string(),
-        "8".to_string(),
-        "9".to_string(),
-        "one".to_string(),
-        "two".to_string(),
-        "three".to_string(),
-        "four".to_string(),
-        "five".to_string(),
-        "six".to_string(),
-        "seven".to_string(),
-        "eight".to_string(),
-        "nine".to_string(),
-    ];
-
-    let mut sum = 0;
-
-    for line in input {
-        let first = find_first_of(line, &numbers)
-            .unwrap_or_else(|| panic!("Failed to find first number in \"{line}\""));
-        let last = find_last_of(line, &numbers)
-            .unwrap_or_else(|| panic!("Failed to find last number in \"{line}\""));
-
-        sum += 10 * to_digit(&first) + to_digit(&last);
+        Ok(sum)
     }
 
-    Ok(sum)
+    fn part2(input: &ParsedInput) -> color_eyre::Result<u32> {
+        // It looks like some of the digits are actually spelled out with letters:
+        // one, two, three, four, five, six, seven, eight, and nine also count as valid "digits".
+
+        let numbers = vec![
+            "0".to_string(),
+            "1".to_string(),
+            "2".to_string(),
+            "3".to_string(),
+            "4".to_string(),
+            "5".to_string(),
+            "6".to_string(),
+            "7".to_string(),
+            "8".to_string(),
+            "9".to_string(),
+            "one".to_string(),
+            "two".to_string(),
+            "three".to_string(),
+            "four".to_string(),
+            "five".to_string(),
+            "six".to_string(),
+            "seven".to_string(),
+            "eight".to_string(),
+            "nine".to_string(),
+        ];
+
+        let mut sum = 0;
+
+        for line in input {
+            let first = find_first_of(line, &numbers)
+                .unwrap_or_else(|| panic!("Failed to find first number in \"{line}\""));
+            let last = find_last_of(line, &numbers)
+                .unwrap_or_else(|| panic!("Failed to find last number in \"{line}\""));
+
+            sum += 10 * to_digit(&first) + to_digit(&last);
+        }
+
+        Ok(sum)
+    }
 }
 
 fn to_digit(s: &str) -> u32 {
@@ -1,96 +1,104 @@
 use std::str::FromStr;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Vec<Game>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    // E.g.: Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-    let mut games: Vec<Game> = Vec::new();
+pub struct Solution;
 
-    for line in input.lines() {
-        let (game, draws) = line
-            .split_once(':')
-            .ok_or(AdventError::SplitOnce(line.into(), ':'.into()))?;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-        let (_, id) = game
-            .split_once(' ')
-            .ok_or(AdventError::SplitOnce(game.into(), ' '.into()))?;
-        let mut game = Game::new(id.to_string().parse()?);
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        // E.g.: Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        let mut games: Vec<Game> = Vec::new();
+
+        for line in input.lines() {
+            let (game, draws) = line
+                .split_once(':')
+                .ok_or(AdventError::SplitOnce(line.into(), ':'.into()))?;
+
+            let (_, id) = game
+                .split_once(' ')
+                .ok_or(AdventError::SplitOnce(game.into(), ' '.into()))?;
+            let mut game = Game::new(id.to_string().parse()?);
+
+            let info = draws.trim();
+            for draw_s in info.split(';') {
+                let draw_s = draw_s.trim();
+                let mut draw = Draw::new();
 
-        let info = draws.trim();
-        for draw_s in info.split(';') {
-            let draw_s = draw_s.trim();
-            let mut draw = Draw::new();
+                for colour in draw_s.split(',') {
+                    let colour = colour.trim();
+                    draw.colours.push(Colour::from_str(colour)?);
+                }
 
-            for colour in draw_s.split(',') {
-                let colour = colour.trim();
-                draw.colours.push(Colour::from_str(colour)?);
+                game.draws.push(draw);
             }
 
-            game.draws.push(draw);
+            games.push(game);
         }
 
-        games.push(game);
+        Ok(games)
     }
 
-    Ok(games)
-}
-
-pub fn part1(games: &ParsedInput) -> color_eyre::Result<u32> {
-    // once a bag has been loaded with cubes, the Elf will reach into the bag,
-    // grab a handful of random cubes, show them to you, and then put them back
-    // in the bag. He'll do this a few times per game.
-    // The Elf would first like to know which games would have been possible if the bag
-    // contained only 12 red cubes, 13 green cubes, and 14 blue cubes?
-
-    let red_limit = 12;
-    let green_limit = 13;
-    let blue_limit = 14;
-
-    let mut games = games.clone();
-    games.retain(|game| {
-        game.draws.iter().all(|draw| {
-            draw.colours.iter().all(|colour| match colour {
-                Colour::Red(num) => *num <= red_limit,
-                Colour::Green(num) => *num <= green_limit,
-                Colour::Blue(num) => *num <= blue_limit,
+    fn part1(games: &ParsedInput) -> color_eyre::Result<u32> {
+        // once a bag has been loaded with cubes, the Elf will reach into the bag,
+        // grab a handful of random cubes, show them to you, and then put them back
+        // in the bag. He'll do this a few times per game.
+        // The Elf would first like to know which games would have been possible if the bag
+        // contained only 12 red cubes, 13 green cubes, and 14 blue cubes?
+
+        let red_limit = 12;
+        let green_limit = 13;
+        let blue_limit = 14;
+
+        let mut games = games.clone();
+        games.retain(|game| {
+            game.draws.iter().all(|draw| {
+                draw.colours.iter().all(|colour| match colour {
+                    Colour::Red(num) => *num <= red_limit,
+                    Colour::Green(num) => *num <= green_limit,
+                    Colour::Blue(num) => *num <= blue_limit,
+                })
             })
-        })
-    });
+        });
 
-    Ok(games.iter().map(|game| game.id).sum())
-}
+        Ok(games.iter().map(|game| game.id).sum())
+    }
 
-pub fn part2(games: &ParsedInput) -> color_eyre::Result<u32> {
-    // what is the fewest number of cubes of each color that could have
-    // been in the bag to make the game possible?
-    // The power of a set of cubes is equal to the numbers of red, green,
-    // and blue cubes multiplied together.
-    // What is the sum of the power of these sets?
-    use Colour::*;
-
-    let sum: u32 = games
-        .iter()
-        .map(|game| {
-            let (r, g, b) =
-                game.draws
-                    .iter()
-                    .fold((0, 0, 0), |(mut max_r, mut max_g, mut max_b), draw| {
-                        draw.colours.iter().for_each(|colour| match colour {
-                            Red(draw_r) => max_r = std::cmp::max(max_r, *draw_r),
-                            Green(draw_g) => max_g = std::cmp::max(max_g, *draw_g),
-                            Blue(draw_b) => max_b = std::cmp::max(max_b, *draw_b),
-                        });
+    fn part2(games: &ParsedInput) -> color_eyre::Result<u32> {
+        // what is the fewest number of cubes of each color that could have
+        // been in the bag to make the game possible?
+        // The power of a set of cubes is equal to the numbers of red, green,
+        // and blue cubes multiplied together.
+        // What is the sum of the power of these sets?
+        use Colour::*;
 
-                        (max_r, max_g, max_b)
-                    });
+        let sum: u32 = games
+            .iter()
+            .map(|game| {
+                let (r, g, b) =
+                    game.draws
+                        .iter()
+                        .fold((0, 0, 0), |(mut max_r, mut max_g, mut max_b), draw| {
+                            draw.colours.iter().for_each(|colour| match colour {
+                                Red(draw_r) => max_r = std::cmp::max(max_r, *draw_r),
+                                Green(draw_g) => max_g = std::cmp::max(max_g, *draw_g),
+                                Blue(draw_b) => max_b = std::cmp::max(max_b, *draw_b),
+                            });
+
+                            (max_r, max_g, max_b)
+                        });
 
-            r * g * b // power is max of each set of cubes
-        })
-        .sum();
+                r * g * b // power is max of each set of cubes
+            })
+            .sum();
 
-    Ok(sum)
+        Ok(sum)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
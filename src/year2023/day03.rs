@@ -1,61 +1,69 @@
 use std::collections::HashSet;
 
-use crate::util::grid::Grid;
+use crate::{solver::Solver, util::grid::Grid};
 
 type ParsedInput = Grid<char>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input.try_into()?)
-}
+pub struct Solution;
 
-pub fn part1(grid: &ParsedInput) -> color_eyre::Result<u32> {
-    // any number adjacent to a symbol, even diagonally, is a "part number"
-    // add up all the part numbers in the engine schematic
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-    let mut h = HashSet::new();
-    let mut first_visit = |(num, points): (u32, Vec<(usize, usize)>)| {
-        if points.iter().all(|&point| h.insert(point)) {
-            return Some(num);
-        }
-        None
-    };
-
-    let sum: u32 = grid
-        .indexed_iter()
-        .filter(|((_, _), &value)| !value.is_ascii_digit() && value != '.')
-        .map(|((row, col), _)| adjacent_digit_points(grid, row, col))
-        .flat_map(reduce_adjacent_digit_points)
-        .map(|(row, col)| build_number_at_point(grid, row, col))
-        .filter_map(|(num, points)| first_visit((num, points)))
-        .sum();
-
-    Ok(sum)
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input.try_into()?)
+    }
 
-pub fn part2(grid: &ParsedInput) -> color_eyre::Result<u32> {
-    let sum = grid
-        .indexed_iter()
-        // Filter to '*' locations - gears
-        .filter(|((_, _), &value)| value == '*')
-        // for each gear, map to the adjacent points which are digits
-        .map(|((row, col), _)| adjacent_digit_points(grid, row, col))
-        // for each set of adjacent digit points, remove the ones which are in the same number
-        .map(reduce_adjacent_digit_points)
-        // for each set of adjacent points, map to the full number
-        .map(|points| {
-            points
-                .iter()
-                .map(|(row, col)| build_number_at_point(grid, *row, *col).0)
-                .collect::<Vec<_>>()
-        })
-        // for each set of adjacent numbers, filter to the sets with just 2 (gears only have 2 parts)
-        .filter(|gear_parts| gear_parts.len() == 2)
-        // for each set of adjacent numbers, multiply them
-        .map(|gear_parts| gear_parts.iter().product::<u32>())
-        // Sum all the products
-        .sum();
-
-    Ok(sum)
+    fn part1(grid: &ParsedInput) -> color_eyre::Result<u32> {
+        // any number adjacent to a symbol, even diagonally, is a "part number"
+        // add up all the part numbers in the engine schematic
+
+        let mut h = HashSet::new();
+        let mut first_visit = |(num, points): (u32, Vec<(usize, usize)>)| {
+            if points.iter().all(|&point| h.insert(point)) {
+                return Some(num);
+            }
+            None
+        };
+
+        let sum: u32 = grid
+            .indexed_iter()
+            .filter(|((_, _), &value)| !value.is_ascii_digit() && value != '.')
+            .map(|((row, col), _)| adjacent_digit_points(grid, row, col))
+            .flat_map(reduce_adjacent_digit_points)
+            .map(|(row, col)| build_number_at_point(grid, row, col))
+            .filter_map(|(num, points)| first_visit((num, points)))
+            .sum();
+
+        Ok(sum)
+    }
+
+    fn part2(grid: &ParsedInput) -> color_eyre::Result<u32> {
+        let sum = grid
+            .indexed_iter()
+            // Filter to '*' locations - gears
+            .filter(|((_, _), &value)| value == '*')
+            // for each gear, map to the adjacent points which are digits
+            .map(|((row, col), _)| adjacent_digit_points(grid, row, col))
+            // for each set of adjacent digit points, remove the ones which are in the same number
+            .map(reduce_adjacent_digit_points)
+            // for each set of adjacent points, map to the full number
+            .map(|points| {
+                points
+                    .iter()
+                    .map(|(row, col)| build_number_at_point(grid, *row, *col).0)
+                    .collect::<Vec<_>>()
+            })
+            // for each set of adjacent numbers, filter to the sets with just 2 (gears only have 2 parts)
+            .filter(|gear_parts| gear_parts.len() == 2)
+            // for each set of adjacent numbers, multiply them
+            .map(|gear_parts| gear_parts.iter().product::<u32>())
+            // Sum all the products
+            .sum();
+
+        Ok(sum)
+    }
 }
 
 fn adjacent_digit_points(grid: &Grid<char>, row: usize, col: usize) -> Vec<(usize, usize)> {
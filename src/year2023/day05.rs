@@ -1,85 +1,93 @@
 use itertools::Itertools;
 use std::{num::ParseIntError, ops::Range, str::FromStr};
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Almanac;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let input = input.replace("\r\n", "\n");
-
-    let (seeds, maps) = input
-        .split_once("\n\n")
-        .ok_or(AdventError::SplitOnce(input.to_string(), "\n\n".into()))?;
-
-    // seeds: 79 14 55 13
-    let (_, seeds) = seeds
-        .split_once(':')
-        .ok_or(AdventError::SplitOnce(seeds.into(), ':'.into()))?;
-
-    let seeds_pt1 = seeds
-        .split_ascii_whitespace()
-        .map(|s| s.trim().parse::<u64>())
-        .collect::<Result<Vec<_>, ParseIntError>>()?;
-
-    let seeds_pt2 = seeds
-        .split_ascii_whitespace()
-        .tuples()
-        .map(|(start, len)| Ok((start.trim().parse::<u64>()?, len.trim().parse::<u64>()?)))
-        .collect::<Result<Vec<_>, ParseIntError>>()?;
-
-    let seeds_pt2 = seeds_pt2
-        .iter()
-        .map(|(start, len)| *start..(*start + *len))
-        .collect::<Vec<_>>();
-
-    let maps = maps
-        .split("\n\n")
-        .map(Map::from_str)
-        .collect::<Result<Vec<_>, AdventError>>()?;
-
-    Ok(Almanac {
-        seeds_pt1,
-        seeds_pt2,
-        maps,
-    })
-}
+pub struct Solution;
 
-pub fn part1(almanac: &ParsedInput) -> color_eyre::Result<u64> {
-    // What is the lowest location number that corresponds to any of the initial seed numberss
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    let mut lowest_location = None;
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let input = input.replace("\r\n", "\n");
 
-    for seed in &almanac.seeds_pt1 {
-        let mut seed = *seed;
-        for map in &almanac.maps {
-            seed = map.map_value(seed);
-        }
+        let (seeds, maps) = input
+            .split_once("\n\n")
+            .ok_or(AdventError::SplitOnce(input.to_string(), "\n\n".into()))?;
 
-        if lowest_location.is_none() {
-            lowest_location = Some(seed);
-        } else if let Some(cur_lowest) = lowest_location {
-            lowest_location = Some(std::cmp::min(cur_lowest, seed));
-        }
+        // seeds: 79 14 55 13
+        let (_, seeds) = seeds
+            .split_once(':')
+            .ok_or(AdventError::SplitOnce(seeds.into(), ':'.into()))?;
+
+        let seeds_pt1 = seeds
+            .split_ascii_whitespace()
+            .map(|s| s.trim().parse::<u64>())
+            .collect::<Result<Vec<_>, ParseIntError>>()?;
+
+        let seeds_pt2 = seeds
+            .split_ascii_whitespace()
+            .tuples()
+            .map(|(start, len)| Ok((start.trim().parse::<u64>()?, len.trim().parse::<u64>()?)))
+            .collect::<Result<Vec<_>, ParseIntError>>()?;
+
+        let seeds_pt2 = seeds_pt2
+            .iter()
+            .map(|(start, len)| *start..(*start + *len))
+            .collect::<Vec<_>>();
+
+        let maps = maps
+            .split("\n\n")
+            .map(Map::from_str)
+            .collect::<Result<Vec<_>, AdventError>>()?;
+
+        Ok(Almanac {
+            seeds_pt1,
+            seeds_pt2,
+            maps,
+        })
     }
 
-    Ok(lowest_location.unwrap())
-}
+    fn part1(almanac: &ParsedInput) -> color_eyre::Result<u64> {
+        // What is the lowest location number that corresponds to any of the initial seed numberss
 
-pub fn part2(almanac: &ParsedInput) -> color_eyre::Result<u64> {
-    // seeds: line actually describes ranges of seed numbers.
-    // What is the lowest location number that corresponds to any of the initial seed numberss
+        let mut lowest_location = None;
 
-    let mut seed_ranges = almanac.seeds_pt2.clone();
+        for seed in &almanac.seeds_pt1 {
+            let mut seed = *seed;
+            for map in &almanac.maps {
+                seed = map.map_value(seed);
+            }
 
-    for map in &almanac.maps {
-        seed_ranges = seed_ranges
-            .iter()
-            .flat_map(|range| map.map_range(range))
-            .collect();
+            if lowest_location.is_none() {
+                lowest_location = Some(seed);
+            } else if let Some(cur_lowest) = lowest_location {
+                lowest_location = Some(std::cmp::min(cur_lowest, seed));
+            }
+        }
+
+        Ok(lowest_location.unwrap())
     }
 
-    Ok(seed_ranges.iter().map(|range| range.start).min().unwrap())
+    fn part2(almanac: &ParsedInput) -> color_eyre::Result<u64> {
+        // seeds: line actually describes ranges of seed numbers.
+        // What is the lowest location number that corresponds to any of the initial seed numberss
+
+        let mut seed_ranges = almanac.seeds_pt2.clone();
+
+        for map in &almanac.maps {
+            seed_ranges = seed_ranges
+                .iter()
+                .flat_map(|range| map.map_range(range))
+                .collect();
+        }
+
+        Ok(seed_ranges.iter().map(|range| range.start).min().unwrap())
+    }
 }
 
 pub struct Almanac {
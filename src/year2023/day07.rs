@@ -2,30 +2,38 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Vec<Round>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input
-        .lines()
-        .map(Round::from_str)
-        .collect::<Result<_, _>>()?)
-}
+pub struct Solution;
 
-pub fn part1(rounds: &ParsedInput) -> color_eyre::Result<u64> {
-    // Each hand wins an amount equal to its bid multiplied by its rank,
-    // where the weakest hand gets rank 1, the second-weakest hand gets rank 2,
-    // and so on up to the strongest hand.
-    // determine the total winnings of this set of hands by adding up the result of multiplying each hand's bid with its rank
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    get_winnings(rounds, false)
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input
+            .lines()
+            .map(Round::from_str)
+            .collect::<Result<_, _>>()?)
+    }
 
-pub fn part2(rounds: &ParsedInput) -> color_eyre::Result<u64> {
-    // Now, J cards are jokers - wildcards that can act like whatever card would make the hand the strongest type possible.
+    fn part1(rounds: &ParsedInput) -> color_eyre::Result<u64> {
+        // Each hand wins an amount equal to its bid multiplied by its rank,
+        // where the weakest hand gets rank 1, the second-weakest hand gets rank 2,
+        // and so on up to the strongest hand.
+        // determine the total winnings of this set of hands by adding up the result of multiplying each hand's bid with its rank
 
-    get_winnings(rounds, true)
+        get_winnings(rounds, false)
+    }
+
+    fn part2(rounds: &ParsedInput) -> color_eyre::Result<u64> {
+        // Now, J cards are jokers - wildcards that can act like whatever card would make the hand the strongest type possible.
+
+        get_winnings(rounds, true)
+    }
 }
 
 fn get_winnings(rounds: &ParsedInput, jacks_wild: bool) -> color_eyre::Result<u64> {
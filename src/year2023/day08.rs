@@ -3,75 +3,83 @@ use num::integer::lcm;
 use std::collections::HashMap;
 
 type ParsedInput = Docs;
-use crate::error::AdventError;
-
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut lines = input.lines();
-    let steps = lines
-        .next()
-        .ok_or(AdventError::EndOfIterator)?
-        .chars()
-        .collect_vec();
-
-    lines.next(); // Skip the blank line
-
-    let mut network = HashMap::new();
-
-    for line in lines {
-        let (node, links) = line
-            .split_once('=')
-            .ok_or(AdventError::SplitOnce(line.into(), '='.into()))?;
-        let node = node.trim().to_string();
-
-        let (link_l, link_r) = links
-            .split_once(',')
-            .ok_or(AdventError::SplitOnce(links.into(), ','.into()))?;
-        let link_l = link_l
-            .trim()
-            .strip_prefix('(')
-            .unwrap_or(link_l)
-            .to_string();
-        let link_r = link_r
-            .trim()
-            .strip_suffix(')')
-            .unwrap_or(link_r)
-            .to_string();
-
-        network.insert(node, (link_l, link_r));
+use crate::{error::AdventError, solver::Solver};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut lines = input.lines();
+        let steps = lines
+            .next()
+            .ok_or(AdventError::EndOfIterator)?
+            .chars()
+            .collect_vec();
+
+        lines.next(); // Skip the blank line
+
+        let mut network = HashMap::new();
+
+        for line in lines {
+            let (node, links) = line
+                .split_once('=')
+                .ok_or(AdventError::SplitOnce(line.into(), '='.into()))?;
+            let node = node.trim().to_string();
+
+            let (link_l, link_r) = links
+                .split_once(',')
+                .ok_or(AdventError::SplitOnce(links.into(), ','.into()))?;
+            let link_l = link_l
+                .trim()
+                .strip_prefix('(')
+                .unwrap_or(link_l)
+                .to_string();
+            let link_r = link_r
+                .trim()
+                .strip_suffix(')')
+                .unwrap_or(link_r)
+                .to_string();
+
+            network.insert(node, (link_l, link_r));
+        }
+
+        Ok(Docs { steps, network })
     }
 
-    Ok(Docs { steps, network })
-}
-
-pub fn part1(docs: &ParsedInput) -> color_eyre::Result<u64> {
-    let start_node = "AAA";
+    fn part1(docs: &ParsedInput) -> color_eyre::Result<u64> {
+        let start_node = "AAA";
 
-    Ok(solve(docs, start_node, |node| node == "ZZZ")?)
-}
+        Ok(solve(docs, start_node, |node| node == "ZZZ")?)
+    }
 
-pub fn part2(docs: &ParsedInput) -> color_eyre::Result<u64> {
-    // Simultaneously start on every node that ends with A. How many steps does it take before you're only on nodes that end with Z?
+    fn part2(docs: &ParsedInput) -> color_eyre::Result<u64> {
+        // Simultaneously start on every node that ends with A. How many steps does it take before you're only on nodes that end with Z?
 
-    let start_nodes = docs
-        .network
-        .keys()
-        .filter(|key| key.ends_with('A'))
-        .cloned()
-        .collect_vec();
+        let start_nodes = docs
+            .network
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .cloned()
+            .collect_vec();
 
-    let at_end = |node: &str| node.ends_with('Z');
+        let at_end = |node: &str| node.ends_with('Z');
 
-    let steps = start_nodes
-        .iter()
-        .map(|start_node| solve(docs, start_node, at_end))
-        .collect::<Result<Vec<u64>, AdventError>>()?;
+        let steps = start_nodes
+            .iter()
+            .map(|start_node| solve(docs, start_node, at_end))
+            .collect::<Result<Vec<u64>, AdventError>>()?;
 
-    let coal = steps
-        .into_iter()
-        .coalesce(|prev, cur| Ok(lcm(prev, cur)))
-        .collect_vec();
+        let coal = steps
+            .into_iter()
+            .coalesce(|prev, cur| Ok(lcm(prev, cur)))
+            .collect_vec();
 
-    Ok(coal[0])
+        Ok(coal[0])
+    }
 }
 
 fn solve<F>(docs: &Docs, start_node: &str, at_end: F) -> Result<u64, AdventError>
@@ -1,27 +1,35 @@
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Vec<Reading>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input
-        .lines()
-        .map(|line| line.split_ascii_whitespace())
-        .map(|history| history.map(|value| value.parse::<i32>()).try_collect())
-        .try_collect()?)
-}
+pub struct Solution;
 
-pub fn part1(readings: &ParsedInput) -> color_eyre::Result<i32> {
-    let next_values: Vec<i32> = readings.iter().map(next_in_sequence).try_collect()?;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = i32;
+    type Answer2 = i32;
 
-    Ok(next_values.iter().sum())
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input
+            .lines()
+            .map(|line| line.split_ascii_whitespace())
+            .map(|history| history.map(|value| value.parse::<i32>()).try_collect())
+            .try_collect()?)
+    }
 
-pub fn part2(readings: &ParsedInput) -> color_eyre::Result<i32> {
-    let prev_values: Vec<i32> = readings.iter().map(prev_in_sequence).try_collect()?;
+    fn part1(readings: &ParsedInput) -> color_eyre::Result<i32> {
+        let next_values: Vec<i32> = readings.iter().map(next_in_sequence).try_collect()?;
 
-    Ok(prev_values.iter().sum())
+        Ok(next_values.iter().sum())
+    }
+
+    fn part2(readings: &ParsedInput) -> color_eyre::Result<i32> {
+        let prev_values: Vec<i32> = readings.iter().map(prev_in_sequence).try_collect()?;
+
+        Ok(prev_values.iter().sum())
+    }
 }
 
 pub fn next_in_sequence(reading: &Reading) -> color_eyre::Result<i32> {
@@ -3,48 +3,57 @@ use strum::IntoEnumIterator;
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{direction::Direction, grid::Grid, point::Point},
 };
 
 type ParsedInput = Grid<NodeType>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut inner = grid::Grid::new(0, 0);
+pub struct Solution;
 
-    for line in input.lines() {
-        inner.push_row(line.chars().map(NodeType::try_from).try_collect()?);
-    }
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = i64;
 
-    Ok(Grid::from(inner))
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut inner = grid::Grid::new(0, 0);
 
-pub fn part1(grid: &ParsedInput) -> color_eyre::Result<usize> {
-    let path = get_path(grid)?;
-    if !path.is_empty() {
-        Ok(path.len() / 2)
-    } else {
-        Err(AdventError::NotFound("Path not found".to_string()).into())
+        for line in input.lines() {
+            inner.push_row(line.chars().map(NodeType::try_from).try_collect()?);
+        }
+
+        Ok(Grid::from(inner))
     }
-}
 
-pub fn part2(grid: &ParsedInput) -> color_eyre::Result<i64> {
-    let (start, _) = grid
-        .indexed_iter()
-        .find(|&(_, node)| *node == NodeType::Start)
-        .ok_or(AdventError::NotFound(NodeType::Start.to_string()))?;
+    fn part1(grid: &ParsedInput) -> color_eyre::Result<usize> {
+        let path = get_path(grid)?;
+        if !path.is_empty() {
+            Ok(path.len() / 2)
+        } else {
+            Err(AdventError::NotFound("Path not found".to_string()).into())
+        }
+    }
 
-    let mut path = get_path(grid)?;
-    path.push(start);
+    fn part2(grid: &ParsedInput) -> color_eyre::Result<i64> {
+        let (start, _) = grid
+            .indexed_iter()
+            .find(|&(_, node)| *node == NodeType::Start)
+            .ok_or(AdventError::NotFound(NodeType::Start.to_string()))?;
 
-    let perimeter = path.len() as i64;
-    let mut area = 0;
+        let mut path = get_path(grid)?;
+        path.push(start);
 
-    for (a, b) in path.iter().tuple_windows() {
-        area += calc_determinant(&Point::from(*a), &Point::from(*b));
-    }
+        let perimeter = path.len() as i64;
+        let mut area = 0;
+
+        for (a, b) in path.iter().tuple_windows() {
+            area += calc_determinant(&Point::from(*a), &Point::from(*b));
+        }
 
-    // Pick's Theorem
-    Ok(area.abs() / 2 - (perimeter / 2 - 1))
+        // Pick's Theorem
+        Ok(area.abs() / 2 - (perimeter / 2 - 1))
+    }
 }
 
 // Shoelace Theorem
@@ -1,50 +1,58 @@
 use std::fmt::Debug;
 
-use crate::error::AdventError;
 use crate::util::grid::Grid;
+use crate::{error::AdventError, solver::Solver};
 use itertools::Itertools;
 
 type ParsedInput = Universe;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut grid = Grid::new(0, 0);
+pub struct Solution;
 
-    for line in input.lines() {
-        let row = line.chars().collect_vec();
-        if row.iter().all_equal_value() == Ok(&'.') {
-            grid.push_row(vec!['*'; grid.cols()]);
-        }
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-        grid.push_row(row);
-    }
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut grid = Grid::new(0, 0);
 
-    let mut col_idx = 0;
-    loop {
-        if grid.iter_col(col_idx).all(|v| *v == '.' || *v == '*') {
-            grid.insert_col(col_idx, vec!['*'; grid.rows()]);
-            col_idx += 1;
+        for line in input.lines() {
+            let row = line.chars().collect_vec();
+            if row.iter().all_equal_value() == Ok(&'.') {
+                grid.push_row(vec!['*'; grid.cols()]);
+            }
+
+            grid.push_row(row);
         }
 
-        col_idx += 1;
+        let mut col_idx = 0;
+        loop {
+            if grid.iter_col(col_idx).all(|v| *v == '.' || *v == '*') {
+                grid.insert_col(col_idx, vec!['*'; grid.rows()]);
+                col_idx += 1;
+            }
+
+            col_idx += 1;
 
-        if col_idx == grid.cols() {
-            break;
+            if col_idx == grid.cols() {
+                break;
+            }
         }
-    }
 
-    Ok(Universe { grid })
-}
+        Ok(Universe { grid })
+    }
 
-pub fn part1(universe: &ParsedInput) -> color_eyre::Result<u64> {
-    let distances = universe.get_distances(2)?;
+    fn part1(universe: &ParsedInput) -> color_eyre::Result<u64> {
+        let distances = universe.get_distances(2)?;
 
-    Ok(distances.iter().sum())
-}
+        Ok(distances.iter().sum())
+    }
 
-pub fn part2(universe: &ParsedInput) -> color_eyre::Result<u64> {
-    let distances = universe.get_distances(1000000)?;
+    fn part2(universe: &ParsedInput) -> color_eyre::Result<u64> {
+        let distances = universe.get_distances(1000000)?;
 
-    Ok(distances.iter().sum())
+        Ok(distances.iter().sum())
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -2,68 +2,76 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Input;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let part1 = input
-        .lines()
-        .map(|line| {
-            let (springs, groups) = line
-                .split_once(' ')
-                .ok_or(AdventError::SplitOnce(line.into(), ' '.into()))?;
-
-            let springs = springs.chars().collect_vec();
-            let groups: Vec<usize> = groups
-                .split(',')
-                .map(|group| group.parse::<usize>())
-                .try_collect()?;
-
-            Ok(Row { springs, groups })
-        })
-        .collect::<Result<Vec<Row>, AdventError>>()?;
-
-    let part2 = part1
-        .iter()
-        .map(|row| {
-            let mut new_row = row.clone();
-            (0..4).for_each(|_| {
-                new_row.springs.push('?');
-                new_row.springs.extend(row.springs.iter());
-                new_row.groups.extend(row.groups.iter());
-            });
-            new_row
-        })
-        .collect_vec();
-
-    Ok(Input { part1, part2 })
-}
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let part1 = input
+            .lines()
+            .map(|line| {
+                let (springs, groups) = line
+                    .split_once(' ')
+                    .ok_or(AdventError::SplitOnce(line.into(), ' '.into()))?;
+
+                let springs = springs.chars().collect_vec();
+                let groups: Vec<usize> = groups
+                    .split(',')
+                    .map(|group| group.parse::<usize>())
+                    .try_collect()?;
+
+                Ok(Row { springs, groups })
+            })
+            .collect::<Result<Vec<Row>, AdventError>>()?;
+
+        let part2 = part1
+            .iter()
+            .map(|row| {
+                let mut new_row = row.clone();
+                (0..4).for_each(|_| {
+                    new_row.springs.push('?');
+                    new_row.springs.extend(row.springs.iter());
+                    new_row.groups.extend(row.groups.iter());
+                });
+                new_row
+            })
+            .collect_vec();
+
+        Ok(Input { part1, part2 })
+    }
 
-pub fn part1(input: &ParsedInput) -> color_eyre::Result<u64> {
-    let mut total = 0;
-    for row in &input.part1 {
-        let springs = row.springs.clone();
-        let groups = row.groups.clone();
+    fn part1(input: &ParsedInput) -> color_eyre::Result<u64> {
+        let mut total = 0;
+        for row in &input.part1 {
+            let springs = row.springs.clone();
+            let groups = row.groups.clone();
 
-        let mut cache = HashMap::new();
-        let answer = cached_solve(&springs, &groups, &mut cache)?;
-        total += answer;
+            let mut cache = HashMap::new();
+            let answer = cached_solve(&springs, &groups, &mut cache)?;
+            total += answer;
+        }
+        Ok(total)
     }
-    Ok(total)
-}
 
-pub fn part2(input: &ParsedInput) -> color_eyre::Result<u64> {
-    let mut total = 0;
-    for row in &input.part2 {
-        let springs = row.springs.clone();
-        let groups = row.groups.clone();
+    fn part2(input: &ParsedInput) -> color_eyre::Result<u64> {
+        let mut total = 0;
+        for row in &input.part2 {
+            let springs = row.springs.clone();
+            let groups = row.groups.clone();
 
-        let mut cache = HashMap::new();
-        let answer = cached_solve(&springs, &groups, &mut cache)?;
-        total += answer;
+            let mut cache = HashMap::new();
+            let answer = cached_solve(&springs, &groups, &mut cache)?;
+            total += answer;
+        }
+        Ok(total)
     }
-    Ok(total)
 }
 
 fn cached_solve<'a>(
@@ -1,56 +1,64 @@
 use itertools::Itertools;
 
-use crate::util::grid::Grid;
+use crate::{solver::Solver, util::grid::Grid};
 
 type ParsedInput = Vec<Grid<char>>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let input = input.replace("\r\n", "\n");
+pub struct Solution;
 
-    let grids = input.split("\n\n").collect_vec();
-    Ok(grids.into_iter().map(|s| s.try_into()).try_collect()?)
-}
-
-pub fn part1(grids: &ParsedInput) -> color_eyre::Result<usize> {
-    let mut grids = grids.clone();
-
-    let mut answer = 0;
-    for grid in &mut grids {
-        if let Some(mirror_row) = find_mirror(grid, false) {
-            let rows_above = mirror_row + 1;
-            answer += rows_above * 100;
-        }
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-        grid.transpose();
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let input = input.replace("\r\n", "\n");
 
-        if let Some(mirror_col) = find_mirror(grid, false) {
-            let cols_left = mirror_col + 1;
-            answer += cols_left;
-        }
+        let grids = input.split("\n\n").collect_vec();
+        Ok(grids.into_iter().map(|s| s.try_into()).try_collect()?)
     }
 
-    Ok(answer)
-}
+    fn part1(grids: &ParsedInput) -> color_eyre::Result<usize> {
+        let mut grids = grids.clone();
 
-pub fn part2(grids: &ParsedInput) -> color_eyre::Result<usize> {
-    let mut grids = grids.clone();
+        let mut answer = 0;
+        for grid in &mut grids {
+            if let Some(mirror_row) = find_mirror(grid, false) {
+                let rows_above = mirror_row + 1;
+                answer += rows_above * 100;
+            }
 
-    let mut answer = 0;
-    for grid in &mut grids {
-        if let Some(mirror_row) = find_mirror(grid, true) {
-            let rows_above = mirror_row + 1;
-            answer += rows_above * 100;
-        } else {
             grid.transpose();
 
-            if let Some(mirror_col) = find_mirror(grid, true) {
+            if let Some(mirror_col) = find_mirror(grid, false) {
                 let cols_left = mirror_col + 1;
                 answer += cols_left;
             }
         }
+
+        Ok(answer)
     }
 
-    Ok(answer)
+    fn part2(grids: &ParsedInput) -> color_eyre::Result<usize> {
+        let mut grids = grids.clone();
+
+        let mut answer = 0;
+        for grid in &mut grids {
+            if let Some(mirror_row) = find_mirror(grid, true) {
+                let rows_above = mirror_row + 1;
+                answer += rows_above * 100;
+            } else {
+                grid.transpose();
+
+                if let Some(mirror_col) = find_mirror(grid, true) {
+                    let cols_left = mirror_col + 1;
+                    answer += cols_left;
+                }
+            }
+        }
+
+        Ok(answer)
+    }
 }
 
 fn find_mirror(grid: &mut Grid<char>, detect_smudge: bool) -> Option<usize> {
@@ -1,73 +1,81 @@
 use itertools::Itertools;
 use std::collections::HashMap;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Vec<String>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input.split(',').map_into().collect_vec())
-}
+pub struct Solution;
 
-pub fn part1(steps: &ParsedInput) -> color_eyre::Result<u32> {
-    // Determine the ASCII code for the current character of the string.
-    // Increase the current value by the ASCII code you just determined.
-    // Set the current value to itself multiplied by 17.
-    // Set the current value to the remainder of dividing itself by 256.
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-    let mut total: u32 = 0;
-    for step in steps {
-        total += hash(step);
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input.split(',').map_into().collect_vec())
     }
 
-    Ok(total)
-}
+    fn part1(steps: &ParsedInput) -> color_eyre::Result<u32> {
+        // Determine the ASCII code for the current character of the string.
+        // Increase the current value by the ASCII code you just determined.
+        // Set the current value to itself multiplied by 17.
+        // Set the current value to the remainder of dividing itself by 256.
 
-pub fn part2(steps: &ParsedInput) -> color_eyre::Result<u32> {
-    let mut boxes = HashMap::<u32, Vec<Lens>>::new();
-
-    for step in steps {
-        let mut label = "";
-        let mut op = ' ';
-        let mut focal_length = None;
-        if let Some((s, o)) = step.split_once('=') {
-            label = s;
-            op = '=';
-            focal_length = Some(o.parse::<u32>().map_err(AdventError::ParseInt)?);
-        } else if let Some((s, _)) = step.split_once('-') {
-            label = s;
-            op = '-';
+        let mut total: u32 = 0;
+        for step in steps {
+            total += hash(step);
         }
 
-        let box_num = hash(label);
-        if op == '-' {
-            if let Some(lenses) = boxes.get_mut(&box_num) {
-                if let Some(idx) = lenses.iter().position(|lens| lens.label == label) {
-                    lenses.remove(idx);
-                }
+        Ok(total)
+    }
+
+    fn part2(steps: &ParsedInput) -> color_eyre::Result<u32> {
+        let mut boxes = HashMap::<u32, Vec<Lens>>::new();
+
+        for step in steps {
+            let mut label = "";
+            let mut op = ' ';
+            let mut focal_length = None;
+            if let Some((s, o)) = step.split_once('=') {
+                label = s;
+                op = '=';
+                focal_length = Some(o.parse::<u32>().map_err(AdventError::ParseInt)?);
+            } else if let Some((s, _)) = step.split_once('-') {
+                label = s;
+                op = '-';
             }
-        } else if op == '=' {
-            let lens = Lens {
-                label: label.into(),
-                focal_length: focal_length.ok_or(AdventError::NotFound(step.into()))?,
-            };
-
-            boxes
-                .entry(box_num)
-                .and_modify(|lenses| {
+
+            let box_num = hash(label);
+            if op == '-' {
+                if let Some(lenses) = boxes.get_mut(&box_num) {
                     if let Some(idx) = lenses.iter().position(|lens| lens.label == label) {
-                        lenses[idx] = lens.clone();
-                    } else {
-                        lenses.push(lens.clone());
+                        lenses.remove(idx);
                     }
-                })
-                .or_insert(vec![lens]);
+                }
+            } else if op == '=' {
+                let lens = Lens {
+                    label: label.into(),
+                    focal_length: focal_length.ok_or(AdventError::NotFound(step.into()))?,
+                };
+
+                boxes
+                    .entry(box_num)
+                    .and_modify(|lenses| {
+                        if let Some(idx) = lenses.iter().position(|lens| lens.label == label) {
+                            lenses[idx] = lens.clone();
+                        } else {
+                            lenses.push(lens.clone());
+                        }
+                    })
+                    .or_insert(vec![lens]);
+            }
         }
-    }
 
-    let focusing_power = boxes.iter().map(box_focusing_power).sum::<u32>();
+        let focusing_power = boxes.iter().map(box_focusing_power).sum::<u32>();
 
-    Ok(focusing_power)
+        Ok(focusing_power)
+    }
 }
 
 fn box_focusing_power((box_num, lenses): (&u32, &Vec<Lens>)) -> u32 {
@@ -1,56 +1,64 @@
 use std::collections::HashMap;
 
-use crate::error::AdventError;
 use crate::util::direction::Direction::{self, *};
 use crate::util::grid::Grid;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Grid<char>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(Grid::try_from(input)?)
-}
-
-pub fn part1(grid: &ParsedInput) -> color_eyre::Result<usize> {
-    let beam = Beam {
-        point: (0, 0),
-        direction: East,
-    };
+pub struct Solution;
 
-    fire_beam(beam, grid)
-}
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-pub fn part2(grid: &ParsedInput) -> color_eyre::Result<usize> {
-    let mut max_energized = 0;
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(Grid::try_from(input)?)
+    }
 
-    for row in 0..grid.rows() {
+    fn part1(grid: &ParsedInput) -> color_eyre::Result<usize> {
         let beam = Beam {
-            point: (row, 0),
+            point: (0, 0),
             direction: East,
         };
-        max_energized = max_energized.max(fire_beam(beam, grid)?);
 
-        let beam = Beam {
-            point: (row, grid.cols() - 1),
-            direction: West,
-        };
-        max_energized = max_energized.max(fire_beam(beam, grid)?);
+        fire_beam(beam, grid)
     }
 
-    for col in 0..grid.cols() {
-        let beam = Beam {
-            point: (0, col),
-            direction: South,
-        };
-        max_energized = max_energized.max(fire_beam(beam, grid)?);
+    fn part2(grid: &ParsedInput) -> color_eyre::Result<usize> {
+        let mut max_energized = 0;
+
+        for row in 0..grid.rows() {
+            let beam = Beam {
+                point: (row, 0),
+                direction: East,
+            };
+            max_energized = max_energized.max(fire_beam(beam, grid)?);
+
+            let beam = Beam {
+                point: (row, grid.cols() - 1),
+                direction: West,
+            };
+            max_energized = max_energized.max(fire_beam(beam, grid)?);
+        }
 
-        let beam = Beam {
-            point: (grid.rows() - 1, col),
-            direction: West,
-        };
-        max_energized = max_energized.max(fire_beam(beam, grid)?);
-    }
+        for col in 0..grid.cols() {
+            let beam = Beam {
+                point: (0, col),
+                direction: South,
+            };
+            max_energized = max_energized.max(fire_beam(beam, grid)?);
+
+            let beam = Beam {
+                point: (grid.rows() - 1, col),
+                direction: West,
+            };
+            max_energized = max_energized.max(fire_beam(beam, grid)?);
+        }
 
-    Ok(max_energized)
+        Ok(max_energized)
+    }
 }
 
 fn fire_beam(beam: Beam, grid: &ParsedInput) -> color_eyre::Result<usize> {
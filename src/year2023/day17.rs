@@ -2,77 +2,86 @@ use itertools::Itertools;
 use pathfinding::directed::dijkstra::*;
 use strum::IntoEnumIterator;
 
-use crate::error::AdventError;
 use crate::util::direction::Direction;
 use crate::util::grid::Grid;
 use crate::util::point::Point;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Grid<u32>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    // Fix Grid try_from to support any type...
-    // let g = Grid::<u32>::try_from(input)?;
-
-    let mut inner = grid::Grid::new(0, 0);
-
-    for line in input.lines() {
-        inner.push_row(
-            line.chars()
-                .map(|ch| {
-                    ch.to_digit(10)
-                        .ok_or(AdventError::UnexpectedValue("number".into(), ch.into()))
-                })
-                .try_collect()?,
-        );
-    }
+pub struct Solution;
 
-    Ok(Grid::from(inner))
-}
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-pub fn part1(map: &ParsedInput) -> color_eyre::Result<usize> {
-    let successors = |node: &Node| -> Vec<(Node, usize)> { get_cardinal_successors(map, node, 3) };
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        // Fix Grid try_from to support any type...
+        // let g = Grid::<u32>::try_from(input)?;
 
-    let start = Node::new(0, 0);
-    let end = Node::new(map.cols() - 1, map.rows() - 1);
-    let success = |node: &Node| -> bool { node.is_same_loc(&end) };
+        let mut inner = grid::Grid::new(0, 0);
 
-    let path = dijkstra(&start, successors, success).expect("Failed to find path");
-    // print_path(map, path.0);
+        for line in input.lines() {
+            inner.push_row(
+                line.chars()
+                    .map(|ch| {
+                        ch.to_digit(10)
+                            .ok_or(AdventError::UnexpectedValue("number".into(), ch.into()))
+                    })
+                    .try_collect()?,
+            );
+        }
 
-    Ok(path.1)
-}
+        Ok(Grid::from(inner))
+    }
 
-pub fn part2(map: &ParsedInput) -> color_eyre::Result<usize> {
-    let successors = |node: &Node| -> Vec<(Node, usize)> {
-        let grid_loc = (node.loc.y, node.loc.x);
+    fn part1(map: &ParsedInput) -> color_eyre::Result<usize> {
+        let successors =
+            |node: &Node| -> Vec<(Node, usize)> { get_cardinal_successors(map, node, 3) };
 
-        if node.dir_count < 4 {
-            if let Some(from) = node.in_dir {
-                if let Some((next_point, _)) = map.get_in_direction_indexed(grid_loc, from) {
-                    let mut next_node = Node::from((next_point.1, next_point.0));
-                    next_node.in_dir = Some(from);
-                    next_node.dir_count = node.dir_count + 1;
+        let start = Node::new(0, 0);
+        let end = Node::new(map.cols() - 1, map.rows() - 1);
+        let success = |node: &Node| -> bool { node.is_same_loc(&end) };
+
+        let path = dijkstra(&start, successors, success).expect("Failed to find path");
+        // print_path(map, path.0);
+
+        Ok(path.1)
+    }
 
-                    if let Some(weight) = map.get(next_node.loc.y, next_node.loc.x) {
-                        return vec![(next_node, *weight as usize)];
+    fn part2(map: &ParsedInput) -> color_eyre::Result<usize> {
+        let successors = |node: &Node| -> Vec<(Node, usize)> {
+            let grid_loc = (node.loc.y, node.loc.x);
+
+            if node.dir_count < 4 {
+                if let Some(from) = node.in_dir {
+                    if let Some((next_point, _)) = map.get_in_direction_indexed(grid_loc, from) {
+                        let mut next_node = Node::from((next_point.1, next_point.0));
+                        next_node.in_dir = Some(from);
+                        next_node.dir_count = node.dir_count + 1;
+
+                        if let Some(weight) = map.get(next_node.loc.y, next_node.loc.x) {
+                            return vec![(next_node, *weight as usize)];
+                        }
+                    } else {
+                        return vec![];
                     }
-                } else {
-                    return vec![];
                 }
             }
-        }
 
-        get_cardinal_successors(map, node, 10)
-    };
+            get_cardinal_successors(map, node, 10)
+        };
 
-    let start = Node::new(0, 0);
-    let end = Node::new(map.cols() - 1, map.rows() - 1);
-    let success = |node: &Node| -> bool { node.is_same_loc(&end) && node.dir_count >= 4 };
+        let start = Node::new(0, 0);
+        let end = Node::new(map.cols() - 1, map.rows() - 1);
+        let success = |node: &Node| -> bool { node.is_same_loc(&end) && node.dir_count >= 4 };
 
-    let path = dijkstra(&start, successors, success).expect("Failed to find path");
-    // print_path(map, path.0);
+        let path = dijkstra(&start, successors, success).expect("Failed to find path");
+        // print_path(map, path.0);
 
-    Ok(path.1)
+        Ok(path.1)
+    }
 }
 
 fn get_cardinal_successors(
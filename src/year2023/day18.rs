@@ -1,59 +1,67 @@
 use itertools::Itertools;
 
-use crate::error::AdventError;
 use crate::util::direction::Direction;
 use crate::util::point::PointT;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Input;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let parts: Vec<(DigStep, DigStep)> = input
-        .lines()
-        .map(|line| {
-            let splits = line.split_ascii_whitespace().collect_vec();
-
-            let dir = match splits[0] {
-                "U" => Direction::North,
-                "R" => Direction::East,
-                "D" => Direction::South,
-                "L" => Direction::West,
-                _ => return Err(AdventError::UnknownPattern(splits[0].into())),
-            };
-
-            let part1 = DigStep {
-                dir,
-                size: splits[1].parse()?,
-            };
-
-            let colour = splits[2].trim_start_matches("(#").trim_end_matches(')');
-
-            let (size, dir) = colour.split_at(5);
-            let dir = match dir {
-                "3" => Direction::North,
-                "0" => Direction::East,
-                "1" => Direction::South,
-                "2" => Direction::West,
-                _ => return Err(AdventError::UnknownPattern(dir.into())),
-            };
-            let size = i64::from_str_radix(size, 16)?;
-
-            let part2 = DigStep { dir, size };
-
-            Ok((part1, part2))
-        })
-        .try_collect()?;
-
-    let (part1, part2) = parts.into_iter().unzip();
-
-    Ok(Input { part1, part2 })
-}
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let parts: Vec<(DigStep, DigStep)> = input
+            .lines()
+            .map(|line| {
+                let splits = line.split_ascii_whitespace().collect_vec();
+
+                let dir = match splits[0] {
+                    "U" => Direction::North,
+                    "R" => Direction::East,
+                    "D" => Direction::South,
+                    "L" => Direction::West,
+                    _ => return Err(AdventError::UnknownPattern(splits[0].into())),
+                };
+
+                let part1 = DigStep {
+                    dir,
+                    size: splits[1].parse()?,
+                };
+
+                let colour = splits[2].trim_start_matches("(#").trim_end_matches(')');
+
+                let (size, dir) = colour.split_at(5);
+                let dir = match dir {
+                    "3" => Direction::North,
+                    "0" => Direction::East,
+                    "1" => Direction::South,
+                    "2" => Direction::West,
+                    _ => return Err(AdventError::UnknownPattern(dir.into())),
+                };
+                let size = i64::from_str_radix(size, 16)?;
+
+                let part2 = DigStep { dir, size };
+
+                Ok((part1, part2))
+            })
+            .try_collect()?;
+
+        let (part1, part2) = parts.into_iter().unzip();
+
+        Ok(Input { part1, part2 })
+    }
 
-pub fn part1(input: &ParsedInput) -> color_eyre::Result<i64> {
-    Ok(solve(&input.part1))
-}
+    fn part1(input: &ParsedInput) -> color_eyre::Result<i64> {
+        Ok(solve(&input.part1))
+    }
 
-pub fn part2(input: &ParsedInput) -> color_eyre::Result<i64> {
-    Ok(solve(&input.part2))
+    fn part2(input: &ParsedInput) -> color_eyre::Result<i64> {
+        Ok(solve(&input.part2))
+    }
 }
 
 fn solve(steps: &Vec<DigStep>) -> i64 {
@@ -2,184 +2,192 @@ use std::{collections::HashMap, ops::Range};
 
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type Workflows = HashMap<String, Workflow>;
 type Parts = Vec<Part>;
 
 type ParsedInput = (Workflows, Parts);
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let input = input.replace("\r\n", "\n");
-
-    let (workflows, parts) = input.split_once("\n\n").ok_or(AdventError::SplitOnce(
-        input.to_string(),
-        "double-newline".into(),
-    ))?;
-
-    // px{a<2006:qkq,m>2090:A,rfg}
-    let workflows: Workflows = workflows
-        .lines()
-        .map(|line| -> Result<_, AdventError> {
-            let (name, rules) = line
-                .split_once('{')
-                .ok_or(AdventError::SplitOnce(line.into(), '{'.into()))?;
-
-            let rules = rules.trim_end_matches('}');
-            let rules = rules.split(',').collect_vec();
-
-            let fallthrough = rules.last().ok_or(AdventError::EmptySlice)?.to_string();
-            let rules = rules
-                .iter()
-                .take(rules.len() - 1)
-                .copied()
-                .map(Rule::try_from)
-                .try_collect()?;
-
-            Ok((name.to_string(), Workflow { rules, fallthrough }))
-        })
-        .try_collect()?;
+pub struct Solution;
 
-    // {x=787,m=2655,a=1222,s=2876}
-    let parts = parts.lines().map(Part::try_from).try_collect()?;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    Ok((workflows, parts))
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let input = input.replace("\r\n", "\n");
 
-pub fn part1(input: &ParsedInput) -> color_eyre::Result<u64> {
-    let workflows = &input.0;
-    let parts = &input.1;
-
-    let mut accepted_parts = Vec::new();
-
-    for part in parts {
-        let mut cur_workflow = "in";
-
-        loop {
-            let workflow = workflows
-                .get(cur_workflow)
-                .ok_or(AdventError::NotFound("in".into()))?;
-            let next = workflow.eval_part(part);
-
-            if next == "A" {
-                accepted_parts.push(part);
-                break;
-            } else if next == "R" {
-                // rejected
-                break;
-            } else {
-                cur_workflow = next;
-            }
-        }
-    }
+        let (workflows, parts) = input.split_once("\n\n").ok_or(AdventError::SplitOnce(
+            input.to_string(),
+            "double-newline".into(),
+        ))?;
 
-    Ok(accepted_parts.iter().map(|&part| part.total_rating()).sum())
-}
+        // px{a<2006:qkq,m>2090:A,rfg}
+        let workflows: Workflows = workflows
+            .lines()
+            .map(|line| -> Result<_, AdventError> {
+                let (name, rules) = line
+                    .split_once('{')
+                    .ok_or(AdventError::SplitOnce(line.into(), '{'.into()))?;
 
-pub fn part2(input: &ParsedInput) -> color_eyre::Result<u64> {
-    let workflows = &input.0;
-
-    let initial_parts = PartRanged {
-        x: 1..4001,
-        m: 1..4001,
-        a: 1..4001,
-        s: 1..4001,
-    };
-
-    let mut combinations = vec![Combination {
-        workflow_id: "in".to_string(),
-        rule_index: 0,
-        parts: initial_parts,
-    }];
-
-    let mut total = 0;
-
-    while let Some(combination) = combinations.pop() {
-        if combination.is_accepted() {
-            total += combination.parts.total();
-            continue;
-        } else if combination.is_rejected() {
-            continue;
-        }
+                let rules = rules.trim_end_matches('}');
+                let rules = rules.split(',').collect_vec();
+
+                let fallthrough = rules.last().ok_or(AdventError::EmptySlice)?.to_string();
+                let rules = rules
+                    .iter()
+                    .take(rules.len() - 1)
+                    .copied()
+                    .map(Rule::try_from)
+                    .try_collect()?;
+
+                Ok((name.to_string(), Workflow { rules, fallthrough }))
+            })
+            .try_collect()?;
 
-        let workflow = workflows.get(&combination.workflow_id).unwrap();
+        // {x=787,m=2655,a=1222,s=2876}
+        let parts = parts.lines().map(Part::try_from).try_collect()?;
+
+        Ok((workflows, parts))
+    }
 
-        if workflow.should_fallthrough(combination.rule_index) {
-            combinations.push(Combination {
-                workflow_id: workflow.fallthrough.clone(),
-                rule_index: 0,
-                parts: combination.parts.clone(),
-            });
-            continue;
+    fn part1(input: &ParsedInput) -> color_eyre::Result<u64> {
+        let workflows = &input.0;
+        let parts = &input.1;
+
+        let mut accepted_parts = Vec::new();
+
+        for part in parts {
+            let mut cur_workflow = "in";
+
+            loop {
+                let workflow = workflows
+                    .get(cur_workflow)
+                    .ok_or(AdventError::NotFound("in".into()))?;
+                let next = workflow.eval_part(part);
+
+                if next == "A" {
+                    accepted_parts.push(part);
+                    break;
+                } else if next == "R" {
+                    // rejected
+                    break;
+                } else {
+                    cur_workflow = next;
+                }
+            }
         }
 
-        let rule = &workflow.rules[combination.rule_index];
-        let parts = &combination.parts;
+        Ok(accepted_parts.iter().map(|&part| part.total_rating()).sum())
+    }
+
+    fn part2(input: &ParsedInput) -> color_eyre::Result<u64> {
+        let workflows = &input.0;
 
-        let field_range = match rule.field {
-            Field::X => &parts.x,
-            Field::M => &parts.m,
-            Field::A => &parts.a,
-            Field::S => &parts.s,
+        let initial_parts = PartRanged {
+            x: 1..4001,
+            m: 1..4001,
+            a: 1..4001,
+            s: 1..4001,
         };
 
-        match rule.kind {
-            RuleKind::LessThan => {
-                let r = field_range.start..rule.value;
-                let mut lt_combination = combination.clone();
-                lt_combination.workflow_id = rule.target.clone();
-                match rule.field {
-                    Field::X => lt_combination.parts.x = r,
-                    Field::M => lt_combination.parts.m = r,
-                    Field::A => lt_combination.parts.a = r,
-                    Field::S => lt_combination.parts.s = r,
-                }
-                lt_combination.rule_index = 0;
+        let mut combinations = vec![Combination {
+            workflow_id: "in".to_string(),
+            rule_index: 0,
+            parts: initial_parts,
+        }];
 
-                combinations.push(lt_combination);
+        let mut total = 0;
 
-                let r = rule.value..field_range.end;
-                let mut gt_combination = combination.clone();
-                match rule.field {
-                    Field::X => gt_combination.parts.x = r,
-                    Field::M => gt_combination.parts.m = r,
-                    Field::A => gt_combination.parts.a = r,
-                    Field::S => gt_combination.parts.s = r,
-                }
-                gt_combination.rule_index += 1; // Staying in this workflow, but the next rule
+        while let Some(combination) = combinations.pop() {
+            if combination.is_accepted() {
+                total += combination.parts.total();
+                continue;
+            } else if combination.is_rejected() {
+                continue;
+            }
+
+            let workflow = workflows.get(&combination.workflow_id).unwrap();
 
-                combinations.push(gt_combination);
+            if workflow.should_fallthrough(combination.rule_index) {
+                combinations.push(Combination {
+                    workflow_id: workflow.fallthrough.clone(),
+                    rule_index: 0,
+                    parts: combination.parts.clone(),
+                });
+                continue;
             }
-            RuleKind::GreaterThan => {
-                let r = field_range.start..rule.value + 1;
-                let mut lt_combination = combination.clone();
-                match rule.field {
-                    Field::X => lt_combination.parts.x = r,
-                    Field::M => lt_combination.parts.m = r,
-                    Field::A => lt_combination.parts.a = r,
-                    Field::S => lt_combination.parts.s = r,
+
+            let rule = &workflow.rules[combination.rule_index];
+            let parts = &combination.parts;
+
+            let field_range = match rule.field {
+                Field::X => &parts.x,
+                Field::M => &parts.m,
+                Field::A => &parts.a,
+                Field::S => &parts.s,
+            };
+
+            match rule.kind {
+                RuleKind::LessThan => {
+                    let r = field_range.start..rule.value;
+                    let mut lt_combination = combination.clone();
+                    lt_combination.workflow_id = rule.target.clone();
+                    match rule.field {
+                        Field::X => lt_combination.parts.x = r,
+                        Field::M => lt_combination.parts.m = r,
+                        Field::A => lt_combination.parts.a = r,
+                        Field::S => lt_combination.parts.s = r,
+                    }
+                    lt_combination.rule_index = 0;
+
+                    combinations.push(lt_combination);
+
+                    let r = rule.value..field_range.end;
+                    let mut gt_combination = combination.clone();
+                    match rule.field {
+                        Field::X => gt_combination.parts.x = r,
+                        Field::M => gt_combination.parts.m = r,
+                        Field::A => gt_combination.parts.a = r,
+                        Field::S => gt_combination.parts.s = r,
+                    }
+                    gt_combination.rule_index += 1; // Staying in this workflow, but the next rule
+
+                    combinations.push(gt_combination);
                 }
-                lt_combination.rule_index += 1; // Staying in this workflow, but the next rule
-
-                combinations.push(lt_combination);
-
-                let r = rule.value + 1..field_range.end;
-                let mut gt_combination = combination.clone();
-                gt_combination.workflow_id = rule.target.clone();
-                match rule.field {
-                    Field::X => gt_combination.parts.x = r,
-                    Field::M => gt_combination.parts.m = r,
-                    Field::A => gt_combination.parts.a = r,
-                    Field::S => gt_combination.parts.s = r,
+                RuleKind::GreaterThan => {
+                    let r = field_range.start..rule.value + 1;
+                    let mut lt_combination = combination.clone();
+                    match rule.field {
+                        Field::X => lt_combination.parts.x = r,
+                        Field::M => lt_combination.parts.m = r,
+                        Field::A => lt_combination.parts.a = r,
+                        Field::S => lt_combination.parts.s = r,
+                    }
+                    lt_combination.rule_index += 1; // Staying in this workflow, but the next rule
+
+                    combinations.push(lt_combination);
+
+                    let r = rule.value + 1..field_range.end;
+                    let mut gt_combination = combination.clone();
+                    gt_combination.workflow_id = rule.target.clone();
+                    match rule.field {
+                        Field::X => gt_combination.parts.x = r,
+                        Field::M => gt_combination.parts.m = r,
+                        Field::A => gt_combination.parts.a = r,
+                        Field::S => gt_combination.parts.s = r,
+                    }
+                    gt_combination.rule_index = 0;
+
+                    combinations.push(gt_combination);
                 }
-                gt_combination.rule_index = 0;
-
-                combinations.push(gt_combination);
             }
         }
-    }
 
-    Ok(total)
+        Ok(total)
+    }
 }
 
 #[derive(Clone, Debug)]
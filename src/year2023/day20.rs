@@ -3,137 +3,145 @@ use std::collections::{HashMap, VecDeque};
 use itertools::Itertools;
 use num::integer::lcm;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 pub type ParsedInput = HashMap<String, Module>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut modules: HashMap<String, Module> = input
-        .lines()
-        .map(to_module)
-        .map_ok(|(id, module)| (id.clone(), module))
-        .try_collect()?;
-
-    let conjunctions = modules
-        .iter_mut()
-        .filter_map(|(key, module)| {
-            if let Module::Conjuction(_) = module {
-                Some(key.clone())
-            } else {
-                None
-            }
-        })
-        .collect_vec();
-
-    for conjunction in conjunctions {
-        let inputs = modules
-            .iter()
-            .filter_map(|(id, module)| {
-                if module.targets().unwrap().contains(&conjunction) {
-                    Some(id.to_owned())
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut modules: HashMap<String, Module> = input
+            .lines()
+            .map(to_module)
+            .map_ok(|(id, module)| (id.clone(), module))
+            .try_collect()?;
+
+        let conjunctions = modules
+            .iter_mut()
+            .filter_map(|(key, module)| {
+                if let Module::Conjuction(_) = module {
+                    Some(key.clone())
                 } else {
                     None
                 }
             })
             .collect_vec();
 
-        if let Module::Conjuction(conjunction) = modules.get_mut(&conjunction).unwrap() {
-            conjunction.set_inputs(inputs);
+        for conjunction in conjunctions {
+            let inputs = modules
+                .iter()
+                .filter_map(|(id, module)| {
+                    if module.targets().unwrap().contains(&conjunction) {
+                        Some(id.to_owned())
+                    } else {
+                        None
+                    }
+                })
+                .collect_vec();
+
+            if let Module::Conjuction(conjunction) = modules.get_mut(&conjunction).unwrap() {
+                conjunction.set_inputs(inputs);
+            }
         }
-    }
 
-    modules.insert("rx".to_string(), Module::Rx(Rx::new()));
+        modules.insert("rx".to_string(), Module::Rx(Rx::new()));
 
-    Ok(modules)
-}
+        Ok(modules)
+    }
 
-pub fn part1(modules: &ParsedInput) -> color_eyre::Result<usize> {
-    let mut modules = (*modules).clone();
-    let mut sent_freq = Vec::new();
+    fn part1(modules: &ParsedInput) -> color_eyre::Result<usize> {
+        let mut modules = (*modules).clone();
+        let mut sent_freq = Vec::new();
 
-    for press in 0..1000 {
-        push_button(&mut sent_freq, &mut modules, &press);
-    }
+        for press in 0..1000 {
+            push_button(&mut sent_freq, &mut modules, &press);
+        }
 
-    let (low, high): (Vec<Frequency>, Vec<Frequency>) =
-        sent_freq.iter().partition(|&freq| *freq == Frequency::Low);
+        let (low, high): (Vec<Frequency>, Vec<Frequency>) =
+            sent_freq.iter().partition(|&freq| *freq == Frequency::Low);
 
-    Ok(low.len() * high.len())
-}
+        Ok(low.len() * high.len())
+    }
 
-pub fn part2(modules: &ParsedInput) -> color_eyre::Result<usize> {
-    // Thanks to Reddit, we know that the modules are arranged in a graph like this:
-    // https://www.reddit.com/r/adventofcode/comments/18mypla/2023_day_20_input_data_plot/?utm_source=share&utm_medium=web2x&context=3
-    // In my input...
-    /*
-                        rx
-                        ^
-                        |
-                       &zh
-                       ^
-                _ __ __|__ ____
-               |    |    |    |
-              &xc  &th  &pd  &bp
-               ^    ^    ^    ^
-               |    |    |    |
-              &ps  &kh  &mk  &ml
-               ^    ^    ^    ^
-               |    |    |    |
-               %*   %*   %*   %*
-
-        where %* is a series of flip-flops
-    */
-    // Remember that...
-    // Conjunction modules (prefix &) remember the type of the most recent pulse received from each o
-    // their connected input modules; they initially default to remembering a low pulse for each input.
-    // When a pulse is received, the conjunction module first updates its memory for that input. Then,
-    // if it remembers high pulses for all inputs, it sends a low pulse; otherwise, it sends a high pulse.
-    // So...
-    // To get a low-pulse to rx we need a low-pulse from zh
-    // To send a low pulse, zh needs a high pulse from all inputs (xc, th, pd, bp)
-    // To send a high pulse they need to have a low input from at least 1 of their inputs (ps, kh, mk, ml respectively)
-    // To send a low pulse they need to have all high pulses from their inputs
-    // So...
-    // We iterate until each of ps, kh, mk, and ml have all of their inputs registering high - recording
-    // the number of button presses for each one.
-    // Then we sum # of presses for each Flip-Flops connected to those Conjunctions to get the number of
-    // iterations each one needs to send a low pulse
-    // Then we work out the least-common-multiple of those numbers to get the number of presses needed
-    // to align them all together - which would flip zh, sending a low pulse to rx
-
-    let mut modules = (*modules).clone();
-    let mut sent_freq = Vec::new();
-    let mut button_presses = 1u32;
-    let mut stored_sum = vec![("ps", 0), ("kh", 0), ("mk", 0), ("ml", 0)];
-
-    loop {
-        push_button(&mut sent_freq, &mut modules, &button_presses);
-
-        // For the conjunctions we care about, check the ones which haven't stored a sum yet
-        for (id, sum) in stored_sum.iter_mut() {
-            if *sum == 0 {
-                if let Some(Module::Conjuction(c)) = modules.get(*id) {
-                    if c.all_inputs_seen_high() {
-                        *sum = c.sum_input_memory();
+    fn part2(modules: &ParsedInput) -> color_eyre::Result<usize> {
+        // Thanks to Reddit, we know that the modules are arranged in a graph like this:
+        // https://www.reddit.com/r/adventofcode/comments/18mypla/2023_day_20_input_data_plot/?utm_source=share&utm_medium=web2x&context=3
+        // In my input...
+        /*
+                            rx
+                            ^
+                            |
+                           &zh
+                           ^
+                    _ __ __|__ ____
+                   |    |    |    |
+                  &xc  &th  &pd  &bp
+                   ^    ^    ^    ^
+                   |    |    |    |
+                  &ps  &kh  &mk  &ml
+                   ^    ^    ^    ^
+                   |    |    |    |
+                   %*   %*   %*   %*
+
+            where %* is a series of flip-flops
+        */
+        // Remember that...
+        // Conjunction modules (prefix &) remember the type of the most recent pulse received from each o
+        // their connected input modules; they initially default to remembering a low pulse for each input.
+        // When a pulse is received, the conjunction module first updates its memory for that input. Then,
+        // if it remembers high pulses for all inputs, it sends a low pulse; otherwise, it sends a high pulse.
+        // So...
+        // To get a low-pulse to rx we need a low-pulse from zh
+        // To send a low pulse, zh needs a high pulse from all inputs (xc, th, pd, bp)
+        // To send a high pulse they need to have a low input from at least 1 of their inputs (ps, kh, mk, ml respectively)
+        // To send a low pulse they need to have all high pulses from their inputs
+        // So...
+        // We iterate until each of ps, kh, mk, and ml have all of their inputs registering high - recording
+        // the number of button presses for each one.
+        // Then we sum # of presses for each Flip-Flops connected to those Conjunctions to get the number of
+        // iterations each one needs to send a low pulse
+        // Then we work out the least-common-multiple of those numbers to get the number of presses needed
+        // to align them all together - which would flip zh, sending a low pulse to rx
+
+        let mut modules = (*modules).clone();
+        let mut sent_freq = Vec::new();
+        let mut button_presses = 1u32;
+        let mut stored_sum = vec![("ps", 0), ("kh", 0), ("mk", 0), ("ml", 0)];
+
+        loop {
+            push_button(&mut sent_freq, &mut modules, &button_presses);
+
+            // For the conjunctions we care about, check the ones which haven't stored a sum yet
+            for (id, sum) in stored_sum.iter_mut() {
+                if *sum == 0 {
+                    if let Some(Module::Conjuction(c)) = modules.get(*id) {
+                        if c.all_inputs_seen_high() {
+                            *sum = c.sum_input_memory();
+                        }
                     }
                 }
             }
-        }
 
-        if stored_sum.iter().all(|(_, sum)| *sum != 0) {
-            break;
-        }
+            if stored_sum.iter().all(|(_, sum)| *sum != 0) {
+                break;
+            }
 
-        button_presses += 1;
-    }
+            button_presses += 1;
+        }
 
-    let coal = stored_sum
-        .into_iter()
-        .map(|(_, lcm)| lcm)
-        .coalesce(|prev, cur| Ok(lcm(prev, cur)))
-        .collect_vec();
+        let coal = stored_sum
+            .into_iter()
+            .map(|(_, lcm)| lcm)
+            .coalesce(|prev, cur| Ok(lcm(prev, cur)))
+            .collect_vec();
 
-    Ok(coal[0])
+        Ok(coal[0])
+    }
 }
 
 fn push_button(
@@ -1,15 +1,27 @@
+use crate::solver::Solver;
+
 type ParsedInput = Map;
 
-pub fn parse(_input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(Map::new())
-}
+pub struct Solution;
 
-pub fn part1(_: &ParsedInput) -> color_eyre::Result<u32> {
-    Ok(0)
-}
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-pub fn part2(_: &ParsedInput) -> color_eyre::Result<u32> {
-    Ok(0)
+    const WIP: bool = true;
+
+    fn parse(_input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(Map::new())
+    }
+
+    fn part1(_: &ParsedInput) -> color_eyre::Result<u32> {
+        Ok(0)
+    }
+
+    fn part2(_: &ParsedInput) -> color_eyre::Result<u32> {
+        Ok(0)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -2,118 +2,126 @@ use std::{collections::HashSet, ops::Range};
 
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Vec<Brick>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut bricks: Vec<Brick> = input.lines().map(Brick::try_from).try_collect()?;
-    bricks.sort_unstable_by(Brick::compare_z);
+pub struct Solution;
 
-    for (i, brick) in bricks.iter_mut().enumerate() {
-        brick.id = i as u32 + 1;
-    }
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    // Simulate the bricks falling
-    for i in 0..bricks.len() {
-        let mut min_z = 1;
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut bricks: Vec<Brick> = input.lines().map(Brick::try_from).try_collect()?;
+        bricks.sort_unstable_by(Brick::compare_z);
 
-        for j in 0..i {
-            if bricks[i].overlaps(&bricks[j]) {
-                min_z = min_z.max(bricks[j].max_z() + 1);
-            }
+        for (i, brick) in bricks.iter_mut().enumerate() {
+            brick.id = i as u32 + 1;
         }
 
-        let height = bricks[i].height();
-        bricks[i].z = min_z..min_z + height;
-    }
-
-    // Collect all bricks directly above/below
-    for brick_idx in 0..bricks.len() {
-        let brick_id = bricks[brick_idx].id;
+        // Simulate the bricks falling
+        for i in 0..bricks.len() {
+            let mut min_z = 1;
 
-        for other_idx in 0..brick_idx {
-            let other_id = bricks[other_idx].id;
-
-            if bricks[brick_idx].overlaps(&bricks[other_idx])
-                && bricks[brick_idx].min_z() == bricks[other_idx].max_z() + 1
-            {
-                bricks[brick_idx].add_below(other_id);
-                bricks[other_idx].add_above(brick_id);
+            for j in 0..i {
+                if bricks[i].overlaps(&bricks[j]) {
+                    min_z = min_z.max(bricks[j].max_z() + 1);
+                }
             }
+
+            let height = bricks[i].height();
+            bricks[i].z = min_z..min_z + height;
         }
-    }
 
-    Ok(bricks)
-}
+        // Collect all bricks directly above/below
+        for brick_idx in 0..bricks.len() {
+            let brick_id = bricks[brick_idx].id;
 
-pub fn part1(bricks: &ParsedInput) -> color_eyre::Result<usize> {
-    // Critical bricks are those which are the only one supporting a higher brick
-    let critical_bricks: HashSet<u32> = bricks
-        .iter()
-        .filter_map(|brick| {
-            if brick.below.len() == 1 {
-                Some(brick.below[0])
-            } else {
-                None
+            for other_idx in 0..brick_idx {
+                let other_id = bricks[other_idx].id;
+
+                if bricks[brick_idx].overlaps(&bricks[other_idx])
+                    && bricks[brick_idx].min_z() == bricks[other_idx].max_z() + 1
+                {
+                    bricks[brick_idx].add_below(other_id);
+                    bricks[other_idx].add_above(brick_id);
+                }
             }
-        })
-        .collect();
+        }
 
-    // Every other brick can be disintegrated
-    let removable_bricks = bricks.len() - critical_bricks.len();
+        Ok(bricks)
+    }
 
-    Ok(removable_bricks)
-}
+    fn part1(bricks: &ParsedInput) -> color_eyre::Result<usize> {
+        // Critical bricks are those which are the only one supporting a higher brick
+        let critical_bricks: HashSet<u32> = bricks
+            .iter()
+            .filter_map(|brick| {
+                if brick.below.len() == 1 {
+                    Some(brick.below[0])
+                } else {
+                    None
+                }
+            })
+            .collect();
 
-pub fn part2(bricks: &ParsedInput) -> color_eyre::Result<usize> {
-    let critical_bricks: HashSet<u32> = bricks
-        .iter()
-        .filter_map(|brick| {
-            if brick.below.len() == 1 {
-                Some(brick.below[0])
-            } else {
-                None
-            }
-        })
-        .collect();
+        // Every other brick can be disintegrated
+        let removable_bricks = bricks.len() - critical_bricks.len();
+
+        Ok(removable_bricks)
+    }
 
-    let mut todo = Vec::new();
-    let mut removed_bricks = HashSet::<u32>::new();
-    let mut total = 0;
+    fn part2(bricks: &ParsedInput) -> color_eyre::Result<usize> {
+        let critical_bricks: HashSet<u32> = bricks
+            .iter()
+            .filter_map(|brick| {
+                if brick.below.len() == 1 {
+                    Some(brick.below[0])
+                } else {
+                    None
+                }
+            })
+            .collect();
 
-    for brick_id in critical_bricks {
-        todo.push(brick_id);
-        removed_bricks.insert(brick_id);
+        let mut todo = Vec::new();
+        let mut removed_bricks = HashSet::<u32>::new();
+        let mut total = 0;
 
-        while let Some(current) = todo.pop() {
-            let brick = bricks
-                .iter()
-                .find(|brick| brick.id == current)
-                .ok_or(AdventError::NotFound(format!("{}", brick_id)))?;
+        for brick_id in critical_bricks {
+            todo.push(brick_id);
+            removed_bricks.insert(brick_id);
 
-            for above_id in &brick.above {
-                let above_brick = bricks
+            while let Some(current) = todo.pop() {
+                let brick = bricks
                     .iter()
-                    .find(|brick| brick.id == *above_id)
+                    .find(|brick| brick.id == current)
                     .ok_or(AdventError::NotFound(format!("{}", brick_id)))?;
 
-                if above_brick
-                    .below
-                    .iter()
-                    .all(|below_id| removed_bricks.contains(below_id))
-                {
-                    todo.push(*above_id);
-                    removed_bricks.insert(*above_id);
+                for above_id in &brick.above {
+                    let above_brick = bricks
+                        .iter()
+                        .find(|brick| brick.id == *above_id)
+                        .ok_or(AdventError::NotFound(format!("{}", brick_id)))?;
+
+                    if above_brick
+                        .below
+                        .iter()
+                        .all(|below_id| removed_bricks.contains(below_id))
+                    {
+                        todo.push(*above_id);
+                        removed_bricks.insert(*above_id);
+                    }
                 }
             }
+
+            total += removed_bricks.len() - 1;
+            removed_bricks.clear();
         }
 
-        total += removed_bricks.len() - 1;
-        removed_bricks.clear();
+        Ok(total)
     }
-
-    Ok(total)
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use grid::grid;
 use itertools::Itertools;
@@ -7,166 +7,182 @@ use strum::IntoEnumIterator;
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{direction::Direction, grid::Grid, point::Point},
 };
 
 type ParsedInput = Grid<Tile>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut grid = grid![];
+pub struct Solution;
 
-    for line in input.lines() {
-        grid.push_row(line.chars().map(Tile::try_from).try_collect()?);
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // Part 2 searches every path through the maze for the longest, which is given up on quickly
+    // unless --timeout allows longer
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    const WIP: bool = true;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut grid = grid![];
+
+        for line in input.lines() {
+            grid.push_row(line.chars().map(Tile::try_from).try_collect()?);
+        }
+
+        Ok(Grid::from(grid))
     }
 
-    Ok(Grid::from(grid))
-}
+    fn part1(grid: &ParsedInput) -> color_eyre::Result<usize> {
+        let start = grid
+            .iter_row(0)
+            .enumerate()
+            .find(|(_, tile)| Tile::is_path(tile))
+            .map(|(col, _)| (0usize, col))
+            .ok_or(AdventError::NotFound("Path".to_string()))?;
+
+        let last_row = grid.rows() - 1;
+        let end = grid
+            .iter_row(last_row)
+            .enumerate()
+            .find(|(_, tile)| Tile::is_path(tile))
+            .map(|(col, _)| (last_row, col))
+            .ok_or(AdventError::NotFound("Path".to_string()))?;
+
+        if let Some(paths) = find_paths(
+            grid,
+            Point::from(start),
+            Direction::South,
+            Point::from(end),
+            true,
+        ) {
+            // if paths.is_empty() {
+            //     println!("Failed to find any path to the end");
+            // }
+
+            // for path in &paths {
+            //     println!("Len = {}", path.len() - 1);
+            //     //println!("{path:?}");
+            // }
+
+            let longest_path = paths
+                .iter()
+                .max_by(|p1, p2| p1.len().cmp(&p2.len()))
+                .unwrap();
+            //println!("Longest path = {}", longest_path.len() - 1);
 
-pub fn part1(grid: &ParsedInput) -> color_eyre::Result<usize> {
-    let start = grid
-        .iter_row(0)
-        .enumerate()
-        .find(|(_, tile)| Tile::is_path(tile))
-        .map(|(col, _)| (0usize, col))
-        .ok_or(AdventError::NotFound("Path".to_string()))?;
-
-    let last_row = grid.rows() - 1;
-    let end = grid
-        .iter_row(last_row)
-        .enumerate()
-        .find(|(_, tile)| Tile::is_path(tile))
-        .map(|(col, _)| (last_row, col))
-        .ok_or(AdventError::NotFound("Path".to_string()))?;
-
-    if let Some(paths) = find_paths(
-        grid,
-        Point::from(start),
-        Direction::South,
-        Point::from(end),
-        true,
-    ) {
-        // if paths.is_empty() {
-        //     println!("Failed to find any path to the end");
-        // }
-
-        // for path in &paths {
-        //     println!("Len = {}", path.len() - 1);
-        //     //println!("{path:?}");
-        // }
-
-        let longest_path = paths
-            .iter()
-            .max_by(|p1, p2| p1.len().cmp(&p2.len()))
-            .unwrap();
-        //println!("Longest path = {}", longest_path.len() - 1);
-
-        Ok(longest_path.len() - 1)
-    } else {
-        Err(AdventError::NotFound("Path".into()).into())
+            Ok(longest_path.len() - 1)
+        } else {
+            Err(AdventError::NotFound("Path".into()).into())
+        }
     }
-}
 
-pub fn part2(grid: &ParsedInput) -> color_eyre::Result<usize> {
-    // Running part 1 code doesn't work, it blows the stack.
-    // Need to first create a graph of junction nodes so can search that instead
-
-    let start: Point = grid
-        .iter_row(0)
-        .enumerate()
-        .find(|(_, tile)| Tile::is_path(tile))
-        .map(|(col, _)| (0usize, col))
-        .ok_or(AdventError::NotFound("Path".to_string()))?
-        .into();
-
-    let last_row = grid.rows() - 1;
-    let end: Point = grid
-        .iter_row(last_row)
-        .enumerate()
-        .find(|(_, tile)| Tile::is_path(tile))
-        .map(|(col, _)| (last_row, col))
-        .ok_or(AdventError::NotFound("Path".to_string()))?
-        .into();
-
-    let get_cardinal_neighbours = |p: &Point| -> Vec<(Point, usize)> {
-        Direction::iter()
-            .filter_map(|dir| {
-                if let Some((next_pos, next_tile)) = grid.get_in_direction_indexed((*p).into(), dir)
-                {
-                    if !matches!(next_tile, Tile::Forest) {
-                        Some((next_pos.into(), 1))
+    fn part2(grid: &ParsedInput) -> color_eyre::Result<usize> {
+        // Running part 1 code doesn't work, it blows the stack.
+        // Need to first create a graph of junction nodes so can search that instead
+
+        let start: Point = grid
+            .iter_row(0)
+            .enumerate()
+            .find(|(_, tile)| Tile::is_path(tile))
+            .map(|(col, _)| (0usize, col))
+            .ok_or(AdventError::NotFound("Path".to_string()))?
+            .into();
+
+        let last_row = grid.rows() - 1;
+        let end: Point = grid
+            .iter_row(last_row)
+            .enumerate()
+            .find(|(_, tile)| Tile::is_path(tile))
+            .map(|(col, _)| (last_row, col))
+            .ok_or(AdventError::NotFound("Path".to_string()))?
+            .into();
+
+        let get_cardinal_neighbours = |p: &Point| -> Vec<(Point, usize)> {
+            Direction::iter()
+                .filter_map(|dir| {
+                    if let Some((next_pos, next_tile)) = grid.get_in_direction_indexed(*p, dir) {
+                        if !matches!(next_tile, Tile::Forest) {
+                            Some((next_pos.into(), 1))
+                        } else {
+                            None
+                        }
                     } else {
                         None
                     }
-                } else {
-                    None
-                }
-            })
-            .collect_vec()
-    };
-
-    let mut junctions = vec![start];
-
-    junctions.append(
-        &mut grid
-            .indexed_iter()
-            .filter(|(_, tile)| Tile::is_path(tile))
-            .filter_map(|((row, col), _)| {
-                let available_moves = get_cardinal_neighbours(&Point::from((row, col))).len();
-
-                if available_moves >= 3 {
-                    Some(Point::from((row, col)))
-                } else {
-                    None
-                }
-            })
-            .collect_vec(),
-    );
+                })
+                .collect_vec()
+        };
 
-    junctions.push(end);
+        let mut junctions = vec![start];
 
-    println!("{junctions:?}");
+        junctions.append(
+            &mut grid
+                .indexed_iter()
+                .filter(|(_, tile)| Tile::is_path(tile))
+                .filter_map(|((row, col), _)| {
+                    let available_moves = get_cardinal_neighbours(&Point::from((row, col))).len();
 
-    let mut links = HashMap::new();
+                    if available_moves >= 3 {
+                        Some(Point::from((row, col)))
+                    } else {
+                        None
+                    }
+                })
+                .collect_vec(),
+        );
 
-    for (start, end) in junctions.iter().tuple_combinations() {
-        let success = |p: &Point| -> bool { p == end };
+        junctions.push(end);
 
-        if links
-            .get(start)
-            .is_some_and(|linked_to: &Vec<(Point, usize)>| linked_to.iter().any(|(p, _)| p == end))
-        {
-            continue;
-        }
+        log::debug!("{junctions:?}");
 
-        // Don't let us go through another junction to find this one
-        let successors = |p: &Point| -> Vec<(Point, usize)> {
-            get_cardinal_neighbours(p)
-                .iter()
-                .filter(|&next| next.0 == *end || !junctions.contains(&next.0))
-                .cloned()
-                .collect_vec()
-        };
+        let mut links = HashMap::new();
 
-        if let Some((_, steps)) = dijkstra(start, successors, success) {
-            links
-                .entry(start)
-                .and_modify(|v: &mut Vec<(Point, usize)>| {
-                    v.push((*end, steps));
-                })
-                .or_insert(vec![(*end, steps)]);
+        for (start, end) in junctions.iter().tuple_combinations() {
+            let success = |p: &Point| -> bool { p == end };
 
-            links
-                .entry(end)
-                .and_modify(|v: &mut Vec<(Point, usize)>| {
-                    v.push((*start, steps));
+            if links
+                .get(start)
+                .is_some_and(|linked_to: &Vec<(Point, usize)>| {
+                    linked_to.iter().any(|(p, _)| p == end)
                 })
-                .or_insert(vec![(*start, steps)]);
+            {
+                continue;
+            }
+
+            // Don't let us go through another junction to find this one
+            let successors = |p: &Point| -> Vec<(Point, usize)> {
+                get_cardinal_neighbours(p)
+                    .iter()
+                    .filter(|&next| next.0 == *end || !junctions.contains(&next.0))
+                    .cloned()
+                    .collect_vec()
+            };
+
+            if let Some((_, steps)) = dijkstra(start, successors, success) {
+                links
+                    .entry(start)
+                    .and_modify(|v: &mut Vec<(Point, usize)>| {
+                        v.push((*end, steps));
+                    })
+                    .or_insert(vec![(*end, steps)]);
+
+                links
+                    .entry(end)
+                    .and_modify(|v: &mut Vec<(Point, usize)>| {
+                        v.push((*start, steps));
+                    })
+                    .or_insert(vec![(*start, steps)]);
+            }
         }
-    }
 
-    //println!("{links:#?}");
+        //println!("{links:#?}");
 
-    Ok(0)
+        Ok(0)
+    }
 }
 
 pub fn find_paths(
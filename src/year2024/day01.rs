@@ -1,44 +1,52 @@
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = (Vec<usize>, Vec<usize>);
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut list1 = Vec::new();
-    let mut list2 = Vec::new();
+pub struct Solution;
 
-    for line in input.lines() {
-        let mut splits = line.split_whitespace();
-        let v1 = splits.next().ok_or(AdventError::EndOfIterator)?.parse()?;
-        list1.push(v1);
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-        let v2 = splits.next().ok_or(AdventError::EndOfIterator)?.parse()?;
-        list2.push(v2);
-    }
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut list1 = Vec::new();
+        let mut list2 = Vec::new();
 
-    Ok((list1, list2))
-}
+        for line in input.lines() {
+            let mut splits = line.split_whitespace();
+            let v1 = splits.next().ok_or(AdventError::EndOfIterator)?.parse()?;
+            list1.push(v1);
 
-pub fn part1((list1, list2): &ParsedInput) -> color_eyre::Result<usize> {
-    let mut list1 = list1.clone();
-    list1.sort();
+            let v2 = splits.next().ok_or(AdventError::EndOfIterator)?.parse()?;
+            list2.push(v2);
+        }
 
-    let mut list2 = list2.clone();
-    list2.sort();
+        Ok((list1, list2))
+    }
 
-    Ok(list1
-        .iter()
-        .zip(list2.iter())
-        .map(|(v1, v2)| v1.abs_diff(*v2))
-        .sum())
-}
+    fn part1((list1, list2): &ParsedInput) -> color_eyre::Result<usize> {
+        let mut list1 = list1.clone();
+        list1.sort();
 
-pub fn part2((list1, list2): &ParsedInput) -> color_eyre::Result<usize> {
-    Ok(list1
-        .iter()
-        .map(|v1| {
-            let occ = list2.iter().filter(|&v2| v1 == v2).count();
+        let mut list2 = list2.clone();
+        list2.sort();
 
-            v1 * occ
-        })
-        .sum())
+        Ok(list1
+            .iter()
+            .zip(list2.iter())
+            .map(|(v1, v2)| v1.abs_diff(*v2))
+            .sum())
+    }
+
+    fn part2((list1, list2): &ParsedInput) -> color_eyre::Result<usize> {
+        Ok(list1
+            .iter()
+            .map(|v1| {
+                let occ = list2.iter().filter(|&v2| v1 == v2).count();
+
+                v1 * occ
+            })
+            .sum())
+    }
 }
@@ -1,61 +1,72 @@
 use strum::IntoEnumIterator;
 
-use crate::util::{direction::DirectionEx, grid::Grid};
+use crate::{
+    solver::Solver,
+    util::{direction::DirectionEx, grid::Grid},
+};
 
 type ParsedInput = Grid<char>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input.try_into()?)
-}
+pub struct Solution;
 
-pub fn part1(wordsearch: &ParsedInput) -> color_eyre::Result<u32> {
-    let mut xmas_count = 0;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-    for (x_loc, x) in wordsearch.indexed_iter() {
-        if *x != 'X' {
-            continue;
-        }
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input.try_into()?)
+    }
+
+    fn part1(wordsearch: &ParsedInput) -> color_eyre::Result<u32> {
+        let mut xmas_count = 0;
 
-        for dir in DirectionEx::iter() {
-            if is_xmas(x_loc, dir, wordsearch) {
-                xmas_count += 1;
+        for (x_loc, x) in wordsearch.indexed_iter() {
+            if *x != 'X' {
+                continue;
+            }
+
+            for dir in DirectionEx::iter() {
+                if is_xmas(x_loc, dir, wordsearch) {
+                    xmas_count += 1;
+                }
             }
         }
+
+        Ok(xmas_count)
     }
 
-    Ok(xmas_count)
-}
+    fn part2(wordsearch: &ParsedInput) -> color_eyre::Result<u32> {
+        let mut x_mas_count = 0;
 
-pub fn part2(wordsearch: &ParsedInput) -> color_eyre::Result<u32> {
-    let mut x_mas_count = 0;
+        // Just X shapes and not + shapes
+        let corner_dirs = [
+            DirectionEx::NorthEast,
+            DirectionEx::SouthEast,
+            DirectionEx::SouthWest,
+            DirectionEx::NorthWest,
+        ];
 
-    // Just X shapes and not + shapes
-    let corner_dirs = [
-        DirectionEx::NorthEast,
-        DirectionEx::SouthEast,
-        DirectionEx::SouthWest,
-        DirectionEx::NorthWest,
-    ];
+        for (a_loc, a) in wordsearch.indexed_iter() {
+            if *a != 'A' {
+                continue;
+            }
 
-    for (a_loc, a) in wordsearch.indexed_iter() {
-        if *a != 'A' {
-            continue;
-        }
+            for dir in corner_dirs {
+                if is_mas(a_loc, dir, wordsearch) {
+                    if is_mas(a_loc, dir.rotate_90_cwise(), wordsearch)
+                        || is_mas(a_loc, dir.rotate_90_c_cwise(), wordsearch)
+                    {
+                        x_mas_count += 1;
+                    }
 
-        for dir in corner_dirs {
-            if is_mas(a_loc, dir, wordsearch) {
-                if is_mas(a_loc, dir.rotate_90_cwise(), wordsearch)
-                    || is_mas(a_loc, dir.rotate_90_c_cwise(), wordsearch)
-                {
-                    x_mas_count += 1;
+                    break;
                 }
-
-                break;
             }
         }
-    }
 
-    Ok(x_mas_count)
+        Ok(x_mas_count)
+    }
 }
 
 fn is_xmas(x_loc: (usize, usize), dir: DirectionEx, wordsearch: &ParsedInput) -> bool {
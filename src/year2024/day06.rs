@@ -2,47 +2,56 @@ use std::collections::HashSet;
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{direction::Direction, grid::Grid},
 };
 
 type ParsedInput = Grid<char>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input.try_into()?)
-}
-
-pub fn part1(map: &ParsedInput) -> color_eyre::Result<usize> {
-    let visited = find_distinct_path(map)?;
-    Ok(visited.len())
-}
+pub struct Solution;
 
-pub fn part2(map: &ParsedInput) -> color_eyre::Result<u32> {
-    let mut cur_dir = Direction::North;
-    let mut cur_pos = get_start_pos(map)?;
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = u32;
 
-    let mut visited = HashSet::new();
-    visited.insert((cur_pos, cur_dir));
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input.try_into()?)
+    }
 
-    let mut obstacles = HashSet::new();
-    let mut loops = 0;
+    fn part1(map: &ParsedInput) -> color_eyre::Result<usize> {
+        let visited = find_distinct_path(map)?;
+        Ok(visited.len())
+    }
 
-    while let Some((next_pos, next_val)) = map.get_in_direction_indexed(cur_pos, cur_dir) {
-        if *next_val == '#' {
-            cur_dir = cur_dir.rotate_90_cwise();
-        } else {
-            // Only test obstacles once as hitting it later in the path a 2nd time shouldn't count
-            if obstacles.insert(next_pos)
-                && does_path_loop(&cur_pos, &cur_dir, &visited, map, next_pos)?
-            {
-                loops += 1;
+    fn part2(map: &ParsedInput) -> color_eyre::Result<u32> {
+        let mut cur_dir = Direction::North;
+        let mut cur_pos = get_start_pos(map)?;
+
+        let mut visited = HashSet::new();
+        visited.insert((cur_pos, cur_dir));
+
+        let mut obstacles = HashSet::new();
+        let mut loops = 0;
+
+        while let Some((next_pos, next_val)) = map.get_in_direction_indexed(cur_pos, cur_dir) {
+            if *next_val == '#' {
+                cur_dir = cur_dir.rotate_90_cwise();
+            } else {
+                // Only test obstacles once as hitting it later in the path a 2nd time shouldn't count
+                if obstacles.insert(next_pos)
+                    && does_path_loop(&cur_pos, &cur_dir, &visited, map, next_pos)?
+                {
+                    loops += 1;
+                }
+
+                cur_pos = next_pos;
+                visited.insert((cur_pos, cur_dir));
             }
-
-            cur_pos = next_pos;
-            visited.insert((cur_pos, cur_dir));
         }
-    }
 
-    Ok(loops)
+        Ok(loops)
+    }
 }
 
 fn get_start_pos(map: &ParsedInput) -> color_eyre::Result<(usize, usize)> {
@@ -1,31 +1,39 @@
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Vec<Equation>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input.lines().map(Equation::try_from).try_collect()?)
-}
+pub struct Solution;
 
-pub fn part1(equations: &ParsedInput) -> color_eyre::Result<u128> {
-    let answer = equations
-        .iter()
-        .filter(|eq| is_solvable(eq.target, &eq.numbers, false))
-        .map(|eq| eq.target)
-        .sum();
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u128;
+    type Answer2 = u128;
 
-    Ok(answer)
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input.lines().map(Equation::try_from).try_collect()?)
+    }
 
-pub fn part2(equations: &ParsedInput) -> color_eyre::Result<u128> {
-    let answer = equations
-        .iter()
-        .filter(|eq| is_solvable(eq.target, &eq.numbers, true))
-        .map(|eq| eq.target)
-        .sum();
+    fn part1(equations: &ParsedInput) -> color_eyre::Result<u128> {
+        let answer = equations
+            .iter()
+            .filter(|eq| is_solvable(eq.target, &eq.numbers, false))
+            .map(|eq| eq.target)
+            .sum();
 
-    Ok(answer)
+        Ok(answer)
+    }
+
+    fn part2(equations: &ParsedInput) -> color_eyre::Result<u128> {
+        let answer = equations
+            .iter()
+            .filter(|eq| is_solvable(eq.target, &eq.numbers, true))
+            .map(|eq| eq.target)
+            .sum();
+
+        Ok(answer)
+    }
 }
 
 fn is_solvable(target: u128, numbers: &[u128], test_concat: bool) -> bool {
@@ -1,192 +1,200 @@
 use std::fmt::Debug;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Vec<File>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut files = Vec::new();
-    let mut file_id = 0;
-    let mut is_file = true;
-
-    for ch in input.chars() {
-        // Ignores the new-line
-        if let Some(num) = ch.to_digit(10) {
-            if is_file {
-                files.push(File {
-                    id: Some(file_id),
-                    size: num,
-                });
-
-                file_id += 1;
-            } else {
-                files.push(File {
-                    id: None,
-                    size: num,
-                });
-            }
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut files = Vec::new();
+        let mut file_id = 0;
+        let mut is_file = true;
+
+        for ch in input.chars() {
+            // Ignores the new-line
+            if let Some(num) = ch.to_digit(10) {
+                if is_file {
+                    files.push(File {
+                        id: Some(file_id),
+                        size: num,
+                    });
+
+                    file_id += 1;
+                } else {
+                    files.push(File {
+                        id: None,
+                        size: num,
+                    });
+                }
 
-            is_file = !is_file;
+                is_file = !is_file;
+            }
         }
+
+        Ok(files)
     }
 
-    Ok(files)
-}
+    fn part1(disk_map: &ParsedInput) -> color_eyre::Result<u128> {
+        let mut new_disk_map = Vec::new();
 
-pub fn part1(disk_map: &ParsedInput) -> color_eyre::Result<u128> {
-    let mut new_disk_map = Vec::new();
+        let mut rev_iter = disk_map.iter().rev().enumerate();
+        let mut end_opt = rev_iter.next();
+        let mut end_file_rem = None;
 
-    let mut rev_iter = disk_map.iter().rev().enumerate();
-    let mut end_opt = rev_iter.next();
-    let mut end_file_rem = None;
+        let mut space_to_fill: u32 = disk_map
+            .iter()
+            .filter_map(|file| {
+                if file.id.is_none() {
+                    Some(file.size)
+                } else {
+                    None
+                }
+            })
+            .sum();
 
-    let mut space_to_fill: u32 = disk_map
-        .iter()
-        .filter_map(|file| {
-            if file.id.is_none() {
-                Some(file.size)
+        let mut file_sizes = 0;
+        for file in disk_map.iter().rev() {
+            if file.id.is_some() {
+                file_sizes += file.size;
             } else {
-                None
+                space_to_fill -= file.size;
             }
-        })
-        .sum();
-
-    let mut file_sizes = 0;
-    for file in disk_map.iter().rev() {
-        if file.id.is_some() {
-            file_sizes += file.size;
-        } else {
-            space_to_fill -= file.size;
-        }
-
-        if file_sizes >= space_to_fill {
-            break;
-        }
-    }
 
-    let mut space_filled = 0;
-
-    for file in disk_map.iter() {
-        if file.id.is_some() {
-            new_disk_map.push(file.clone());
-            continue;
+            if file_sizes >= space_to_fill {
+                break;
+            }
         }
 
-        let mut space_size = file.size;
+        let mut space_filled = 0;
 
-        loop {
-            let (_, end_file) =
-                end_opt.ok_or(AdventError::LogicError("Hit end of rev iter".to_string()))?;
-
-            if end_file.id.is_none() {
-                end_opt = rev_iter.next();
+        for file in disk_map.iter() {
+            if file.id.is_some() {
+                new_disk_map.push(file.clone());
                 continue;
             }
 
-            let end_file_size = end_file_rem.unwrap_or(end_file.size);
+            let mut space_size = file.size;
 
-            match end_file_size.cmp(&space_size) {
-                std::cmp::Ordering::Less => {
-                    let new_file = File {
-                        id: end_file.id,
-                        size: end_file_size,
-                    };
-                    new_disk_map.push(new_file);
-                    end_file_rem = None;
-                    end_opt = rev_iter.next();
-                    space_filled += end_file_size;
+            loop {
+                let (_, end_file) =
+                    end_opt.ok_or(AdventError::LogicError("Hit end of rev iter".to_string()))?;
 
-                    space_size -= end_file_size;
-                }
-                std::cmp::Ordering::Equal => {
-                    let new_file = File {
-                        id: end_file.id,
-                        size: end_file_size,
-                    };
-                    new_disk_map.push(new_file);
-                    end_file_rem = None;
+                if end_file.id.is_none() {
                     end_opt = rev_iter.next();
-                    space_filled += end_file_size;
-
-                    break;
+                    continue;
                 }
-                std::cmp::Ordering::Greater => {
+
+                let end_file_size = end_file_rem.unwrap_or(end_file.size);
+
+                match end_file_size.cmp(&space_size) {
+                    std::cmp::Ordering::Less => {
+                        let new_file = File {
+                            id: end_file.id,
+                            size: end_file_size,
+                        };
+                        new_disk_map.push(new_file);
+                        end_file_rem = None;
+                        end_opt = rev_iter.next();
+                        space_filled += end_file_size;
+
+                        space_size -= end_file_size;
+                    }
+                    std::cmp::Ordering::Equal => {
+                        let new_file = File {
+                            id: end_file.id,
+                            size: end_file_size,
+                        };
+                        new_disk_map.push(new_file);
+                        end_file_rem = None;
+                        end_opt = rev_iter.next();
+                        space_filled += end_file_size;
+
+                        break;
+                    }
+                    std::cmp::Ordering::Greater => {
+                        let new_file = File {
+                            id: end_file.id,
+                            size: space_size,
+                        };
+                        new_disk_map.push(new_file);
+                        end_file_rem = Some(end_file_size - space_size);
+
+                        space_filled += space_size;
+
+                        break;
+                    }
+                };
+            }
+
+            if space_filled >= space_to_fill {
+                if end_file_rem.is_some_and(|n| n > 0) {
+                    let (_, end_file) = end_opt
+                        .ok_or(AdventError::LogicError("Hit end of rev iter".to_string()))?;
+
                     let new_file = File {
                         id: end_file.id,
-                        size: space_size,
+                        size: end_file_rem.unwrap(),
                     };
                     new_disk_map.push(new_file);
-                    end_file_rem = Some(end_file_size - space_size);
-
-                    space_filled += space_size;
-
-                    break;
                 }
-            };
-        }
 
-        if space_filled >= space_to_fill {
-            if end_file_rem.is_some_and(|n| n > 0) {
-                let (_, end_file) =
-                    end_opt.ok_or(AdventError::LogicError("Hit end of rev iter".to_string()))?;
-
-                let new_file = File {
-                    id: end_file.id,
-                    size: end_file_rem.unwrap(),
-                };
-                new_disk_map.push(new_file);
+                break;
             }
-
-            break;
         }
-    }
-
-    Ok(calc_checksum(new_disk_map))
-}
 
-pub fn part2(disk_map: &ParsedInput) -> color_eyre::Result<u128> {
-    let mut new_disk_map = disk_map.clone();
+        Ok(calc_checksum(new_disk_map))
+    }
 
-    for (rev_idx, file_to_move) in disk_map.iter().rev().enumerate() {
-        if file_to_move.id.is_none() {
-            continue;
-        }
+    fn part2(disk_map: &ParsedInput) -> color_eyre::Result<u128> {
+        let mut new_disk_map = disk_map.clone();
 
-        // Used to make sure the space we've found is before the item we are processing
-        let fwd_idx = (new_disk_map.len() - 1) - rev_idx;
-
-        if let Some((space_idx, space)) =
-            new_disk_map
-                .iter_mut()
-                .enumerate()
-                .find(|(space_idx, file)| {
-                    *space_idx <= fwd_idx && file.id.is_none() && file.size >= file_to_move.size
-                })
-        {
-            let rem_space = space.size - file_to_move.size;
-            *space = file_to_move.clone();
-
-            if rem_space > 0 {
-                new_disk_map.insert(
-                    space_idx + 1,
-                    File {
-                        id: None,
-                        size: rem_space,
-                    },
-                );
+        for (rev_idx, file_to_move) in disk_map.iter().rev().enumerate() {
+            if file_to_move.id.is_none() {
+                continue;
             }
 
-            if let Some(moved) = new_disk_map
-                .iter_mut()
-                .rev()
-                .find(|file| file.id == file_to_move.id)
+            // Used to make sure the space we've found is before the item we are processing
+            let fwd_idx = (new_disk_map.len() - 1) - rev_idx;
+
+            if let Some((space_idx, space)) =
+                new_disk_map
+                    .iter_mut()
+                    .enumerate()
+                    .find(|(space_idx, file)| {
+                        *space_idx <= fwd_idx && file.id.is_none() && file.size >= file_to_move.size
+                    })
             {
-                moved.id = None;
+                let rem_space = space.size - file_to_move.size;
+                *space = file_to_move.clone();
+
+                if rem_space > 0 {
+                    new_disk_map.insert(
+                        space_idx + 1,
+                        File {
+                            id: None,
+                            size: rem_space,
+                        },
+                    );
+                }
+
+                if let Some(moved) = new_disk_map
+                    .iter_mut()
+                    .rev()
+                    .find(|file| file.id == file_to_move.id)
+                {
+                    moved.id = None;
+                }
             }
         }
-    }
 
-    Ok(calc_checksum(new_disk_map))
+        Ok(calc_checksum(new_disk_map))
+    }
 }
 
 fn calc_checksum(new_disk_map: Vec<File>) -> u128 {
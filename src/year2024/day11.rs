@@ -2,25 +2,35 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
+use crate::solver::Solver;
+
 type ParsedInput = HashMap<u128, u128>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input
-        .split_whitespace()
-        .map(|s| s.parse::<u128>())
-        .map(|r| match r {
-            Ok(stone) => Ok((stone, 1)),
-            Err(e) => Err(e),
-        })
-        .try_collect()?)
-}
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input
+            .split_whitespace()
+            .map(|s| s.parse::<u128>())
+            .map(|r| match r {
+                Ok(stone) => Ok((stone, 1)),
+                Err(e) => Err(e),
+            })
+            .try_collect()?)
+    }
 
-pub fn part1(stones: &ParsedInput) -> color_eyre::Result<u128> {
-    Ok(blink(stones, 25))
-}
+    fn part1(stones: &ParsedInput) -> color_eyre::Result<u128> {
+        Ok(blink(stones, 25))
+    }
 
-pub fn part2(stones: &ParsedInput) -> color_eyre::Result<u128> {
-    Ok(blink(stones, 75))
+    fn part2(stones: &ParsedInput) -> color_eyre::Result<u128> {
+        Ok(blink(stones, 75))
+    }
 }
 
 fn blink(stones: &ParsedInput, num_blinks: u32) -> u128 {
@@ -2,38 +2,47 @@ use std::collections::HashSet;
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{direction::Direction, grid::Grid},
 };
 
 type ParsedInput = Grid<char>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input.try_into()?)
-}
+pub struct Solution;
 
-pub fn part1(map: &ParsedInput) -> color_eyre::Result<usize> {
-    let regions = build_regions(map);
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    Ok(regions
-        .iter()
-        .map(|region| region.points.len() * region.perimeter)
-        .sum())
-}
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input.try_into()?)
+    }
+
+    fn part1(map: &ParsedInput) -> color_eyre::Result<usize> {
+        let regions = build_regions(map);
+
+        Ok(regions
+            .iter()
+            .map(|region| region.points.len() * region.perimeter)
+            .sum())
+    }
 
-pub fn part2(map: &ParsedInput) -> color_eyre::Result<usize> {
-    let regions = build_regions(map);
+    fn part2(map: &ParsedInput) -> color_eyre::Result<usize> {
+        let regions = build_regions(map);
 
-    let mut total_cost = 0;
+        let mut total_cost = 0;
 
-    for region in regions {
-        let (top_sides, bottom_sides) = get_top_bottom_sides(&region, map)?;
-        let (left_sides, right_sides) = get_left_right_sides(&region, map)?;
-        let total_sides = top_sides + bottom_sides + left_sides + right_sides;
+        for region in regions {
+            let (top_sides, bottom_sides) = get_top_bottom_sides(&region, map)?;
+            let (left_sides, right_sides) = get_left_right_sides(&region, map)?;
+            let total_sides = top_sides + bottom_sides + left_sides + right_sides;
 
-        total_cost += region.points.len() * total_sides;
-    }
+            total_cost += region.points.len() * total_sides;
+        }
 
-    Ok(total_cost)
+        Ok(total_cost)
+    }
 }
 
 fn build_regions(map: &Grid<char>) -> Vec<Region> {
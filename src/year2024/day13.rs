@@ -1,29 +1,48 @@
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = Vec<Game>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input
-        .split("\n\n")
-        .map(|game| {
-            let mut game_lines = game.lines();
-            let button_a = game_lines
-                .next()
-                .ok_or(AdventError::LogicError("Lost Button A".to_string()))?;
-            let button_b = game_lines
-                .next()
-                .ok_or(AdventError::LogicError("Lost Button B".to_string()))?;
-            let prize = game_lines
-                .next()
-                .ok_or(AdventError::LogicError("Lost prize".to_string()))?;
-
-            let a_move = parse_line(button_a, '+')?;
-            let b_move = parse_line(button_b, '+')?;
-            let prize_loc = parse_line(prize, '=')?;
-
-            Ok(Game::new(a_move, b_move, prize_loc))
-        })
-        .collect::<Result<Vec<Game>, AdventError>>()?)
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input
+            .split("\n\n")
+            .map(|game| {
+                let mut game_lines = game.lines();
+                let button_a = game_lines
+                    .next()
+                    .ok_or(AdventError::LogicError("Lost Button A".to_string()))?;
+                let button_b = game_lines
+                    .next()
+                    .ok_or(AdventError::LogicError("Lost Button B".to_string()))?;
+                let prize = game_lines
+                    .next()
+                    .ok_or(AdventError::LogicError("Lost prize".to_string()))?;
+
+                let a_move = parse_line(button_a, '+')?;
+                let b_move = parse_line(button_b, '+')?;
+                let prize_loc = parse_line(prize, '=')?;
+
+                Ok(Game::new(a_move, b_move, prize_loc))
+            })
+            .collect::<Result<Vec<Game>, AdventError>>()?)
+    }
+
+    fn part1(games: &ParsedInput) -> color_eyre::Result<i64> {
+        Ok(games.iter().filter_map(|game| solve_game(game, 0)).sum())
+    }
+
+    fn part2(games: &ParsedInput) -> color_eyre::Result<i64> {
+        Ok(games
+            .iter()
+            .filter_map(|game| solve_game(game, 10000000000000))
+            .sum())
+    }
 }
 
 fn parse_line(line: &str, delimeter: char) -> Result<(u128, u128), AdventError> {
@@ -55,17 +74,6 @@ fn parse_line(line: &str, delimeter: char) -> Result<(u128, u128), AdventError>
     Ok((x_offset, y_offset))
 }
 
-pub fn part1(games: &ParsedInput) -> color_eyre::Result<i64> {
-    Ok(games.iter().filter_map(|game| solve_game(game, 0)).sum())
-}
-
-pub fn part2(games: &ParsedInput) -> color_eyre::Result<i64> {
-    Ok(games
-        .iter()
-        .filter_map(|game| solve_game(game, 10000000000000))
-        .sum())
-}
-
 fn solve_game(game: &Game, prize_offset: i64) -> Option<i64> {
     // Found on https://www.reddit.com/r/adventofcode/comments/1hd7irq/2024_day_13_an_explanation_of_the_mathematics/
     // Cramer's rule
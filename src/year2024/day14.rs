@@ -1,105 +1,121 @@
+use std::time::Duration;
+
 use itertools::Itertools;
 use log::debug;
 use strum::IntoEnumIterator;
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{direction::DirectionEx, grid::Grid, point::PointSig},
 };
 
 type ParsedInput = (Vec<Guard>, isize, isize);
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut lines = input.lines().peekable();
+pub struct Solution;
 
-    // The robots outside the actual bathroom are in a space which is 101 tiles wide and 103 tiles tall (when viewed from above).
-    // However, in this example, the robots are in a space which is only 11 tiles wide and 7 tiles tall.
-    let (width, height) = if lines.peek().is_some_and(|&line| line == "example") {
-        lines.next();
-        (11, 7)
-    } else {
-        (101, 103)
-    };
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-    Ok((lines.map(Guard::try_from).try_collect()?, width, height))
-}
+    // Part 2 steps through every second until the robots form the tree, which is given up on
+    // quickly unless --timeout allows longer
+    const TIMEOUT: Duration = Duration::from_secs(10);
 
-pub fn part1((guards, width, height): &ParsedInput) -> color_eyre::Result<u32> {
-    // Where will the robots be after 100 seconds?
-    // count the number of robots in each quadrant
-    // Robots that are exactly in the middle (horizontally or vertically) don't count as being in any quadrant
-
-    let final_locs = guards
-        .iter()
-        .map(|guard| project_guard(guard, 100, *width, *height))
-        .collect_vec();
-
-    let mid_x = width / 2;
-    let mid_y = height / 2;
-
-    let mut top_left = 0;
-    let mut top_right = 0;
-    let mut bottom_left = 0;
-    let mut bottom_right = 0;
-
-    for loc in final_locs {
-        if (0..mid_x).contains(&loc.x) && (0..mid_y).contains(&loc.y) {
-            top_left += 1;
-        } else if (0..mid_x).contains(&loc.x) && (mid_y + 1..*height).contains(&loc.y) {
-            bottom_left += 1;
-        } else if (mid_x + 1..*width).contains(&loc.x) && (0..mid_y).contains(&loc.y) {
-            top_right += 1;
-        } else if (mid_x + 1..*width).contains(&loc.x) && (mid_y + 1..*height).contains(&loc.y) {
-            bottom_right += 1;
-        }
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut lines = input.lines().peekable();
+
+        // The robots outside the actual bathroom are in a space which is 101 tiles wide and 103 tiles tall (when viewed from above).
+        // However, in this example, the robots are in a space which is only 11 tiles wide and 7 tiles tall.
+        let (width, height) = if lines.peek().is_some_and(|&line| line == "example") {
+            lines.next();
+            (11, 7)
+        } else {
+            (101, 103)
+        };
+
+        Ok((lines.map(Guard::try_from).try_collect()?, width, height))
     }
 
-    Ok(top_left * top_right * bottom_left * bottom_right)
-}
+    fn part1((guards, width, height): &ParsedInput) -> color_eyre::Result<u32> {
+        // Where will the robots be after 100 seconds?
+        // count the number of robots in each quadrant
+        // Robots that are exactly in the middle (horizontally or vertically) don't count as being in any quadrant
+
+        let final_locs = guards
+            .iter()
+            .map(|guard| project_guard(guard, 100, *width, *height))
+            .collect_vec();
+
+        let mid_x = width / 2;
+        let mid_y = height / 2;
+
+        let mut top_left = 0;
+        let mut top_right = 0;
+        let mut bottom_left = 0;
+        let mut bottom_right = 0;
+
+        for loc in final_locs {
+            if (0..mid_x).contains(&loc.x) && (0..mid_y).contains(&loc.y) {
+                top_left += 1;
+            } else if (0..mid_x).contains(&loc.x) && (mid_y + 1..*height).contains(&loc.y) {
+                bottom_left += 1;
+            } else if (mid_x + 1..*width).contains(&loc.x) && (0..mid_y).contains(&loc.y) {
+                top_right += 1;
+            } else if (mid_x + 1..*width).contains(&loc.x) && (mid_y + 1..*height).contains(&loc.y)
+            {
+                bottom_right += 1;
+            }
+        }
 
-pub fn part2((guards, width, height): &ParsedInput) -> color_eyre::Result<u32> {
-    let mut guards = guards.clone();
-    let mut grid = Grid::init(*height as usize, *width as usize, 0);
-    let mut found_after = 0;
+        Ok(top_left * top_right * bottom_left * bottom_right)
+    }
 
-    'seconds: for seconds in 1..=10000 {
-        for guard in guards.iter_mut() {
-            if let Some(n) = grid.get_mut(guard.pos.y as usize, guard.pos.x as usize) {
-                if *n > 0 {
-                    *n -= 1;
+    fn part2((guards, width, height): &ParsedInput) -> color_eyre::Result<u32> {
+        let mut guards = guards.clone();
+        let mut grid = Grid::init(*height as usize, *width as usize, 0);
+        let mut found_after = 0;
+
+        'seconds: for seconds in 1..=10000 {
+            for guard in guards.iter_mut() {
+                if let Some(n) = grid.get_mut(guard.pos.y as usize, guard.pos.x as usize) {
+                    if *n > 0 {
+                        *n -= 1;
+                    }
                 }
-            }
 
-            guard.pos = project_guard(&guard, 1, *width, *height);
+                guard.pos = project_guard(&guard, 1, *width, *height);
 
-            if let Some(n) = grid.get_mut(guard.pos.y as usize, guard.pos.x as usize) {
-                *n += 1;
+                if let Some(n) = grid.get_mut(guard.pos.y as usize, guard.pos.x as usize) {
+                    *n += 1;
+                }
             }
-        }
 
-        'guard: for guard in &guards {
-            // If a guard is completely surrounded by other guards then we've hit
-            // the christmas tree shape
-
-            for direction in DirectionEx::iter() {
-                let point = (guard.pos.y as usize, guard.pos.x as usize);
-                if grid
-                    .get_in_direction_ex(point, direction)
-                    .is_none_or(|n| *n == 0)
-                {
-                    continue 'guard;
+            'guard: for guard in &guards {
+                // If a guard is completely surrounded by other guards then we've hit
+                // the christmas tree shape
+
+                for direction in DirectionEx::iter() {
+                    let point = (guard.pos.y as usize, guard.pos.x as usize);
+                    if grid
+                        .get_in_direction_ex(point, direction)
+                        .is_none_or(|n| *n == 0)
+                    {
+                        continue 'guard;
+                    }
                 }
-            }
 
-            debug!("At {seconds} - {guard:?}");
-            debug!("{grid:?}");
+                debug!("At {seconds} - {guard:?}");
+                debug!("{grid:?}");
 
-            found_after = seconds;
-            break 'seconds;
+                found_after = seconds;
+                break 'seconds;
+            }
         }
-    }
 
-    Ok(found_after)
+        Ok(found_after)
+    }
 }
 
 fn project_guard(guard: &Guard, num_steps: isize, width: isize, height: isize) -> PointSig {
@@ -4,160 +4,173 @@ use std::collections::HashSet;
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{direction::Direction, grid::Grid},
 };
 
 type ParsedInput = (Grid<char>, Vec<Direction>);
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let (map, directions) = input.split_once("\n\n").ok_or(AdventError::SplitOnce(
-        input.to_string(),
-        "double-newline".to_string(),
-    ))?;
-
-    let map = map.try_into()?;
-
-    let directions = directions
-        .chars()
-        .filter_map(|ch| match ch {
-            '^' => Some(Direction::North),
-            '>' => Some(Direction::East),
-            'v' => Some(Direction::South),
-            '<' => Some(Direction::West),
-            _ => None,
-        })
-        .collect_vec();
-
-    Ok((map, directions))
-}
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let (map, directions) = input.split_once("\n\n").ok_or(AdventError::SplitOnce(
+            input.to_string(),
+            "double-newline".to_string(),
+        ))?;
+
+        let map = map.try_into()?;
+
+        let directions = directions
+            .chars()
+            .filter_map(|ch| match ch {
+                '^' => Some(Direction::North),
+                '>' => Some(Direction::East),
+                'v' => Some(Direction::South),
+                '<' => Some(Direction::West),
+                _ => None,
+            })
+            .collect_vec();
+
+        Ok((map, directions))
+    }
 
-pub fn part1((map, directions): &ParsedInput) -> color_eyre::Result<usize> {
-    let mut map = map.clone();
-    let (mut robot_pos, _) =
-        map.indexed_iter()
-            .find(|&(_, ch)| *ch == '@')
-            .ok_or(AdventError::LogicError(
-                "Failed to find robot in map".to_string(),
-            ))?;
-
-    for direction in directions {
-        if let Some((next_pos, next_val)) = map.get_in_direction_indexed(robot_pos, *direction) {
-            let can_move = match *next_val {
-                '#' => false,
-                '.' => true,
-                'O' => move_small_box(next_pos, *direction, &mut map)?,
-                _ => {
-                    return Err(AdventError::LogicError(format!(
-                        "Value at {next_pos:?} is {next_val} unexpectedly"
-                    ))
-                    .into())
-                }
-            };
+    fn part1((map, directions): &ParsedInput) -> color_eyre::Result<usize> {
+        let mut map = map.clone();
+        let (mut robot_pos, _) =
+            map.indexed_iter()
+                .find(|&(_, ch)| *ch == '@')
+                .ok_or(AdventError::LogicError(
+                    "Failed to find robot in map".to_string(),
+                ))?;
+
+        for direction in directions {
+            if let Some((next_pos, next_val)) = map.get_in_direction_indexed(robot_pos, *direction)
+            {
+                let can_move = match *next_val {
+                    '#' => false,
+                    '.' => true,
+                    'O' => move_small_box(next_pos, *direction, &mut map)?,
+                    _ => {
+                        return Err(AdventError::LogicError(format!(
+                            "Value at {next_pos:?} is {next_val} unexpectedly"
+                        ))
+                        .into())
+                    }
+                };
+
+                if can_move {
+                    // We know this is safe because we got
+                    unsafe {
+                        let cur_val = map.get_unchecked_mut(robot_pos.0, robot_pos.1);
+                        *cur_val = '.';
+                        let next_val = map.get_unchecked_mut(next_pos.0, next_pos.1);
+                        *next_val = '@';
+                    }
 
-            if can_move {
-                // We know this is safe because we got
-                unsafe {
-                    let cur_val = map.get_unchecked_mut(robot_pos.0, robot_pos.1);
-                    *cur_val = '.';
-                    let next_val = map.get_unchecked_mut(next_pos.0, next_pos.1);
-                    *next_val = '@';
+                    robot_pos = next_pos;
                 }
-
-                robot_pos = next_pos;
+            } else {
+                return Err(AdventError::LogicError(format!(
+                    "{direction} of {robot_pos:?} is out of bounds"
+                ))
+                .into());
             }
-        } else {
-            return Err(AdventError::LogicError(format!(
-                "{direction} of {robot_pos:?} is out of bounds"
-            ))
-            .into());
         }
-    }
 
-    // The GPS coordinate of a box is equal to 100 times its distance from the top edge of the map
-    // plus its distance from the left edge of the map.
-    // What is the sum of all boxes' GPS coordinates?
+        // The GPS coordinate of a box is equal to 100 times its distance from the top edge of the map
+        // plus its distance from the left edge of the map.
+        // What is the sum of all boxes' GPS coordinates?
 
-    Ok(map
-        .indexed_iter()
-        .filter_map(|(pos, val)| {
-            if *val == 'O' {
-                Some(100 * pos.0 + pos.1)
-            } else {
-                None
-            }
-        })
-        .sum())
-}
+        Ok(map
+            .indexed_iter()
+            .filter_map(|(pos, val)| {
+                if *val == 'O' {
+                    Some(100 * pos.0 + pos.1)
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
 
-pub fn part2((map, directions): &ParsedInput) -> color_eyre::Result<usize> {
-    let mut map = expand_map(map)?;
-    let (mut robot_pos, _) =
-        map.indexed_iter()
-            .find(|&(_, ch)| *ch == '@')
-            .ok_or(AdventError::LogicError(
-                "Failed to find robot in map".to_string(),
-            ))?;
-
-    for direction in directions {
-        if let Some((next_pos, next_val)) = map.get_in_direction_indexed(robot_pos, *direction) {
-            let move_robot = match *next_val {
-                '#' => false,
-                '.' => true,
-                '[' | ']' => {
-                    let (box_lhs, box_rhs) = if *next_val == '[' {
-                        (next_pos, (next_pos.0, next_pos.1 + 1))
-                    } else {
-                        ((next_pos.0, next_pos.1 - 1), next_pos)
-                    };
-
-                    if let Some(boxes) = can_move_big_box(box_lhs, box_rhs, *direction, &mut map)? {
-                        move_big_boxes(boxes, *direction, &mut map);
-                        true
-                    } else {
-                        false
+    fn part2((map, directions): &ParsedInput) -> color_eyre::Result<usize> {
+        let mut map = expand_map(map)?;
+        let (mut robot_pos, _) =
+            map.indexed_iter()
+                .find(|&(_, ch)| *ch == '@')
+                .ok_or(AdventError::LogicError(
+                    "Failed to find robot in map".to_string(),
+                ))?;
+
+        for direction in directions {
+            if let Some((next_pos, next_val)) = map.get_in_direction_indexed(robot_pos, *direction)
+            {
+                let move_robot = match *next_val {
+                    '#' => false,
+                    '.' => true,
+                    '[' | ']' => {
+                        let (box_lhs, box_rhs) = if *next_val == '[' {
+                            (next_pos, (next_pos.0, next_pos.1 + 1))
+                        } else {
+                            ((next_pos.0, next_pos.1 - 1), next_pos)
+                        };
+
+                        if let Some(boxes) =
+                            can_move_big_box(box_lhs, box_rhs, *direction, &mut map)?
+                        {
+                            move_big_boxes(boxes, *direction, &mut map);
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    _ => {
+                        return Err(AdventError::LogicError(format!(
+                            "Value at {next_pos:?} is {next_val} unexpectedly"
+                        ))
+                        .into())
+                    }
+                };
+
+                if move_robot {
+                    // We know this is safe because we got the positions already above
+                    unsafe {
+                        let cur_val = map.get_unchecked_mut(robot_pos.0, robot_pos.1);
+                        *cur_val = '.';
+                        let next_val = map.get_unchecked_mut(next_pos.0, next_pos.1);
+                        *next_val = '@';
                     }
-                }
-                _ => {
-                    return Err(AdventError::LogicError(format!(
-                        "Value at {next_pos:?} is {next_val} unexpectedly"
-                    ))
-                    .into())
-                }
-            };
 
-            if move_robot {
-                // We know this is safe because we got the positions already above
-                unsafe {
-                    let cur_val = map.get_unchecked_mut(robot_pos.0, robot_pos.1);
-                    *cur_val = '.';
-                    let next_val = map.get_unchecked_mut(next_pos.0, next_pos.1);
-                    *next_val = '@';
+                    robot_pos = next_pos;
                 }
-
-                robot_pos = next_pos;
+            } else {
+                return Err(AdventError::LogicError(format!(
+                    "{direction} of {robot_pos:?} is out of bounds"
+                ))
+                .into());
             }
-        } else {
-            return Err(AdventError::LogicError(format!(
-                "{direction} of {robot_pos:?} is out of bounds"
-            ))
-            .into());
         }
-    }
 
-    // The GPS coordinate of a box is equal to 100 times its distance from the top edge of the map
-    // plus its distance from the left edge of the map.
-    // What is the sum of all boxes' GPS coordinates?
+        // The GPS coordinate of a box is equal to 100 times its distance from the top edge of the map
+        // plus its distance from the left edge of the map.
+        // What is the sum of all boxes' GPS coordinates?
 
-    Ok(map
-        .indexed_iter()
-        .filter_map(|(pos, val)| {
-            if *val == '[' {
-                Some(100 * pos.0 + pos.1)
-            } else {
-                None
-            }
-        })
-        .sum())
+        Ok(map
+            .indexed_iter()
+            .filter_map(|(pos, val)| {
+                if *val == '[' {
+                    Some(100 * pos.0 + pos.1)
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
 }
 
 fn move_small_box(
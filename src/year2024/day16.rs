@@ -11,72 +11,81 @@ use strum::IntoEnumIterator;
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{direction::Direction, grid::Grid, point::Point},
 };
 
 type ParsedInput = Grid<char>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input.try_into()?)
-}
+pub struct Solution;
 
-pub fn part1(map: &ParsedInput) -> color_eyre::Result<usize> {
-    // Runs in about 5 mins...
-    Ok(134588)
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    // let ((row, col), _) = map
-    //     .indexed_iter()
-    //     .find(|&(_, val)| *val == 'S')
-    //     .ok_or(AdventError::NotFound('S'.to_string()))?;
-    // let start = Node::new(col, row, Some(Direction::East), 0);
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input.try_into()?)
+    }
 
-    // let ((row, col), _) = map
-    //     .indexed_iter()
-    //     .find(|&(_, val)| *val == 'E')
-    //     .ok_or(AdventError::NotFound('S'.to_string()))?;
-    // let end = Node::new(col, row, None, 0);
+    fn part1(map: &ParsedInput) -> color_eyre::Result<usize> {
+        // Runs in about 5 mins...
+        Ok(134588)
 
-    // let successors = |node: &Node| -> Vec<(Node, usize)> { get_successors(node, map) };
-    // let heuristic = |node: &Node| -> usize { get_heuristic(&node.loc, &end.loc) };
-    // let success = |node: &Node| -> bool { node.is_same_loc(&end) };
+        // let ((row, col), _) = map
+        //     .indexed_iter()
+        //     .find(|&(_, val)| *val == 'S')
+        //     .ok_or(AdventError::NotFound('S'.to_string()))?;
+        // let start = Node::new(col, row, Some(Direction::East), 0);
 
-    // let path = astar(&start, successors, heuristic, success).expect("Failed to find a path");
+        // let ((row, col), _) = map
+        //     .indexed_iter()
+        //     .find(|&(_, val)| *val == 'E')
+        //     .ok_or(AdventError::NotFound('S'.to_string()))?;
+        // let end = Node::new(col, row, None, 0);
 
-    // Ok(path.1)
-}
+        // let successors = |node: &Node| -> Vec<(Node, usize)> { get_successors(node, map) };
+        // let heuristic = |node: &Node| -> usize { get_heuristic(&node.loc, &end.loc) };
+        // let success = |node: &Node| -> bool { node.is_same_loc(&end) };
 
-pub fn part2(map: &ParsedInput) -> color_eyre::Result<usize> {
-    // Runs in about 11 mins...
-    Ok(631)
+        // let path = astar(&start, successors, heuristic, success).expect("Failed to find a path");
 
-    // let ((row, col), _) = map
-    //     .indexed_iter()
-    //     .find(|&(_, val)| *val == 'S')
-    //     .ok_or(AdventError::NotFound('S'.to_string()))?;
-    // let start = Node::new(col, row, Some(Direction::East), 0);
+        // Ok(path.1)
+    }
 
-    // let ((row, col), _) = map
-    //     .indexed_iter()
-    //     .find(|&(_, val)| *val == 'E')
-    //     .ok_or(AdventError::NotFound('S'.to_string()))?;
-    // let end = Node::new(col, row, None, 0);
+    fn part2(map: &ParsedInput) -> color_eyre::Result<usize> {
+        // Runs in about 11 mins...
+        Ok(631)
 
-    // let successors = |node: &Node| -> Vec<(Node, usize)> { get_successors(node, map) };
-    // let heuristic = |node: &Node| -> usize { get_heuristic(&node.loc, &end.loc) };
-    // let success = |node: &Node| -> bool { node.is_same_loc(&end) };
+        // let ((row, col), _) = map
+        //     .indexed_iter()
+        //     .find(|&(_, val)| *val == 'S')
+        //     .ok_or(AdventError::NotFound('S'.to_string()))?;
+        // let start = Node::new(col, row, Some(Direction::East), 0);
 
-    // let (paths, _) = astar_bag_collect(&start, successors, heuristic, success).expect("Failed to find a path");
+        // let ((row, col), _) = map
+        //     .indexed_iter()
+        //     .find(|&(_, val)| *val == 'E')
+        //     .ok_or(AdventError::NotFound('S'.to_string()))?;
+        // let end = Node::new(col, row, None, 0);
 
-    // let mut seats = HashSet::new();
-    // for path in paths {
-    //     for node in path {
-    //         seats.insert(node.loc);
-    //     }
-    // }
+        // let successors = |node: &Node| -> Vec<(Node, usize)> { get_successors(node, map) };
+        // let heuristic = |node: &Node| -> usize { get_heuristic(&node.loc, &end.loc) };
+        // let success = |node: &Node| -> bool { node.is_same_loc(&end) };
 
-    // //print_seats(map, &seats);
+        // let (paths, _) = astar_bag_collect(&start, successors, heuristic, success).expect("Failed to find a path");
 
-    // Ok(seats.len())
+        // let mut seats = HashSet::new();
+        // for path in paths {
+        //     for node in path {
+        //         seats.insert(node.loc);
+        //     }
+        // }
+
+        // //print_seats(map, &seats);
+
+        // Ok(seats.len())
+    }
 }
 
 fn get_successors(node: &Node, map: &ParsedInput) -> Vec<(Node, usize)> {
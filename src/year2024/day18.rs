@@ -4,73 +4,82 @@ use strum::IntoEnumIterator;
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{direction::Direction, grid::Grid, point::Point},
 };
 
 type ParsedInput = (Grid<char>, Vec<Point>, usize);
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut lines = input.lines().peekable();
-    // Your memory space is a two-dimensional grid with coordinates that range from 0 to 70
-    // both horizontally and vertically. However, for the sake of example, suppose you're on
-    // a smaller grid with coordinates that range from 0 to 6
-    // Only consider first 12 byte for example, or 1024 for real input
-    let (grid, num_bytes) = if lines.peek().is_some_and(|&line| line == "example") {
-        lines.next();
-        (Grid::init(7, 7, '.'), 12)
-    } else {
-        (Grid::init(71, 71, '.'), 1024)
-    };
-
-    let byte_locs: Vec<Point> = lines.map(|line| line.try_into()).try_collect()?;
-
-    Ok((grid, byte_locs, num_bytes))
-}
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut lines = input.lines().peekable();
+        // Your memory space is a two-dimensional grid with coordinates that range from 0 to 70
+        // both horizontally and vertically. However, for the sake of example, suppose you're on
+        // a smaller grid with coordinates that range from 0 to 6
+        // Only consider first 12 byte for example, or 1024 for real input
+        let (grid, num_bytes) = if lines.peek().is_some_and(|&line| line == "example") {
+            lines.next();
+            (Grid::init(7, 7, '.'), 12)
+        } else {
+            (Grid::init(71, 71, '.'), 1024)
+        };
+
+        let byte_locs: Vec<Point> = lines.map(|line| line.try_into()).try_collect()?;
+
+        Ok((grid, byte_locs, num_bytes))
+    }
 
-pub fn part1((grid, byte_locs, num_bytes): &ParsedInput) -> color_eyre::Result<usize> {
-    let mut grid = grid.clone();
-    corrupt_grid(&mut grid, &byte_locs[..*num_bytes])?;
+    fn part1((grid, byte_locs, num_bytes): &ParsedInput) -> color_eyre::Result<usize> {
+        let mut grid = grid.clone();
+        corrupt_grid(&mut grid, &byte_locs[..*num_bytes])?;
 
-    let (_, steps) = find_path(&grid).ok_or(AdventError::LogicError(
-        "Failed to find path through grid".to_string(),
-    ))?;
+        let (_, steps) = find_path(&grid).ok_or(AdventError::LogicError(
+            "Failed to find path through grid".to_string(),
+        ))?;
 
-    Ok(steps)
-}
+        Ok(steps)
+    }
 
-pub fn part2((grid, byte_locs, num_bytes): &ParsedInput) -> color_eyre::Result<String> {
-    // Can still corrupt the grid with the first num_bytes as we know a path is still
-    // feasible when they are present from part 1
-    let mut grid = grid.clone();
-    corrupt_grid(&mut grid, &byte_locs[..*num_bytes])?;
+    fn part2((grid, byte_locs, num_bytes): &ParsedInput) -> color_eyre::Result<String> {
+        // Can still corrupt the grid with the first num_bytes as we know a path is still
+        // feasible when they are present from part 1
+        let mut grid = grid.clone();
+        corrupt_grid(&mut grid, &byte_locs[..*num_bytes])?;
 
-    let (mut path, _) = find_path(&grid).ok_or(AdventError::LogicError(
-        "Failed to find path through grid".to_string(),
-    ))?;
+        let (mut path, _) = find_path(&grid).ok_or(AdventError::LogicError(
+            "Failed to find path through grid".to_string(),
+        ))?;
 
-    for byte_loc in &byte_locs[*num_bytes..] {
-        let grid_val = grid
-            .get_mut(byte_loc.y, byte_loc.x)
-            .ok_or(AdventError::LogicError(format!(
-                "Byte loc {byte_loc:?} not found in grid"
-            )))?;
+        for byte_loc in &byte_locs[*num_bytes..] {
+            let grid_val = grid
+                .get_mut(byte_loc.y, byte_loc.x)
+                .ok_or(AdventError::LogicError(format!(
+                    "Byte loc {byte_loc:?} not found in grid"
+                )))?;
 
-        *grid_val = '#';
+            *grid_val = '#';
 
-        if path.contains(byte_loc) {
-            if let Some((new_path, _)) = find_path(&grid) {
-                path = new_path;
-            } else {
-                return Ok(format!(
-                    "{},{}",
-                    byte_loc.x.to_string(),
-                    byte_loc.y.to_string()
-                ));
+            if path.contains(byte_loc) {
+                if let Some((new_path, _)) = find_path(&grid) {
+                    path = new_path;
+                } else {
+                    return Ok(format!(
+                        "{},{}",
+                        byte_loc.x.to_string(),
+                        byte_loc.y.to_string()
+                    ));
+                }
             }
         }
-    }
 
-    Err(AdventError::LogicError("No byte locs failed to block the path".to_string()).into())
+        Err(AdventError::LogicError("No byte locs failed to block the path".to_string()).into())
+    }
 }
 
 fn corrupt_grid(grid: &mut Grid<char>, byte_locs: &[Point]) -> color_eyre::Result<()> {
@@ -2,47 +2,55 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 
-use crate::error::AdventError;
+use crate::{error::AdventError, solver::Solver};
 
 type ParsedInput = (Vec<String>, Vec<String>);
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    let mut lines = input.lines();
+pub struct Solution;
 
-    let towels = lines.next().ok_or(AdventError::EndOfIterator)?;
-    let towels = towels
-        .split(',')
-        .map(str::trim)
-        .map(String::from)
-        .collect_vec();
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    lines.next().ok_or(AdventError::EndOfIterator)?;
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        let mut lines = input.lines();
 
-    let designs = lines.map(String::from).collect_vec();
+        let towels = lines.next().ok_or(AdventError::EndOfIterator)?;
+        let towels = towels
+            .split(',')
+            .map(str::trim)
+            .map(String::from)
+            .collect_vec();
 
-    Ok((towels, designs))
-}
+        lines.next().ok_or(AdventError::EndOfIterator)?;
 
-pub fn part1((towels, designs): &ParsedInput) -> color_eyre::Result<u64> {
-    let mut cache = HashMap::<String, u64>::new();
-    let mut total_designs = 0;
+        let designs = lines.map(String::from).collect_vec();
 
-    for design in designs {
-        total_designs += is_design_possible(design, towels, true, &mut cache);
+        Ok((towels, designs))
     }
 
-    Ok(total_designs)
-}
+    fn part1((towels, designs): &ParsedInput) -> color_eyre::Result<u64> {
+        let mut cache = HashMap::<String, u64>::new();
+        let mut total_designs = 0;
 
-pub fn part2((towels, designs): &ParsedInput) -> color_eyre::Result<u64> {
-    let mut cache = HashMap::<String, u64>::new();
-    let mut total_designs = 0;
+        for design in designs {
+            total_designs += is_design_possible(design, towels, true, &mut cache);
+        }
 
-    for design in designs {
-        total_designs += is_design_possible(design, towels, false, &mut cache);
+        Ok(total_designs)
     }
 
-    Ok(total_designs)
+    fn part2((towels, designs): &ParsedInput) -> color_eyre::Result<u64> {
+        let mut cache = HashMap::<String, u64>::new();
+        let mut total_designs = 0;
+
+        for design in designs {
+            total_designs += is_design_possible(design, towels, false, &mut cache);
+        }
+
+        Ok(total_designs)
+    }
 }
 
 fn is_design_possible(
@@ -2,21 +2,30 @@ use strum::IntoEnumIterator;
 
 use crate::{
     error::AdventError,
+    solver::Solver,
     util::{direction::Direction, grid::Grid},
 };
 
 type ParsedInput = Grid<char>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input.try_into()?)
-}
+pub struct Solution;
 
-pub fn part1(grid: &ParsedInput) -> color_eyre::Result<u32> {
-    solve(grid, 2)
-}
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input.try_into()?)
+    }
 
-pub fn part2(grid: &ParsedInput) -> color_eyre::Result<u32> {
-    solve(grid, 20)
+    fn part1(grid: &ParsedInput) -> color_eyre::Result<u32> {
+        solve(grid, 2)
+    }
+
+    fn part2(grid: &ParsedInput) -> color_eyre::Result<u32> {
+        solve(grid, 20)
+    }
 }
 
 fn solve(grid: &ParsedInput, max_cheat_dist: usize) -> color_eyre::Result<u32> {
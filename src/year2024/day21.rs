@@ -1,90 +1,98 @@
 use itertools::Itertools;
 
-use crate::{error::AdventError, util::point::Point};
+use crate::{error::AdventError, solver::Solver, util::point::Point};
 
 type ParsedInput = Vec<String>;
 
-pub fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
-    Ok(input.lines().map(String::from).collect_vec())
-}
+pub struct Solution;
 
-pub fn part1(codes: &ParsedInput) -> color_eyre::Result<u32> {
-    /*
+impl Solver for Solution {
+    type Input<'a> = ParsedInput;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> color_eyre::Result<ParsedInput> {
+        Ok(input.lines().map(String::from).collect_vec())
+    }
 
-    num_pad <- dir_pad_1 <- dir_pad_2 <- my_pad
-    num_pad
-        ^-  press dir moves arm (robot 1 presses)
-                   ^- press dir moves arm (robot 2 presses)
-                             ^- press dir moves arm (my presses)
+    fn part1(codes: &ParsedInput) -> color_eyre::Result<u32> {
+        /*
 
-    num_pad_pos = ? A
-    dir_pad_1_pos = ? A
-    dir_pad_2_pos = ? A
+        num_pad <- dir_pad_1 <- dir_pad_2 <- my_pad
+        num_pad
+            ^-  press dir moves arm (robot 1 presses)
+                       ^- press dir moves arm (robot 2 presses)
+                                 ^- press dir moves arm (my presses)
 
-    for digit in code // don't forget the A
-      digit_pos = get_digit_pos(digit)
-      num_pad_moves = get_moves(num_pad_pos, digit_pos)
+        num_pad_pos = ? A
+        dir_pad_1_pos = ? A
+        dir_pad_2_pos = ? A
 
-      for arrow in num_pad_moves
-        arrow_pos = get_arrow_pos(arrow)
-        dir_pad_1_moves = get_moves(dir_pad_1_pos, arrow_pos)
+        for digit in code // don't forget the A
+          digit_pos = get_digit_pos(digit)
+          num_pad_moves = get_moves(num_pad_pos, digit_pos)
 
-        for arrow in dir_pad_1_moves
+          for arrow in num_pad_moves
             arrow_pos = get_arrow_pos(arrow)
-            dir_pad_2_moves = get_moves(dir_pad_2_pos, arrow_pos)
-
-     */
+            dir_pad_1_moves = get_moves(dir_pad_1_pos, arrow_pos)
 
-    for code in codes {
-        let mut num_pad_pos = Point::from((2, 3));
-        let mut dir_pad_1_pos = Point::from((2, 0));
-        let mut dir_pad_2_pos = Point::from((2, 0));
+            for arrow in dir_pad_1_moves
+                arrow_pos = get_arrow_pos(arrow)
+                dir_pad_2_moves = get_moves(dir_pad_2_pos, arrow_pos)
 
-        let mut num_pad_res = String::new();
-        let mut dir_pad_1_res = String::new();
-        let mut dir_pad_2_res = String::new();
+         */
 
-        for button in code.chars() {
-            let button_pos = get_num_pad_pos(button)?;
-            let num_pad_moves = get_robot_moves(num_pad_pos, button_pos);
+        for code in codes {
+            let mut num_pad_pos = Point::from((2, 3));
+            let mut dir_pad_1_pos = Point::from((2, 0));
+            let mut dir_pad_2_pos = Point::from((2, 0));
 
-            //dbg!((button, num_pad_pos, button_pos, &num_pad_moves));
-            num_pad_res.extend(num_pad_moves.iter());
+            let mut num_pad_res = String::new();
+            let mut dir_pad_1_res = String::new();
+            let mut dir_pad_2_res = String::new();
 
-            for arrow in num_pad_moves {
-                let button_pos = get_dir_pad_pos(arrow)?;
-                let dir_pad_1_moves = get_robot_moves(dir_pad_1_pos, button_pos);
+            for button in code.chars() {
+                let button_pos = get_num_pad_pos(button)?;
+                let num_pad_moves = get_robot_moves(num_pad_pos, button_pos);
 
-                //dbg!((arrow, dir_pad_1_pos, button_pos, &dir_pad_1_moves));
-                dir_pad_1_res.extend(dir_pad_1_moves.iter());
+                //dbg!((button, num_pad_pos, button_pos, &num_pad_moves));
+                num_pad_res.extend(num_pad_moves.iter());
 
-                for arrow in dir_pad_1_moves {
+                for arrow in num_pad_moves {
                     let button_pos = get_dir_pad_pos(arrow)?;
-                    let dir_pad_2_moves = get_robot_moves(dir_pad_2_pos, button_pos);
+                    let dir_pad_1_moves = get_robot_moves(dir_pad_1_pos, button_pos);
+
+                    //dbg!((arrow, dir_pad_1_pos, button_pos, &dir_pad_1_moves));
+                    dir_pad_1_res.extend(dir_pad_1_moves.iter());
 
-                    //dbg!((arrow, dir_pad_2_pos, button_pos, &dir_pad_2_moves));
-                    dir_pad_2_res.extend(dir_pad_2_moves.iter());
+                    for arrow in dir_pad_1_moves {
+                        let button_pos = get_dir_pad_pos(arrow)?;
+                        let dir_pad_2_moves = get_robot_moves(dir_pad_2_pos, button_pos);
 
-                    dir_pad_2_pos = button_pos;
+                        //dbg!((arrow, dir_pad_2_pos, button_pos, &dir_pad_2_moves));
+                        dir_pad_2_res.extend(dir_pad_2_moves.iter());
+
+                        dir_pad_2_pos = button_pos;
+                    }
+
+                    dir_pad_1_pos = button_pos;
                 }
 
-                dir_pad_1_pos = button_pos;
+                num_pad_pos = button_pos;
             }
+            //dbg!(dir_pad_2_res);
+            // dbg!(dir_pad_1_res);
+            // dbg!(num_pad_res);
 
-            num_pad_pos = button_pos;
+            println!("{code}: {dir_pad_2_res} : {}", dir_pad_2_res.len());
         }
-        //dbg!(dir_pad_2_res);
-        // dbg!(dir_pad_1_res);
-        // dbg!(num_pad_res);
 
-        println!("{code}: {dir_pad_2_res} : {}", dir_pad_2_res.len());
+        Ok(0)
     }
 
-    Ok(0)
-}
-
-pub fn part2(_: &ParsedInput) -> color_eyre::Result<u32> {
-    Ok(0)
+    fn part2(_: &ParsedInput) -> color_eyre::Result<u32> {
+        Ok(0)
+    }
 }
 
 // enum Move {
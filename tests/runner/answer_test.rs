@@ -0,0 +1,86 @@
+use advent_of_code::answer::{Answer, Bitmap};
+
+const PICTURE: &str = "\
+#..#
+####
+#..#";
+
+#[test]
+fn answer_int_matches_by_value() {
+    let answer = Answer::from(7u64);
+
+    assert!(answer.matches("7"));
+    assert!(answer.matches("007"));
+    assert!(answer.matches(" 7\n"));
+    assert!(!answer.matches("8"));
+    assert!(!answer.matches("seven"));
+}
+
+#[test]
+fn answer_str_matches_exactly() {
+    let answer = Answer::from("56,8");
+
+    assert!(answer.matches("56,8"));
+    assert!(!answer.matches("56, 8"));
+    assert!(!answer.matches("56,8\n"));
+}
+
+#[test]
+fn answer_bitmap_matches_pixels() {
+    let answer = Answer::from(PICTURE.parse::<Bitmap>().unwrap());
+
+    assert!(answer.matches(PICTURE));
+    assert!(answer.matches("█  █\n████\n█  █"));
+    assert!(answer.matches("#..#...\n####...\n#..#...\n.......\n"));
+    assert!(!answer.matches("#..#\n####\n#.##"));
+    assert!(!answer.matches("HI"));
+}
+
+#[test]
+fn answer_from_u128_too_big() {
+    assert_eq!(Answer::Int(12), Answer::from(12u128));
+    assert_eq!(Answer::Str(u128::MAX.to_string()), Answer::from(u128::MAX));
+}
+
+#[test]
+fn answer_display() {
+    let bitmap = Answer::from(PICTURE.parse::<Bitmap>().unwrap());
+
+    assert_eq!("-12", Answer::from(-12).to_string());
+    assert_eq!("abc", Answer::from("abc").to_string());
+    assert_eq!(PICTURE, bitmap.to_string());
+    assert_eq!("4x3 bitmap", bitmap.cell());
+    assert_eq!("abc", Answer::from("abc").cell());
+}
+
+#[test]
+fn answer_serde_round_trip() {
+    let answers = vec![
+        Answer::from(34241),
+        Answer::from("56,8"),
+        Answer::from(PICTURE.parse::<Bitmap>().unwrap()),
+    ];
+
+    let json = serde_json::to_string(&answers).unwrap();
+    assert_eq!("[34241,\"56,8\",[\"#..#\",\"####\",\"#..#\"]]", json);
+    assert_eq!(answers, serde_json::from_str::<Vec<Answer>>(&json).unwrap());
+}
+
+#[test]
+fn bitmap_parse() {
+    let bitmap = "#.\n.#\n..".parse::<Bitmap>().unwrap();
+
+    assert_eq!(2, bitmap.width());
+    assert_eq!(2, bitmap.height());
+    assert_eq!(&[vec![true, false], vec![false, true]], bitmap.rows());
+    assert!("#x".parse::<Bitmap>().is_err());
+}
+
+#[test]
+fn bitmap_trims_padding() {
+    let padded = "....\n.#..\n..#.\n....".parse::<Bitmap>().unwrap();
+
+    assert_eq!("#.\n.#\n..".parse::<Bitmap>().unwrap(), padded);
+    assert_eq!("#.\n.#", padded.to_string());
+    assert_eq!(Bitmap::default(), "..\n..".parse::<Bitmap>().unwrap());
+}
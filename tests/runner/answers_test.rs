@@ -0,0 +1,93 @@
+use std::fs;
+
+use advent_of_code::{
+    answer::Answer,
+    registry,
+    runner::{
+        answers::{AnswerStore, Verdict},
+        solve::{PartResult, PuzzleResult},
+    },
+};
+
+fn result(part1: &str, part2: &str) -> PuzzleResult {
+    PuzzleResult {
+        parse_time_s: 0.0,
+        part1: Some(PartResult {
+            answer: Answer::from(part1),
+            time_s: 0.0,
+        }),
+        part2: Some(PartResult {
+            answer: Answer::from(part2),
+            time_s: 0.0,
+        }),
+    }
+}
+
+#[test]
+fn answers_verify() {
+    let dir = std::env::temp_dir().join("aoc_answers_verify");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("2019.toml"), "[day01]\npart1 = \"34241\"\n").unwrap();
+
+    let answers = AnswerStore::load(&dir).unwrap();
+    let day01 = registry::find(2019, 1).unwrap();
+    let day02 = registry::find(2019, 2).unwrap();
+
+    assert_eq!(
+        (Some(Verdict::Correct), Some(Verdict::Unknown)),
+        answers.verify(day01, &result("34241", "51316"))
+    );
+    assert_eq!(
+        (Some(Verdict::Wrong), Some(Verdict::Unknown)),
+        answers.verify(day01, &result("34242", "51316"))
+    );
+    assert_eq!(
+        (Some(Verdict::Unknown), Some(Verdict::Unknown)),
+        answers.verify(day02, &result("1", "2"))
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn answers_record() {
+    let dir = std::env::temp_dir().join("aoc_answers_record");
+    let _ = fs::remove_dir_all(&dir);
+    let day01 = registry::find(2019, 1).unwrap();
+
+    let mut answers = AnswerStore::load(&dir).unwrap();
+    answers.record(day01, &result("34241", "51316"));
+    answers.save().unwrap();
+
+    let answers = AnswerStore::load(&dir).unwrap();
+    assert_eq!(
+        (Some(Verdict::Correct), Some(Verdict::Correct)),
+        answers.verify(day01, &result("34241", "51316"))
+    );
+    assert_eq!(
+        "[day01]\npart1 = \"34241\"\npart2 = \"51316\"\n",
+        fs::read_to_string(dir.join("2019.toml")).unwrap()
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn answers_single_part() {
+    let dir = std::env::temp_dir().join("aoc_answers_single_part");
+    let _ = fs::remove_dir_all(&dir);
+    let day01 = registry::find(2019, 1).unwrap();
+
+    let mut answers = AnswerStore::load(&dir).unwrap();
+    answers.record(day01, &result("34241", "51316"));
+
+    let mut part2 = result("", "51317");
+    part2.part1 = None;
+    assert_eq!((None, Some(Verdict::Wrong)), answers.verify(day01, &part2));
+
+    answers.record(day01, &part2);
+    assert_eq!(
+        (Some(Verdict::Correct), Some(Verdict::Correct)),
+        answers.verify(day01, &result("34241", "51317"))
+    );
+}
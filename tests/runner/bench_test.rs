@@ -0,0 +1,89 @@
+use std::fs;
+
+use advent_of_code::{
+    answer::Answer,
+    registry,
+    runner::{
+        bench::{bench, Baseline, BenchStats},
+        output::BenchRecord,
+        solve::{Part, Parts},
+    },
+};
+
+#[test]
+fn bench_stats_odd() {
+    let stats = BenchStats::from_samples(&[3.0, 1.0, 2.0]);
+
+    assert_eq!(1.0, stats.min);
+    assert_eq!(2.0, stats.median);
+    assert_eq!(2.0, stats.mean);
+    assert_eq!(1.0, stats.std_dev);
+}
+
+#[test]
+fn bench_stats_even() {
+    let stats = BenchStats::from_samples(&[4.0, 1.0, 2.0, 1.0]);
+
+    assert_eq!(1.0, stats.min);
+    assert_eq!(1.5, stats.median);
+    assert_eq!(2.0, stats.mean);
+    assert_eq!(2.0f32.sqrt(), stats.std_dev);
+}
+
+#[test]
+fn bench_stats_single() {
+    let stats = BenchStats::from_samples(&[0.25]);
+
+    assert_eq!(0.25, stats.median);
+    assert_eq!(0.0, stats.std_dev);
+}
+
+#[test]
+fn bench_answers() {
+    let puzzle = registry::find(2019, 1).unwrap();
+    let bench = bench(puzzle, "12\n14\n1969\n100756", 3, Parts::BOTH).unwrap();
+
+    let part1 = bench.result.part1.unwrap();
+    assert_eq!(Answer::Int(34241), part1.answer);
+    assert_eq!(Answer::Int(51316), bench.result.part2.unwrap().answer);
+    assert_eq!(bench.part1.unwrap().median, part1.time_s);
+}
+
+#[test]
+fn bench_baseline_regression() {
+    let path = std::env::temp_dir().join("aoc_bench_baseline.json");
+    let puzzle = registry::find(2019, 1).unwrap();
+    let mut bench = bench(puzzle, "12\n14\n1969\n100756", 1, Parts::BOTH).unwrap();
+    bench.parse.median = 1.0;
+    bench.part1.as_mut().unwrap().median = 1.0;
+    bench.part2.as_mut().unwrap().median = 1.0;
+
+    let mut baseline = Baseline::load(&path).unwrap_or_default();
+    baseline.insert(puzzle, &bench);
+    baseline.save(&path).unwrap();
+    let baseline = Baseline::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    bench.part1.as_mut().unwrap().median = 1.5;
+    bench.part2.as_mut().unwrap().median = 1.05;
+    let records = BenchRecord::from_bench(puzzle, &bench, baseline.get(puzzle), 10.0);
+
+    assert_eq!(
+        vec![("parse", false), ("part1", true), ("part2", false)],
+        records
+            .iter()
+            .map(|record| (record.stage, record.regression))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn bench_single_part() {
+    let puzzle = registry::find(2019, 1).unwrap();
+    let parts = Parts::from(Some(Part::Two));
+    let bench = bench(puzzle, "12\n14\n1969\n100756", 1, parts).unwrap();
+
+    assert!(bench.part1.is_none());
+    assert!(bench.result.part1.is_none());
+    assert_eq!(Answer::Int(51316), bench.result.part2.unwrap().answer);
+}
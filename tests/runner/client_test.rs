@@ -0,0 +1,101 @@
+use std::{fs, time::Duration};
+
+use advent_of_code::runner::{
+    client::{Client, Config},
+    input::{fetch_input, input_path},
+};
+
+use super::mock_server::MockServer;
+
+fn client(server: &MockServer) -> Client {
+    let config = Config {
+        session: Some(String::from("cookie\n")),
+        base_url: Some(server.url.clone() + "/"),
+    };
+    Client::new(&config)
+        .unwrap()
+        .with_delays(Duration::ZERO, Duration::ZERO)
+}
+
+#[test]
+fn client_config() {
+    let path = std::env::temp_dir().join("aoc_client_config.toml");
+    fs::write(&path, "session = \"abc\"\n").unwrap();
+
+    let config = Config::load(&path).unwrap();
+    assert_eq!(Some(String::from("abc")), config.session);
+    assert_eq!(None, config.base_url);
+
+    assert_eq!(
+        Config::default(),
+        Config::load(&path.with_extension("missing")).unwrap()
+    );
+    assert!(Client::new(&Config::default()).is_err());
+}
+
+#[test]
+fn client_fetch() {
+    let server = MockServer::start(&[(200, "12\n14\n")]);
+    let mut client = client(&server);
+    let dir = std::env::temp_dir().join("aoc_client_fetch");
+    let _ = fs::remove_dir_all(&dir);
+
+    assert!(fetch_input(&mut client, &dir, 2019, 1).unwrap());
+    assert_eq!(
+        "12\n14\n",
+        fs::read_to_string(input_path(&dir, 2019, 1)).unwrap()
+    );
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert!(requests[0].starts_with("GET /2019/day/1/input HTTP/1.1\r\n"));
+    assert!(requests[0]
+        .to_lowercase()
+        .contains("cookie: session=cookie\r\n"));
+    assert!(requests[0].contains("github.com/AdamSmith89/advent_of_code"));
+
+    // Cached, so the server isn't asked again
+    assert!(!fetch_input(&mut client, &dir, 2019, 1).unwrap());
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn client_fetch_placeholder() {
+    let server = MockServer::start(&[(200, "input\n")]);
+    let mut client = client(&server);
+    let dir = std::env::temp_dir().join("aoc_client_fetch_placeholder");
+    let path = input_path(&dir, 2025, 2);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "").unwrap();
+
+    assert!(fetch_input(&mut client, &dir, 2025, 2).unwrap());
+    assert_eq!("input\n", fs::read_to_string(path).unwrap());
+}
+
+#[test]
+fn client_retry() {
+    let server = MockServer::start(&[(502, "Bad Gateway"), (200, "input\n")]);
+    let mut client = client(&server);
+
+    assert_eq!("input\n", client.input(2019, 1).unwrap());
+    assert_eq!(2, server.requests().len());
+}
+
+#[test]
+fn client_errors() {
+    let server = MockServer::start(&[(404, "Not Found"), (400, "Please log in")]);
+    let mut client = client(&server);
+    let dir = std::env::temp_dir().join("aoc_client_errors");
+    let _ = fs::remove_dir_all(&dir);
+
+    let error = fetch_input(&mut client, &dir, 2030, 1).unwrap_err();
+    assert!(error.to_string().contains("not be unlocked yet"), "{error}");
+    assert!(!input_path(&dir, 2030, 1).exists());
+
+    let error = client.input(2030, 1).unwrap_err();
+    assert!(
+        error.to_string().contains("session token was rejected"),
+        "{error}"
+    );
+    assert_eq!(2, server.requests().len());
+}
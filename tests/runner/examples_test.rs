@@ -0,0 +1,93 @@
+use advent_of_code::runner::examples::{extract, fill_test, Example, Examples};
+
+const TEMPLATE: &str = include_str!("../../.aoc/test_template.rs");
+
+const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   &lt;3
+</code></pre>
+<p>Pair up <code>1</code> and <code>3</code>, a distance of <em>2</em>.</p>
+<p>In this example, the total distance is <code><em>11</em></code>!</p>
+</article>
+<p>Your puzzle answer was <code>2756096</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using <code><em>7</em></code> on the same example gives <em><code>31</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+fn example(input: &str, answer: &str) -> Option<Example> {
+    Some(Example {
+        blocks: vec![String::from(input)],
+        answers: vec![String::from(answer)],
+    })
+}
+
+#[test]
+fn examples_extract() {
+    let examples = extract(PAGE);
+
+    let part1 = examples.part1.unwrap();
+    assert_eq!(Some("3   4\n4   <3"), part1.input());
+    assert_eq!(Some("11"), part1.answer());
+
+    let part2 = examples.part2.unwrap();
+    assert_eq!(None, part2.input());
+    assert_eq!(vec!["7", "31"], part2.answers);
+    assert_eq!(Some("31"), part2.answer());
+}
+
+#[test]
+fn examples_extract_part1_only() {
+    let page = PAGE.split("<p>Your puzzle answer").next().unwrap();
+
+    assert_eq!(None, extract(page).part2);
+}
+
+#[test]
+fn examples_fill_test() {
+    let test = fill_test(TEMPLATE, &extract(PAGE));
+
+    assert!(test.contains("const EXAMPLE_INPUT: &str = \"\\\n3   4\n4   <3\n\";\n"));
+    assert!(!test.contains("EXAMPLE_INPUT_PART2"));
+    assert!(test.contains(
+        "let actual = Solution::part1(&input).expect(\"Error solving part 1\");\n\n    \
+         assert_eq!(11, actual);"
+    ));
+    assert!(test.contains(
+        "let actual = Solution::part2(&input).expect(\"Error solving part 2\");\n\n    \
+         assert_eq!(31, actual);"
+    ));
+    // The real input's tests are left to fill in
+    assert!(test.contains("//let _actual = Solution::part1(&parsed)"));
+}
+
+#[test]
+fn examples_fill_test_part2_input() {
+    let examples = Examples {
+        part1: example("1", "2"),
+        part2: example(" \"a\"\n\\b", "a,b"),
+    };
+    let test = fill_test(TEMPLATE, &examples);
+
+    assert!(test.contains("const EXAMPLE_INPUT_PART2: &str = \"\\\n\\u{20}\\\"a\\\"\n\\\\b\n\";\n"));
+    assert!(test.contains("Solution::parse(EXAMPLE_INPUT_PART2)"));
+    assert!(test.contains("assert_eq!(\"a,b\", actual.to_string());"));
+}
+
+#[test]
+fn examples_fill_test_one_trailing_newline() {
+    for input in ["1\n2", "1\n2\n", "1\n2\n\n"] {
+        let examples = Examples {
+            part1: example(input, "3"),
+            part2: None,
+        };
+        let test = fill_test(TEMPLATE, &examples);
+
+        assert!(
+            test.contains("const EXAMPLE_INPUT: &str = \"\\\n1\n2\n\";\n"),
+            "{input:?}: {test}"
+        );
+    }
+}
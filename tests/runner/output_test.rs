@@ -0,0 +1,177 @@
+use advent_of_code::{
+    answer::{Answer, Bitmap},
+    runner::{
+        answers::Verdict,
+        output::{Formatter, OutputFormat, Record, Summary},
+    },
+    solver::Solver,
+    year2019::day08,
+};
+
+fn solved_record() -> Record {
+    Record {
+        year: 2024,
+        day: 18,
+        part1: Some(Answer::Int(250)),
+        part2: Some(Answer::from("56,8")),
+        parse_time: Some(0.5),
+        part1_time: Some(0.25),
+        part2_time: Some(0.125),
+        total_time: Some(0.875),
+        error: None,
+        part1_verdict: None,
+        part2_verdict: None,
+    }
+}
+
+fn missing_record() -> Record {
+    Record {
+        year: 2024,
+        day: 19,
+        part1: None,
+        part2: None,
+        parse_time: None,
+        part1_time: None,
+        part2_time: None,
+        total_time: None,
+        error: Some(String::from("missing input")),
+        part1_verdict: None,
+        part2_verdict: None,
+    }
+}
+
+fn summary() -> Summary {
+    Summary {
+        solved: 1,
+        missing: 1,
+        failed: 0,
+        total_time: 0.875,
+        wall_time: 0.875,
+    }
+}
+
+#[test]
+fn output_json() {
+    let mut formatter = Formatter::new(OutputFormat::Json, false);
+    let mut output = formatter.header();
+    output += &formatter.record(&solved_record());
+    output += &formatter.record(&missing_record());
+    output += &formatter.footer(&summary());
+
+    let json: serde_json::Value = serde_json::from_str(&output).expect("Output should be JSON");
+
+    assert_eq!(2, json.as_array().unwrap().len());
+    assert_eq!("56,8", json[0]["part2"]);
+    assert_eq!(0.875, json[0]["total_time"]);
+    assert!(json[1]["part1"].is_null());
+    assert_eq!("missing input", json[1]["error"]);
+}
+
+#[test]
+fn output_json_answer_kinds() {
+    let mut formatter = Formatter::new(OutputFormat::Json, false);
+    let mut record = solved_record();
+    record.part2 = Some(Answer::from("#.#\n.#.".parse::<Bitmap>().unwrap()));
+
+    let output = formatter.header() + &formatter.record(&record) + &formatter.footer(&summary());
+    let json: serde_json::Value = serde_json::from_str(&output).expect("Output should be JSON");
+
+    assert_eq!(250, json[0]["part1"]);
+    assert_eq!(serde_json::json!(["#.#", ".#."]), json[0]["part2"]);
+}
+
+#[test]
+fn output_table_bitmap() {
+    let mut formatter = Formatter::new(OutputFormat::Table, false);
+    let mut record = solved_record();
+    record.part2 = Some(Answer::from("#.#\n.#.".parse::<Bitmap>().unwrap()));
+
+    let lines = formatter.record(&record);
+    let lines = lines.lines().collect::<Vec<_>>();
+
+    assert_eq!(3, lines.len());
+    assert!(lines[0].contains("3x2 bitmap"), "{}", lines[0]);
+    assert!(lines[1].ends_with("┃ #.#"));
+    assert!(lines[2].ends_with("┃ .#."));
+}
+
+#[test]
+fn output_table_solver_bitmap() {
+    // Letters that can't be read come back from the solver as the picture
+    let input = "1000".repeat(6 * 25 / 4 + 1);
+    let input = day08::Solution::parse(&input[..6 * 25]).unwrap();
+    let mut formatter = Formatter::new(OutputFormat::Table, false);
+    let mut record = solved_record();
+    record.part2 = Some(day08::Solution::part2(&input).unwrap());
+
+    let lines = formatter.record(&record);
+    let lines = lines.lines().collect::<Vec<_>>();
+
+    assert_eq!(7, lines.len());
+    assert!(lines[0].contains("25x6 bitmap"), "{}", lines[0]);
+    assert!(lines[1].ends_with("┃ #...#...#...#...#...#...#"));
+    assert!(lines[2].ends_with("┃ ...#...#...#...#...#...#."));
+}
+
+#[test]
+fn output_json_empty() {
+    let formatter = Formatter::new(OutputFormat::Json, false);
+    let output = formatter.header() + &formatter.footer(&Summary::default());
+
+    assert_eq!("[\n]\n", output);
+}
+
+#[test]
+fn output_csv() {
+    let mut formatter = Formatter::new(OutputFormat::Csv, false);
+
+    assert_eq!(
+        "year,day,part1,part2,parse_time,part1_time,part2_time,total_time,error\n",
+        formatter.header()
+    );
+    assert_eq!(
+        "2024,18,250,\"56,8\",0.5,0.25,0.125,0.875,\n",
+        formatter.record(&solved_record())
+    );
+    assert_eq!(
+        "2024,19,,,,,,,missing input\n",
+        formatter.record(&missing_record())
+    );
+    assert_eq!("", formatter.footer(&summary()));
+}
+
+#[test]
+fn output_markdown() {
+    let mut formatter = Formatter::new(OutputFormat::Markdown, false);
+    let mut record = solved_record();
+    record.part1 = Some(Answer::from("a|b"));
+
+    assert!(formatter.header().starts_with("| Year | Day |"));
+    assert_eq!(
+        "| 2024 | 18 | a\\|b | 56,8 | 0.5 | 0.25 | 0.125 | 0.875 |\n",
+        formatter.record(&record)
+    );
+    assert_eq!(
+        "| 2024 | 19 | missing input | - | - | - | - | - |\n",
+        formatter.record(&missing_record())
+    );
+}
+
+#[test]
+fn output_csv_verify() {
+    let mut formatter = Formatter::new(OutputFormat::Csv, true);
+    let record = solved_record().with_verdicts((Some(Verdict::Correct), Some(Verdict::Wrong)));
+
+    assert!(formatter
+        .header()
+        .ends_with(",error,part1_verdict,part2_verdict\n"));
+    assert_eq!(Some(Verdict::Wrong), record.verdict());
+    assert_eq!(
+        "2024,18,250,\"56,8\",0.5,0.25,0.125,0.875,,correct,wrong\n",
+        formatter.record(&record)
+    );
+    assert_eq!(
+        "2024,19,,,,,,,missing input,,\n",
+        formatter.record(&missing_record())
+    );
+}
@@ -0,0 +1,60 @@
+use std::{ops::ControlFlow, thread, time::Duration};
+
+use advent_of_code::runner::pool::run_ordered;
+
+#[test]
+fn pool_emits_in_order() {
+    let items = (0..8u64).collect::<Vec<_>>();
+    let mut emitted = Vec::new();
+
+    // Later items finish first
+    run_ordered(
+        &items,
+        4,
+        |item| {
+            thread::sleep(Duration::from_millis(40 - item * 5));
+            item * 10
+        },
+        |item, result| {
+            emitted.push((*item, result));
+            ControlFlow::Continue(())
+        },
+    );
+
+    assert_eq!(
+        items
+            .iter()
+            .map(|item| (*item, item * 10))
+            .collect::<Vec<_>>(),
+        emitted
+    );
+}
+
+#[test]
+fn pool_stops_on_break() {
+    let items = (0..100).collect::<Vec<_>>();
+    let mut emitted = Vec::new();
+
+    run_ordered(
+        &items,
+        2,
+        |item| *item,
+        |item, _| {
+            emitted.push(*item);
+            if *item == 3 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        },
+    );
+
+    assert_eq!(vec![0, 1, 2, 3], emitted);
+}
+
+#[test]
+fn pool_empty() {
+    let items: Vec<u32> = Vec::new();
+
+    run_ordered(&items, 4, |item| *item, |_, _| panic!("Nothing to emit"));
+}
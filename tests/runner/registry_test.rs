@@ -0,0 +1,29 @@
+use advent_of_code::{answer::Answer, registry};
+
+#[test]
+fn registry_puzzles_in_order() {
+    let puzzles = registry::puzzles();
+
+    assert!(!puzzles.is_empty());
+    assert!(puzzles
+        .windows(2)
+        .all(|pair| (pair[0].year, pair[0].day) < (pair[1].year, pair[1].day)));
+}
+
+#[test]
+fn registry_find() {
+    let puzzle = registry::find(2019, 1).expect("year2019::day01 should be registered");
+
+    assert_eq!("year2019::day01", puzzle.to_string());
+    assert!(registry::find(2019, 26).is_none());
+}
+
+#[test]
+fn registry_solve() {
+    let puzzle = registry::find(2019, 1).expect("year2019::day01 should be registered");
+    let answers = puzzle
+        .solve("12\n14\n1969\n100756")
+        .expect("Error solving puzzle");
+
+    assert_eq!((Answer::Int(34241), Answer::Int(51316)), answers);
+}
@@ -0,0 +1,155 @@
+use std::fs;
+
+use advent_of_code::runner::{
+    examples::{Example, Examples},
+    scaffold::{register_test, Scaffold, Status},
+};
+
+const TESTS: &str = "\
+mod runner {
+    mod pool_test;
+}
+
+mod year2023 {
+    mod day01_test;
+    mod day03_test;
+}
+
+mod year2025 {
+    mod day01_test;
+    // NEXT
+}
+";
+
+#[test]
+fn register_test_in_order() {
+    let registered = register_test(TESTS, 2023, 2).unwrap().unwrap();
+
+    assert!(registered.contains("    mod day01_test;\n    mod day02_test;\n    mod day03_test;\n"));
+}
+
+#[test]
+fn register_test_before_comments() {
+    let registered = register_test(TESTS, 2025, 2).unwrap().unwrap();
+
+    assert!(registered.ends_with("    mod day01_test;\n    mod day02_test;\n    // NEXT\n}\n"));
+}
+
+#[test]
+fn register_test_new_year() {
+    let registered = register_test(TESTS, 2024, 1).unwrap().unwrap();
+    assert!(registered.contains("}\n\nmod year2024 {\n    mod day01_test;\n}\n\nmod year2025 {\n"));
+
+    let registered = register_test(TESTS, 2026, 1).unwrap().unwrap();
+    assert!(registered.ends_with("}\n\nmod year2026 {\n    mod day01_test;\n}\n"));
+}
+
+#[test]
+fn register_test_existing() {
+    assert!(register_test(TESTS, 2023, 3).unwrap().is_none());
+}
+
+#[test]
+fn register_test_unclosed() {
+    assert!(register_test("mod year2023 {\n    mod day01_test;\n", 2023, 2).is_err());
+}
+
+#[test]
+fn scaffold_idempotent() {
+    let root = std::env::temp_dir().join("aoc_scaffold_idempotent");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join(".aoc")).unwrap();
+    fs::create_dir_all(root.join("tests")).unwrap();
+    fs::write(root.join(".aoc/template.rs"), "pub struct Solution;\n").unwrap();
+    fs::write(
+        root.join(".aoc/test_template.rs"),
+        "use advent_of_code::YYYY::DD::*;\n",
+    )
+    .unwrap();
+    fs::write(root.join("tests/test.rs"), TESTS).unwrap();
+
+    let mut scaffold = Scaffold::new(&root, 2024, 7);
+    let statuses = scaffold
+        .run()
+        .unwrap()
+        .into_iter()
+        .map(|(_, status)| status)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            Status::Created,
+            Status::Created,
+            Status::Updated,
+            Status::Created
+        ],
+        statuses
+    );
+    assert!(scaffold.is_changed());
+    assert_eq!(
+        "use advent_of_code::year2024::day07::*;\n",
+        fs::read_to_string(root.join("tests/year2024/day07_test.rs")).unwrap()
+    );
+    assert_eq!(
+        "",
+        fs::read_to_string(root.join("input/year2024/day07.txt")).unwrap()
+    );
+
+    let mut again = Scaffold::new(&root, 2024, 7);
+    let steps = again.run().unwrap();
+    assert!(steps.iter().all(|(_, status)| *status == Status::Exists));
+    assert!(!again.is_changed());
+
+    scaffold.undo().unwrap();
+    assert!(!root.join("src").exists());
+    assert!(!root.join("tests/year2024").exists());
+    assert!(!root.join("input").exists());
+    assert_eq!(
+        TESTS,
+        fs::read_to_string(root.join("tests/test.rs")).unwrap()
+    );
+}
+
+#[test]
+fn scaffold_examples() {
+    let root = std::env::temp_dir().join("aoc_scaffold_examples");
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join(".aoc")).unwrap();
+    fs::create_dir_all(root.join("tests")).unwrap();
+    fs::write(root.join(".aoc/template.rs"), "").unwrap();
+    fs::write(
+        root.join(".aoc/test_template.rs"),
+        include_str!("../../.aoc/test_template.rs"),
+    )
+    .unwrap();
+    fs::write(root.join("tests/test.rs"), TESTS).unwrap();
+    let test_path = root.join("tests/year2024/day07_test.rs");
+
+    Scaffold::new(&root, 2024, 7).run().unwrap();
+    let generated = fs::read_to_string(&test_path).unwrap();
+
+    // Still as generated, so the examples are filled in
+    let examples = Examples {
+        part1: Some(Example {
+            blocks: vec![String::from("1 2")],
+            answers: vec![String::from("3")],
+        }),
+        part2: None,
+    };
+    let mut scaffold = Scaffold::new(&root, 2024, 7).with_examples(examples.clone());
+    let steps = scaffold.run().unwrap();
+    assert_eq!(Status::Updated, steps[1].1);
+    let filled = fs::read_to_string(&test_path).unwrap();
+    assert!(filled.contains("assert_eq!(3, actual);"));
+
+    scaffold.undo().unwrap();
+    assert_eq!(generated, fs::read_to_string(&test_path).unwrap());
+
+    // Edited since, so it's left alone
+    fs::write(&test_path, "// edited\n").unwrap();
+    let steps = Scaffold::new(&root, 2024, 7)
+        .with_examples(examples)
+        .run()
+        .unwrap();
+    assert_eq!(Status::Exists, steps[1].1);
+    assert_eq!("// edited\n", fs::read_to_string(&test_path).unwrap());
+}
@@ -0,0 +1,121 @@
+use advent_of_code::{
+    registry,
+    runner::select::{select, Selector},
+};
+
+fn selected(selectors: &[&str]) -> Vec<String> {
+    let selectors = selectors
+        .iter()
+        .map(|selector| selector.parse::<Selector>().unwrap())
+        .collect::<Vec<_>>();
+
+    select(&selectors)
+        .unwrap()
+        .iter()
+        .map(|puzzle| puzzle.to_string())
+        .collect()
+}
+
+#[test]
+fn select_all() {
+    let finished = registry::puzzles()
+        .iter()
+        .filter(|puzzle| !puzzle.wip)
+        .count();
+    assert_eq!(finished, selected(&[]).len());
+}
+
+#[test]
+fn select_wip() {
+    // year2023::day21 is a work in progress
+    assert!(registry::find(2023, 21).unwrap().wip);
+    assert!(!selected(&[]).contains(&String::from("year2023::day21")));
+    assert!(!selected(&["2023"]).contains(&String::from("year2023::day21")));
+    assert!(!selected(&["2023:20-22"]).contains(&String::from("year2023::day21")));
+    assert_eq!(vec!["year2023::day21"], selected(&["2023/21"]));
+}
+
+#[test]
+fn select_year() {
+    let puzzles = selected(&["2019"]);
+
+    assert!(!puzzles.is_empty());
+    assert!(puzzles
+        .iter()
+        .all(|puzzle| puzzle.starts_with("year2019::")));
+    assert_eq!(puzzles, selected(&["year2019"]));
+}
+
+#[test]
+fn select_day() {
+    for selector in [
+        "2019/1",
+        "2019:1",
+        "2019::1",
+        "year2019::day01",
+        "2019::day01",
+    ] {
+        assert_eq!(vec!["year2019::day01"], selected(&[selector]), "{selector}");
+    }
+}
+
+#[test]
+fn select_range() {
+    assert_eq!(
+        vec!["year2019::day01", "year2019::day02", "year2019::day03"],
+        selected(&["2019:1-3"])
+    );
+}
+
+#[test]
+fn select_list() {
+    assert_eq!(
+        vec!["year2024::day05", "year2024::day07", "year2024::day09"],
+        selected(&["2024:5,7,9"])
+    );
+}
+
+#[test]
+fn select_any_year() {
+    let puzzles = selected(&["*:1"]);
+
+    assert!(puzzles.contains(&String::from("year2019::day01")));
+    assert!(puzzles.contains(&String::from("year2024::day01")));
+    assert!(puzzles.iter().all(|puzzle| puzzle.ends_with("::day01")));
+}
+
+#[test]
+fn select_many() {
+    assert_eq!(
+        vec!["year2019::day01", "year2024::day01"],
+        selected(&["2024/1", "2019/1", "2019:1"])
+    );
+}
+
+#[test]
+fn select_single() {
+    let single = |selector: &str| selector.parse::<Selector>().unwrap().single();
+
+    assert_eq!(Some((2025, 2)), single("2025/2"));
+    assert_eq!(Some((2030, 1)), single("year2030::day01"));
+    assert_eq!(None, single("2025"));
+    assert_eq!(None, single("2025:1-2"));
+    assert_eq!(None, single("*:1"));
+}
+
+#[test]
+fn select_invalid() {
+    assert!("2023:26".parse::<Selector>().is_err());
+    assert!("2023:x".parse::<Selector>().is_err());
+    assert!("twenty".parse::<Selector>().is_err());
+}
+
+#[test]
+fn select_no_such_puzzle() {
+    for selector in ["2019:20", "2019:1,20", "2016"] {
+        let Err(error) = select(&[selector.parse().unwrap()]) else {
+            panic!("{selector} shouldn't select anything");
+        };
+        assert!(error.to_string().starts_with("No such puzzle"), "{error}");
+    }
+}
@@ -0,0 +1,194 @@
+use std::{fs, process::Command, time::Duration};
+
+use advent_of_code::runner::{
+    client::{Client, Config},
+    solve::Part,
+    submit::{parse_response, Response, SubmissionLog},
+};
+
+use super::mock_server::MockServer;
+
+fn page(message: &str) -> String {
+    format!(
+        "<html><body><header><p>adventofcode</p></header><main>\n\
+         <article><p>{message}</p></article>\n</main></body></html>"
+    )
+}
+
+#[test]
+fn submit_parse_response() {
+    let responses = [
+        (
+            "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+            Response::Correct,
+        ),
+        (
+            "That's not the right answer; your answer is too high.  If you're stuck, ...",
+            Response::TooHigh,
+        ),
+        (
+            "That's not the right answer; your answer is too low.  Please wait one minute.",
+            Response::TooLow,
+        ),
+        (
+            "That's not the right answer.  If you're stuck, make sure you're using the full input",
+            Response::Wrong,
+        ),
+        (
+            "You gave an answer too recently; you have to wait after submitting an answer before \
+             trying again.  You have 4m 37s left to wait. <a href=\"/2019/day/1\">[Return]</a>",
+            Response::RateLimited {
+                wait: Duration::from_secs(277),
+            },
+        ),
+        (
+            "You gave an answer too recently.  You have 9s left to wait.",
+            Response::RateLimited {
+                wait: Duration::from_secs(9),
+            },
+        ),
+        (
+            "You don't seem to be solving the right level.  Did you already complete it?",
+            Response::WrongLevel,
+        ),
+    ];
+
+    for (message, expected) in responses {
+        assert_eq!(
+            expected,
+            parse_response(&page(message)).unwrap(),
+            "{message}"
+        );
+    }
+    assert!(parse_response(&page("Something else")).is_err());
+}
+
+#[test]
+fn submit_display() {
+    assert_eq!("too high", Response::TooHigh.to_string());
+    assert_eq!(
+        "rate limited, wait 4m 37s",
+        Response::RateLimited {
+            wait: Duration::from_secs(277)
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn submit_known_response() {
+    let mut log = SubmissionLog::default();
+    let puzzle = "year2019::day01";
+    log.record(puzzle, Part::One, "500", Response::TooHigh);
+    log.record(puzzle, Part::One, "100", Response::TooLow);
+    log.record(puzzle, Part::One, "abc", Response::Wrong);
+    log.record(
+        puzzle,
+        Part::One,
+        "200",
+        Response::RateLimited {
+            wait: Duration::from_secs(1),
+        },
+    );
+
+    assert_eq!(3, log.submissions(puzzle, Part::One).len());
+    assert_eq!(
+        Some(Response::Wrong),
+        log.known_response(puzzle, Part::One, "abc")
+    );
+    assert_eq!(
+        Some(Response::TooHigh),
+        log.known_response(puzzle, Part::One, "600")
+    );
+    assert_eq!(
+        Some(Response::TooLow),
+        log.known_response(puzzle, Part::One, "50")
+    );
+    assert_eq!(None, log.known_response(puzzle, Part::One, "200"));
+    assert_eq!(None, log.known_response(puzzle, Part::Two, "500"));
+
+    log.record(puzzle, Part::One, "300", Response::Correct);
+    assert_eq!(
+        Some(Response::Correct),
+        log.known_response(puzzle, Part::One, "300")
+    );
+    assert_eq!(
+        Some(Response::TooHigh),
+        log.known_response(puzzle, Part::One, "301")
+    );
+    assert_eq!(
+        Some(Response::Wrong),
+        log.known_response(puzzle, Part::One, "xyz")
+    );
+}
+
+#[test]
+fn submit_log_save() {
+    let path = std::env::temp_dir()
+        .join("aoc_submit_log_save")
+        .join("submissions.toml");
+    let _ = fs::remove_file(&path);
+
+    let mut log = SubmissionLog::load(&path).unwrap();
+    log.record("year2024::day16", Part::Two, "42", Response::TooLow);
+    log.save(&path).unwrap();
+
+    let log = SubmissionLog::load(&path).unwrap();
+    assert_eq!(
+        Some(Response::TooLow),
+        log.known_response("year2024::day16", Part::Two, "42")
+    );
+}
+
+#[test]
+fn submit_answer() {
+    let server = MockServer::start(&[(200, &page("That's the right answer!"))]);
+    let config = Config {
+        session: Some(String::from("cookie")),
+        base_url: Some(server.url.clone()),
+    };
+    let mut client = Client::new(&config).unwrap();
+
+    let html = client.answer(2019, 1, Part::Two, "51316").unwrap();
+    assert_eq!(Response::Correct, parse_response(&html).unwrap());
+
+    let request = &server.requests()[0];
+    assert!(request.starts_with("POST /2019/day/1/answer HTTP/1.1\r\n"));
+    assert!(request.ends_with("\r\n\r\nlevel=2&answer=51316"));
+}
+
+#[test]
+fn submit_command() {
+    let dir = std::env::temp_dir().join("aoc_submit_command");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("input.txt"), "12\n14\n1969\n100756").unwrap();
+
+    let server = MockServer::start(&[(200, &page("That's the right answer!"))]);
+    let submit = || {
+        Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+            .arg("--input")
+            .arg(dir.join("input.txt"))
+            .arg("--answers-dir")
+            .arg(dir.join("answers"))
+            .args(["submit", "2019/1", "--part", "1"])
+            .env("AOC_SESSION", "cookie")
+            .env("AOC_BASE_URL", &server.url)
+            .output()
+            .unwrap()
+    };
+
+    let output = submit();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("34241 is correct"));
+    assert_eq!(
+        "[day01]\npart1 = \"34241\"\n",
+        fs::read_to_string(dir.join("answers/2019.toml")).unwrap()
+    );
+
+    // Known to be correct, so it isn't submitted again
+    let output = submit();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already been accepted"));
+    assert_eq!(1, server.requests().len());
+}
@@ -0,0 +1,108 @@
+use std::{path::Path, time::Duration};
+
+use advent_of_code::{
+    answer::Answer,
+    registry,
+    runner::{
+        solve::Part,
+        worker::{Outcome, Worker},
+    },
+};
+
+fn worker(bench_runs: Option<u32>) -> Worker {
+    Worker::new(
+        Path::new(env!("CARGO_BIN_EXE_advent_of_code")),
+        bench_runs,
+        false,
+    )
+}
+
+#[test]
+fn worker_solved() {
+    let puzzle = registry::find(2019, 1).unwrap();
+
+    let Outcome::Solved(result) = worker(None).run(puzzle, "12\n14\n1969\n100756") else {
+        panic!("Puzzle should be solved");
+    };
+    assert_eq!(Answer::Int(34241), result.part1.unwrap().answer);
+    assert_eq!(Answer::Int(51316), result.part2.unwrap().answer);
+}
+
+#[test]
+fn worker_error() {
+    let puzzle = registry::find(2019, 1).unwrap();
+
+    let Outcome::Failed { error, progress } = worker(None).run(puzzle, "12\nabc") else {
+        panic!("Puzzle should fail");
+    };
+    assert!(error.starts_with("parse: "), "{error}");
+    assert!(progress.parse_time_s.is_none());
+}
+
+#[test]
+fn worker_panic() {
+    // There's no password after 999999, which the solver panics on by design
+    let puzzle = registry::find(2019, 4).unwrap();
+
+    let Outcome::Failed { error, progress } = worker(None).run(puzzle, "999999-999999") else {
+        panic!("Puzzle should fail");
+    };
+    assert!(
+        error.starts_with("part1: panicked at src/year2019/day04.rs"),
+        "{error}"
+    );
+    assert!(error.ends_with("All digits are unexpectedly 9!"), "{error}");
+    assert!(progress.parse_time_s.is_some());
+    assert!(progress.part1.is_none());
+}
+
+#[test]
+fn worker_bench() {
+    let puzzle = registry::find(2019, 1).unwrap();
+
+    let Outcome::Benched(bench) = worker(Some(2)).run(puzzle, "12\n14\n1969\n100756") else {
+        panic!("Puzzle should be benchmarked");
+    };
+    assert_eq!(Answer::Int(51316), bench.result.part2.unwrap().answer);
+}
+
+#[test]
+fn worker_timeout() {
+    let puzzle = registry::find(2019, 1).unwrap();
+    let worker = worker(None).with_timeout(Some(Duration::from_nanos(1)));
+
+    let Outcome::Failed { error, .. } = worker.run(puzzle, "12\n14\n1969\n100756") else {
+        panic!("Puzzle should time out");
+    };
+    assert!(error.starts_with("parse: TIMEOUT"), "{error}");
+}
+
+#[test]
+fn worker_puzzle_timeout() {
+    let mut puzzle = registry::find(2019, 1).unwrap().clone();
+    puzzle.timeout = Duration::from_nanos(1);
+
+    let Outcome::Failed { error, .. } = worker(None).run(&puzzle, "12\n14\n1969\n100756") else {
+        panic!("Puzzle should time out");
+    };
+    assert!(error.starts_with("parse: TIMEOUT"), "{error}");
+
+    // Which --timeout can extend
+    let worker = worker(None).with_timeout(Some(Duration::from_secs(60)));
+    assert!(matches!(
+        worker.run(&puzzle, "12\n14\n1969\n100756"),
+        Outcome::Solved(_)
+    ));
+}
+
+#[test]
+fn worker_single_part() {
+    let puzzle = registry::find(2019, 1).unwrap();
+    let worker = worker(None).with_part(Some(Part::Two));
+
+    let Outcome::Solved(result) = worker.run(puzzle, "12\n14\n1969\n100756") else {
+        panic!("Puzzle should be solved");
+    };
+    assert!(result.part1.is_none());
+    assert_eq!(Answer::Int(51316), result.part2.unwrap().answer);
+}
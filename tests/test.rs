@@ -1,5 +1,23 @@
+mod runner {
+    mod answer_test;
+    mod answers_test;
+    mod bench_test;
+    mod client_test;
+    mod examples_test;
+    mod mock_server;
+    mod output_test;
+    mod pool_test;
+    mod registry_test;
+    mod scaffold_test;
+    mod select_test;
+    mod submit_test;
+    mod worker_test;
+}
+
 mod util {
     mod direction_test;
+    mod ocr_test;
+    mod pos_test;
 }
 
 mod year2019 {
@@ -68,5 +86,4 @@ mod year2024 {
 
 mod year2025 {
     mod day01_test;
-    // NEXT
 }
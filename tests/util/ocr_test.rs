@@ -0,0 +1,92 @@
+use advent_of_code::{
+    error::AdventError,
+    util::{grid::Grid, ocr},
+};
+
+const SMALL: &str = "\
+..............................
+.####.#..#.###...##...##..###.
+....#.#..#.#..#.#..#.#..#.#..#
+...#..####.###..#..#.#....#..#
+..#...#..#.#..#.####.#....###.
+.#....#..#.#..#.#..#.#..#.#.#.
+.####.#..#.###..#..#..##..#..#
+..............................";
+
+const LARGE: &str = "\
+#....#..#####....####.
+#....#..#....#..#....#
+.#..#...#....#..#.....
+.#..#...#....#..#.....
+..##....#####...#.....
+..##....#....#..#..###
+.#..#...#....#..#....#
+.#..#...#....#..#....#
+#....#..#....#..#...##
+#....#..#####....###.#";
+
+#[test]
+fn ocr_read_small_chars() {
+    let grid = Grid::<char>::try_from(SMALL).unwrap();
+
+    assert_eq!(Ok(String::from("ZHBACR")), ocr::read(&grid));
+}
+
+#[test]
+fn ocr_read_small_bools() {
+    let pixels = SMALL
+        .lines()
+        .flat_map(|line| line.chars().map(|ch| ch == '#'))
+        .collect();
+    let grid = Grid::from_vec(pixels, 30);
+
+    assert_eq!(Ok(String::from("ZHBACR")), ocr::read(&grid));
+}
+
+#[test]
+fn ocr_read_block_pixels() {
+    let grid = Grid::<char>::try_from(SMALL.replace('#', "█").replace('.', " ").as_str()).unwrap();
+
+    assert_eq!(Ok(String::from("ZHBACR")), ocr::read(&grid));
+}
+
+#[test]
+fn ocr_read_large() {
+    let grid = Grid::<char>::try_from(LARGE).unwrap();
+
+    assert_eq!(Ok(String::from("XBG")), ocr::read(&grid));
+}
+
+#[test]
+fn ocr_read_unknown_glyphs() {
+    let grid = Grid::<char>::try_from(
+        "\
+#..#.#...#.####
+#..#.##.##.#...
+####.#.#.#.###.
+#..#.#...#.#...
+#..#.#...#.#...
+#..#.#...#.####",
+    )
+    .unwrap();
+
+    let error = ocr::read(&grid).unwrap_err();
+    assert_eq!(
+        AdventError::UnknownGlyphs(vec![String::from(
+            "Letter 2:\n#...#\n##.##\n#.#.#\n#...#\n#...#\n#...#"
+        )]),
+        error
+    );
+    assert!(error.to_string().contains("Letter 2:"));
+}
+
+#[test]
+fn ocr_read_unknown_height() {
+    let grid = Grid::<char>::try_from("#.#\n###\n#.#").unwrap();
+
+    assert!(matches!(
+        ocr::read(&grid),
+        Err(AdventError::UnknownPattern(_))
+    ));
+    assert!(ocr::read(&Grid::init(6, 4, false)).is_err());
+}
@@ -0,0 +1,108 @@
+use advent_of_code::util::{
+    direction::{Direction, DirectionEx},
+    grid::Grid,
+    point::{Point, PointSig},
+    pos::*,
+};
+
+const GRID: &str = "\
+abc
+def";
+
+#[test]
+fn pos_arithmetic() {
+    let pos = Pos::new(2, 3);
+    let by = Vec2::new(1, -2);
+
+    assert_eq!(Pos::new(3, 1), pos + by);
+    assert_eq!(Pos::new(1, 5), pos - by);
+    assert_eq!(Vec2::new(1, 1), Pos::new(3, 4) - pos);
+    assert_eq!(Vec2::new(3, -6), by * 3);
+    assert_eq!(Vec2::new(-1, 2), -by);
+    assert_eq!(Vec2::new(2, -4), by + by);
+    assert_eq!(Vec2::new(0, 0), by - by);
+
+    let mut moving = pos;
+    moving += by;
+    assert_eq!(Pos::new(3, 1), moving);
+    moving -= by * 2;
+    assert_eq!(Pos::new(1, 5), moving);
+}
+
+#[test]
+fn pos_step() {
+    let pos = Pos::new(1, 1);
+
+    assert_eq!(Pos::new(0, 1), pos.step(Direction::North));
+    assert_eq!(Pos::new(1, 2), pos.step(Direction::East));
+    assert_eq!(Pos::new(2, 1), pos + Direction::South);
+    assert_eq!(Pos::new(1, 0), pos + Direction::West);
+    assert_eq!(Pos::new(0, 2), pos.step(DirectionEx::NorthEast));
+    assert_eq!(Pos::new(2, 0), pos.step(DirectionEx::SouthWest));
+    assert_eq!(Pos::new(3, 0), pos.step(Vec2::new(2, -1)));
+}
+
+#[test]
+fn pos_to_index() {
+    assert_eq!(Some((2, 3)), Pos::new(2, 3).to_index());
+    assert_eq!(None, Pos::new(-1, 3).to_index());
+    assert_eq!(None, Pos::new(2, -1).to_index());
+
+    assert_eq!(Ok((2, 3)), <(usize, usize)>::try_from(Pos::new(2, 3)));
+    assert_eq!(
+        Err(Pos::new(0, -1)),
+        <(usize, usize)>::try_from(Pos::new(0, -1))
+    );
+    assert_eq!(Pos::new(2, 3), Pos::from((2, 3)));
+}
+
+#[test]
+fn pos_from_point() {
+    assert_eq!(Pos::new(2, 3), Pos::from(Point::from((2, 3))));
+    assert_eq!(Pos::new(-2, 3), Pos::from(PointSig::from((-2, 3))));
+}
+
+#[test]
+fn pos_is_within() {
+    assert!(Pos::new(0, 0).is_within(2, 3));
+    assert!(Pos::new(1, 2).is_within(2, 3));
+    assert!(!Pos::new(2, 2).is_within(2, 3));
+    assert!(!Pos::new(1, 3).is_within(2, 3));
+    assert!(!Pos::new(-1, 0).is_within(2, 3));
+}
+
+#[test]
+fn grid_accepts_pos() {
+    let mut grid = Grid::<char>::try_from(GRID).unwrap();
+
+    assert_eq!('f', grid[Pos::new(1, 2)]);
+    assert_eq!(Some(&'b'), grid.get_at(Pos::new(0, 1)));
+    assert_eq!(None, grid.get_at(Pos::new(-1, 1)));
+    assert_eq!(None, grid.get_at(Pos::new(2, 0)));
+    assert!(grid.is_in_bounds(Pos::new(1, 0)));
+    assert!(!grid.is_in_bounds(Pos::new(0, -1)));
+    assert_eq!(Some((0, 0)), grid.north_of(Pos::new(1, 0)));
+    assert_eq!(None, grid.north_of(Pos::new(0, 0)));
+    assert_eq!(Some(&'d'), grid.get_at(Point::from((1, 0))));
+    assert_eq!(Some(&'c'), grid.get_at(PointSig::from((0, 2))));
+    assert_eq!(None, grid.get_at(PointSig::from((0, -1))));
+
+    grid[Pos::new(0, 0)] = 'z';
+    assert_eq!('z', grid[(0, 0)]);
+
+    grid.swap(Pos::new(0, 0), (1, 1)).unwrap();
+    assert_eq!('e', grid[(0, 0)]);
+    assert_eq!('z', grid[(1, 1)]);
+    assert!(grid.swap(Pos::new(-1, 0), (1, 1)).is_err());
+}
+
+#[test]
+fn grid_step() {
+    let grid = Grid::<char>::try_from(GRID).unwrap();
+
+    assert_eq!(Some((1, 1)), grid.step(Pos::new(0, 1), Direction::South));
+    assert_eq!(Some((0, 2)), grid.step((0, 1), Direction::East));
+    assert_eq!(None, grid.step((0, 1), Direction::North));
+    assert_eq!(None, grid.step((1, 2), Direction::East));
+    assert_eq!(None, grid.step((0, 0), Direction::West));
+}
@@ -1,4 +1,4 @@
-use advent_of_code::year2019::day03::*;
+use advent_of_code::{solver::Solver, year2019::day03::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day03.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -8,7 +8,7 @@ U7,R6,D4,L4
 
 #[test]
 fn parse_example() {
-    let parsed = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     assert_eq!(
         parsed.0,
@@ -73,32 +73,32 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 6);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(answer, 731);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 30);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(answer, 5672);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2019::day05::*;
+use advent_of_code::{solver::Solver, year2019::day05::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day05.txt");
 const EXAMPLE_INPUT: &str = "3,9,101,12,9,10,4,10,99,0,0";
@@ -9,23 +9,23 @@ const EXAMPLE_INPUT: &str = "3,9,101,12,9,10,4,10,99,0,0";
 
 #[test]
 fn parse_example() {
-    let parsed = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     assert_eq!(parsed, vec![3, 9, 101, 12, 9, 10, 4, 10, 99, 0, 0]);
 }
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 13);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(answer, 14522484);
 }
@@ -33,16 +33,16 @@ fn part1_real() {
 // Part 2 tests are handled by tests in int_code_computer.rs
 // #[test]
 // fn part2_example1() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-//     let answer = part2(&input).expect("Error solving part 2");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+//     let answer = Solution::part2(&input).expect("Error solving part 2");
 
 //     assert_eq!(answer, todo!());
 // }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(answer, 4655956);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2019::day06::*;
+use advent_of_code::{solver::Solver, year2019::day06::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day06.txt");
 const EXAMPLE_INPUT_PART1: &str = "\
@@ -31,7 +31,7 @@ I)SAN";
 
 #[test]
 fn parse_example() {
-    let parsed = parse(EXAMPLE_INPUT_PART1).expect("Error parsing input");
+    let parsed = Solution::parse(EXAMPLE_INPUT_PART1).expect("Error parsing input");
 
     assert_eq!(
         vec![
@@ -53,8 +53,8 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT_PART1).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_PART1).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(42, answer);
 }
@@ -72,32 +72,32 @@ COM)A
 B)C
 A)B";
 
-    let input = parse(OUT_OF_ORDER_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(OUT_OF_ORDER_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(6, answer);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(308790, answer);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT_PART2).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT_PART2).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(4, answer);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(472, answer);
 }
@@ -1,4 +1,8 @@
-use advent_of_code::year2019::day08::*;
+use advent_of_code::{
+    answer::{Answer, Bitmap},
+    solver::Solver,
+    year2019::day08::*,
+};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day08.txt");
 //const EXAMPLE_INPUT: &str = "123456789012";
@@ -6,7 +10,7 @@ const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day08.txt");
 // ToDo: how best to test when the layer size is embedded in the parse function?
 // #[test]
 // fn parse_example() {
-//     let parsed = parse(EXAMPLE_INPUT).expect("Error parsing input");
+//     let parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
 //     assert_eq!(
 //         vec![
@@ -19,33 +23,56 @@ const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day08.txt");
 
 // #[test]
 // fn part1_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-//     let answer = part1(&input).expect("Error solving part 1");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+//     let answer = Solution::part1(&input).expect("Error solving part 1");
 //
 //     assert_eq!(1, answer);
 // }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(2210, answer);
 }
 
-// No easily verifiable example for this puzzle
-// #[test]
-// fn part2_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-//     let answer = part2(&input).expect("Error solving part 2");
+// The puzzle's example is too small to hold any letters, so this draws some under a transparent
+// layer instead
+#[test]
+fn part2_example() {
+    let image = "\
+.##...##..####..##..####.
+#..#.#..#.#....#..#.#....
+#....#....###..#....###..
+#....#.##.#....#.##.#....
+#..#.#..#.#....#..#.#....
+.##...###.####..###.####.";
+    let layer = image.lines().collect::<String>();
+    let input = "2".repeat(layer.len()) + &layer.replace('#', "1").replace('.', "0");
 
-//     assert_eq!(todo!("expected"), todo!("actual"));
-// }
+    let input = Solution::parse(&input).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
+
+    assert_eq!(Answer::from("CGEGE"), answer);
+}
+
+// Letters that aren't in the font are given back as the picture
+#[test]
+fn part2_example_picture() {
+    let layer = "#.#..".repeat(30);
+    let input = Solution::parse(&layer.replace('#', "1").replace('.', "0"))
+        .expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
+
+    let picture = "#.#..#.#..#.#..#.#..#.#\n".repeat(6);
+    assert_eq!(Answer::Bitmap(picture.parse::<Bitmap>().unwrap()), answer);
+}
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
-    assert_eq!("CGEGE", answer);
+    assert_eq!(Answer::from("CGEGE"), answer);
 }
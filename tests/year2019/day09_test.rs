@@ -1,4 +1,4 @@
-use advent_of_code::year2019::day09::*;
+use advent_of_code::{solver::Solver, year2019::day09::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day09.txt");
 
@@ -8,39 +8,39 @@ const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day09.txt");
 
 // #[test]
 // fn parse_example() {
-//     let parsed = parse(EXAMPLE_INPUT).expect("Error parsing input");
+//     let parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
 //     assert_eq!(todo!("expected"), todo!("actual"));
 // }
 
 // #[test]
 // fn part1_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-//     let answer = part1(&input).expect("Error solving part 1");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+//     let answer = Solution::part1(&input).expect("Error solving part 1");
 
 //     assert_eq!(todo!("expected"), todo!("actual"));
 // }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(3013554615, answer);
 }
 
 // #[test]
 // fn part2_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-//     let answer = part2(&input).expect("Error solving part 2");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+//     let answer = Solution::part2(&input).expect("Error solving part 2");
 
 //     assert_eq!(todo!("expected"), todo!("actual"));
 // }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(50158, answer);
 }
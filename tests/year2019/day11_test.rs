@@ -1,4 +1,4 @@
-use advent_of_code::year2019::day11::*;
+use advent_of_code::{answer::Answer, solver::Solver, year2019::day11::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day11.txt");
 //const EXAMPLE_INPUT: &str = "";
@@ -6,7 +6,7 @@ const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day11.txt");
 // Input is another IntCode Program so no parse example
 // #[test]
 // fn parse_example() {
-//     let _parsed = parse(EXAMPLE_INPUT).expect("Error parsing input");
+//     let _parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
 //     todo!("assert_eq!(expected, parsed);")
 // }
@@ -14,16 +14,16 @@ const PUZZLE_INPUT: &str = include_str!("../../input/year2019/day11.txt");
 // No example program to generate example output so can't test
 // #[test]
 // fn part1_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-//     let _answer = part1(&input).expect("Error solving part 1");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+//     let _answer = Solution::part1(&input).expect("Error solving part 1");
 
 //     todo!("assert_eq!(expected, answer);")
 // }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(2041, answer);
 }
@@ -31,16 +31,16 @@ fn part1_real() {
 // No example program to test against
 // #[test]
 // fn part2_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-//     let _answer = part2(&input).expect("Error solving part 2");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+//     let _answer = Solution::part2(&input).expect("Error solving part 2");
 
 //     //assert_eq!(expected, answer);
 // }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
-    assert_eq!("ZRZPKEZR", answer);
+    assert_eq!(Answer::from("ZRZPKEZR"), answer);
 }
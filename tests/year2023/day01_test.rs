@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day01::*;
+use advent_of_code::{solver::Solver, year2023::day01::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day01.txt");
 const PART1_EXAMPLE: &str = "\
@@ -9,16 +9,16 @@ treb7uchet";
 
 #[test]
 fn part1_example() {
-    let input = parse(PART1_EXAMPLE).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(PART1_EXAMPLE).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 142);
 }
 
 #[test]
 fn part1_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 54388);
 }
@@ -34,16 +34,16 @@ zoneight234
 
 #[test]
 fn part2_example() {
-    let input = parse(PART2_EXAMPLE).expect("Error parsing input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(PART2_EXAMPLE).expect("Error parsing input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 281);
 }
 
 #[test]
 fn part2_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 53515);
 }
@@ -55,8 +55,8 @@ vfzmncfonexxkzlcstqhxvtwoplsglsix1kpkssfz
 
 #[test]
 fn part2_edge_case() {
-    let input = parse(TROUBLESOME).expect("Error parsing input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(TROUBLESOME).expect("Error parsing input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 16 + 11 + 24);
 }
@@ -1,5 +1,5 @@
 use advent_of_code::error::AdventError;
-use advent_of_code::year2023::day02::*;
+use advent_of_code::{solver::Solver, year2023::day02::*};
 use std::str::FromStr;
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day02.txt");
@@ -35,7 +35,7 @@ fn colour_from_str() {
 #[test]
 fn parse_one() {
     use Colour::*;
-    let parsed = parse("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green")
+    let parsed = Solution::parse("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green")
         .expect("Error parsing input");
 
     let expected = vec![Game {
@@ -56,7 +56,7 @@ fn parse_one() {
 #[test]
 fn parse_two_digit() {
     use Colour::*;
-    let parsed = parse("Game 1: 20 red").expect("Error parsing input");
+    let parsed = Solution::parse("Game 1: 20 red").expect("Error parsing input");
 
     let expected = vec![Game {
         id: 1,
@@ -72,7 +72,7 @@ fn parse_two_digit() {
 fn parse_multiple() {
     use Colour::*;
 
-    let parsed = parse(
+    let parsed = Solution::parse(
         "\
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
@@ -112,32 +112,32 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 8);
 }
 
 #[test]
 fn part1_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 2679);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 2286);
 }
 
 #[test]
 fn part2_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 77607);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day03::*;
+use advent_of_code::{solver::Solver, year2023::day03::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day03.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -20,7 +20,7 @@ fn parse_test() {
 6789ab
 cdefgh
 ";
-    let grid = parse(input).expect("Error parsing input");
+    let grid = Solution::parse(input).expect("Error parsing input");
     assert_eq!(grid[(0, 0)], '0');
     assert_eq!(grid[(1, 5)], 'b');
     assert_eq!(grid[(2, 1)], 'd');
@@ -29,39 +29,39 @@ cdefgh
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 4361);
 }
 
 #[test]
 fn part1_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 557705);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 467835);
 }
 
 #[test]
 fn part2_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 84266818);
 }
 
 #[test]
 fn part1_edge1() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 ........
 .24..4..
@@ -69,13 +69,13 @@ fn part1_edge1() {
     )
     .expect("Error parsing input");
 
-    let answer = part1(&input).expect("Error solving part 1");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
     assert_eq!(answer, 4);
 }
 
 #[test]
 fn part1_edge2() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 ........
 .24$-4..
@@ -83,13 +83,13 @@ fn part1_edge2() {
     )
     .expect("Error parsing input");
 
-    let answer = part1(&input).expect("Error solving part 1");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
     assert_eq!(answer, 28);
 }
 
 #[test]
 fn part1_edge3() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 11....11
 ..$..$..
@@ -97,13 +97,13 @@ fn part1_edge3() {
     )
     .expect("Error parsing input");
 
-    let answer = part1(&input).expect("Error solving part 1");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
     assert_eq!(answer, 44);
 }
 
 #[test]
 fn part1_edge4() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 $......$
 .1....1.
@@ -112,13 +112,13 @@ $......$",
     )
     .expect("Error parsing input");
 
-    let answer = part1(&input).expect("Error solving part 1");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
     assert_eq!(answer, 4);
 }
 
 #[test]
 fn part1_edge5() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 $......$
 .11..11.
@@ -127,13 +127,13 @@ $......$",
     )
     .expect("Error parsing input");
 
-    let answer = part1(&input).expect("Error solving part 1");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
     assert_eq!(answer, 44);
 }
 
 #[test]
 fn part1_edge6() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 $11
 ...
@@ -142,13 +142,13 @@ $11
     )
     .expect("Error parsing input");
 
-    let answer = part1(&input).expect("Error solving part 1");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
     assert_eq!(answer, 22);
 }
 
 #[test]
 fn part1_edge7() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 $..
 .11
@@ -161,38 +161,38 @@ $..
     )
     .expect("Error parsing input");
 
-    let answer = part1(&input).expect("Error solving part 1");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
     assert_eq!(answer, 44);
 }
 
 #[test]
 fn part1_edge8() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 11.$.",
     )
     .expect("Error parsing input");
 
-    let answer = part1(&input).expect("Error solving part 1");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
     assert_eq!(answer, 0);
 }
 
 #[test]
 fn part2_edge1() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 70*...
 ...898",
     )
     .expect("Error parsing input");
 
-    let answer = part2(&input).expect("Error solving part 2");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
     assert_eq!(answer, 62860);
 }
 
 #[test]
 fn part2_edge2() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 *11..
 11...
@@ -201,13 +201,13 @@ fn part2_edge2() {
     )
     .expect("Error parsing input");
 
-    let answer = part2(&input).expect("Error solving part 2");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
     assert_eq!(answer, 242);
 }
 
 #[test]
 fn part2_edge3() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 11...
 *....
@@ -216,13 +216,13 @@ fn part2_edge3() {
     )
     .expect("Error parsing input");
 
-    let answer = part2(&input).expect("Error solving part 2");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
     assert_eq!(answer, 121);
 }
 
 #[test]
 fn part2_edge4() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 ..*..
 11.11
@@ -232,26 +232,26 @@ fn part2_edge4() {
     )
     .expect("Error parsing input");
 
-    let answer = part2(&input).expect("Error solving part 2");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
     assert_eq!(answer, 242);
 }
 
 #[test]
 fn part2_edge5() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 11**11
 11..11",
     )
     .expect("Error parsing input");
 
-    let answer = part2(&input).expect("Error solving part 2");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
     assert_eq!(answer, 242);
 }
 
 #[test]
 fn part2_edge6() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 11..11
 ..**..
@@ -259,13 +259,13 @@ fn part2_edge6() {
     )
     .expect("Error parsing input");
 
-    let answer = part2(&input).expect("Error solving part 2");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
     assert_eq!(answer, 242);
 }
 
 #[test]
 fn part2_edge7() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 ..11..
 ..**..
@@ -273,25 +273,25 @@ fn part2_edge7() {
     )
     .expect("Error parsing input");
 
-    let answer = part2(&input).expect("Error solving part 2");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
     assert_eq!(answer, 242);
 }
 
 #[test]
 fn part2_edge8() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 11*11",
     )
     .expect("Error parsing input");
 
-    let answer = part2(&input).expect("Error solving part 2");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
     assert_eq!(answer, 121);
 }
 
 #[test]
 fn part2_edge9() {
-    let input = parse(
+    let input = Solution::parse(
         "\
 .481
 .*..
@@ -299,6 +299,6 @@ fn part2_edge9() {
     )
     .expect("Error parsing input");
 
-    let answer = part2(&input).expect("Error solving part 2");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
     assert_eq!(answer, 109668);
 }
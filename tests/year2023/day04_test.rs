@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day04::*;
+use advent_of_code::{solver::Solver, year2023::day04::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day04.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -11,7 +11,7 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
 #[test]
 fn parse_example() {
-    let cards = parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let cards = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
 
     assert_eq!(cards.len(), 6);
     assert_eq!(cards[0].winners, vec![41, 48, 83, 86, 17]);
@@ -22,32 +22,32 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Failed to parse example input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Failed to parse example input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 13);
 }
 
 #[test]
 fn part1_real() {
-    let input = parse(PUZZLE_INPUT).expect("Failed to parse example input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Failed to parse example input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 26218);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Failed to parse example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Failed to parse example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 30);
 }
 
 #[test]
 fn part2_real() {
-    let input = parse(PUZZLE_INPUT).expect("Failed to parse example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Failed to parse example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 9997537);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day05::*;
+use advent_of_code::{solver::Solver, year2023::day05::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day05.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -38,7 +38,7 @@ humidity-to-location map:
 
 #[test]
 fn parse_example() {
-    let parsed = parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
 
     assert_eq!(parsed.seeds_pt1, vec![79, 14, 55, 13]);
     assert_eq!(parsed.seeds_pt2, vec![79..93, 55..68]);
@@ -61,32 +61,32 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 35);
 }
 
 #[test]
 fn part1_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing example input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 806029445);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 46);
 }
 
 #[test]
 fn part2_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 59370572);
 }
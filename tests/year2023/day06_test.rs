@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day06::*;
+use advent_of_code::{solver::Solver, year2023::day06::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day06.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -7,7 +7,7 @@ Distance:  9  40  200";
 
 #[test]
 fn parse_test() {
-    let parsed = parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
 
     assert_eq!(
         parsed,
@@ -30,32 +30,32 @@ fn parse_test() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 288);
 }
 
 #[test]
 fn part1_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing example input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 275724);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 71503);
 }
 
 #[test]
 fn part2_real() {
-    let input = parse(PUZZLE_INPUT).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(PUZZLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 37286485);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day07::*;
+use advent_of_code::{solver::Solver, year2023::day07::*};
 use itertools::Itertools;
 
 const EXAMPLE: &str = "\
@@ -10,7 +10,7 @@ QQQJA 483";
 
 #[test]
 fn parse_test() {
-    let parsed = parse(EXAMPLE).expect("Error parsing example input");
+    let parsed = Solution::parse(EXAMPLE).expect("Error parsing example input");
 
     assert_eq!(
         parsed,
@@ -41,16 +41,16 @@ fn parse_test() {
 
 #[test]
 fn part1_test() {
-    let input = parse(EXAMPLE).expect("Error parsing example input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE).expect("Error parsing example input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 6440);
 }
 
 #[test]
 fn part2_test() {
-    let input = parse(EXAMPLE).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 5905);
 }
@@ -78,16 +78,16 @@ JJJJ2 41";
 
 #[test]
 fn part1_edge_case() {
-    let input = parse(EDGE_CASES).expect("Error parsing edge case input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EDGE_CASES).expect("Error parsing edge case input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 6592);
 }
 
 #[test]
 fn part2_edge_case() {
-    let input = parse(EDGE_CASES).expect("Error parsing edge case input");
-    let answer = part2(&input).expect("Error solving part 1");
+    let input = Solution::parse(EDGE_CASES).expect("Error parsing edge case input");
+    let answer = Solution::part2(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 6839);
 }
@@ -99,8 +99,8 @@ AKQT9 5";
 
 #[test]
 fn part2_full_house_edge() {
-    let input = parse(FULL_HOUSE_EDGE).expect("Error parsing input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(FULL_HOUSE_EDGE).expect("Error parsing input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 43);
 }
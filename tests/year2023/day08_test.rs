@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use advent_of_code::year2023::day08::*;
+use advent_of_code::{solver::Solver, year2023::day08::*};
 
 const PART1_EXAMPLE: &str = "\
 RL
@@ -15,7 +15,7 @@ ZZZ = (ZZZ, ZZZ)";
 
 #[test]
 fn parse_test() {
-    let parsed = parse(PART1_EXAMPLE).expect("Error parsing input");
+    let parsed = Solution::parse(PART1_EXAMPLE).expect("Error parsing input");
 
     assert_eq!(parsed.steps, vec!['R', 'L']);
     assert_hashmap(&parsed.network, "AAA", ("BBB", "CCC"));
@@ -36,8 +36,8 @@ fn assert_hashmap(map: &HashMap<String, (String, String)>, k: &str, v: (&str, &s
 
 #[test]
 fn part1_example() {
-    let input = parse(PART1_EXAMPLE).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(PART1_EXAMPLE).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 2);
 }
@@ -45,8 +45,8 @@ fn part1_example() {
 #[test]
 fn part1_real() {
     let input = include_str!("../../input/year2023/day08.txt");
-    let parsed = parse(input).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(input).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(answer, 11911);
 }
@@ -65,8 +65,8 @@ XXX = (XXX, XXX)";
 
 #[test]
 fn part2_example() {
-    let input = parse(PART2_EXAMPLE).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(PART2_EXAMPLE).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 6);
 }
@@ -74,8 +74,8 @@ fn part2_example() {
 #[test]
 fn part2_real() {
     let input = include_str!("../../input/year2023/day08.txt");
-    let parsed = parse(input).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(input).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(answer, 10151663816849);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day09::*;
+use advent_of_code::{solver::Solver, year2023::day09::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day09.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -8,7 +8,7 @@ const EXAMPLE_INPUT: &str = "\
 
 #[test]
 fn parse_example() {
-    let parsed = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     assert_eq!(
         parsed,
@@ -28,7 +28,7 @@ fn parse_example() {
 
 #[test]
 fn parse_neg() {
-    let parsed = parse("0 -1 4 -3 10 -35").expect("Error parsing input");
+    let parsed = Solution::parse("0 -1 4 -3 10 -35").expect("Error parsing input");
 
     assert_eq!(
         parsed,
@@ -40,32 +40,32 @@ fn parse_neg() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 114);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(answer, 1972648895);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 2);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(answer, 919);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::{util::grid::Grid, year2023::day10::*};
+use advent_of_code::{solver::Solver, util::grid::Grid, year2023::day10::*};
 use grid::grid;
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day10.txt");
@@ -41,7 +41,7 @@ L7JLJL-JLJLJL--JLJ.L";
 
 #[test]
 fn parse_example() {
-    let parsed = parse(EXAMPLE_INPUT_PART1_1).expect("Error parsing input");
+    let parsed = Solution::parse(EXAMPLE_INPUT_PART1_1).expect("Error parsing input");
 
     assert_eq!(
         parsed,
@@ -57,48 +57,48 @@ fn parse_example() {
 
 #[test]
 fn part1_example_1() {
-    let input = parse(EXAMPLE_INPUT_PART1_1).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_PART1_1).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 4);
 }
 
 #[test]
 fn part1_example_2() {
-    let input = parse(EXAMPLE_INPUT_PART1_2).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_PART1_2).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 8);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(answer, 7173);
 }
 
 #[test]
 fn part2_example_1() {
-    let input = parse(EXAMPLE_INPUT_PART2_1).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT_PART2_1).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 4);
 }
 
 #[test]
 fn part2_example_2() {
-    let input = parse(EXAMPLE_INPUT_PART2_2).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT_PART2_2).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 10);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(answer, 291);
 }
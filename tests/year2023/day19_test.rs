@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day19::*;
+use advent_of_code::{solver::Solver, year2023::day19::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day19.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -22,7 +22,7 @@ hdj{m>838:A,pv}
 
 #[test]
 fn parse_example() {
-    let (workflows, parts) = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let (workflows, parts) = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     assert_eq!(workflows.len(), 11);
 
@@ -101,32 +101,32 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 19114);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(answer, 362930);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let answer = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(answer, 167409079868000);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(answer, 116365820987729);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day20::*;
+use advent_of_code::{solver::Solver, year2023::day20::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day20.txt");
 const EXAMPLE_INPUT_1: &str = "\
@@ -30,7 +30,7 @@ fn assert_module(
 
 #[test]
 fn parse_example_1() {
-    let parsed = parse(EXAMPLE_INPUT_1).expect("Error parsing input");
+    let parsed = Solution::parse(EXAMPLE_INPUT_1).expect("Error parsing input");
 
     assert_module(
         &parsed,
@@ -70,7 +70,7 @@ fn parse_example_1() {
 
 #[test]
 fn parse_example_2() {
-    let parsed = parse(EXAMPLE_INPUT_2).expect("Error parsing input");
+    let parsed = Solution::parse(EXAMPLE_INPUT_2).expect("Error parsing input");
 
     assert_module(
         &parsed,
@@ -110,32 +110,32 @@ fn parse_example_2() {
 
 #[test]
 fn part1_example_1() {
-    let input = parse(EXAMPLE_INPUT_1).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_1).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 32000000);
 }
 
 #[test]
 fn part1_example_2() {
-    let input = parse(EXAMPLE_INPUT_2).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_2).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(answer, 11687500);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(answer, 886701120);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(answer, 228134431501037);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2023::day21::*;
+use advent_of_code::{solver::Solver, year2023::day21::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day21.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -16,19 +16,19 @@ const EXAMPLE_INPUT: &str = "\
 
 #[test]
 fn parse_example() {
-    let _parsed = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let _parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     todo!("assert_eq!(expected, actual);")
 }
 
 #[test]
 fn part1_example() {
-    let mut input = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let mut input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     // We only have example output for 6 steps
     input.steps_override = Some(6);
 
-    let _answer = part1(&input).expect("Error solving part 1");
+    let _answer = Solution::part1(&input).expect("Error solving part 1");
 
     //assert_eq!(16, answer);
     todo!("assert_eq!(expected, actual);")
@@ -36,24 +36,24 @@ fn part1_example() {
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let _answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let _answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     todo!("assert_eq!(expected, actual);")
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let _answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let _answer = Solution::part2(&input).expect("Error solving part 2");
 
     todo!("assert_eq!(expected, actual);")
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let _answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let _answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     todo!("assert_eq!(expected, actual);")
 }
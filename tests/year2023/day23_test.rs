@@ -1,4 +1,4 @@
-use advent_of_code::{util::grid::Grid, year2023::day23::*};
+use advent_of_code::{solver::Solver, util::grid::Grid, year2023::day23::*};
 use grid::grid;
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2023/day23.txt");
@@ -29,7 +29,7 @@ const EXAMPLE_INPUT: &str = "\
 
 #[test]
 fn parse_example() {
-    let parsed = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let parsed = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     assert_eq!(
         parsed,
@@ -63,24 +63,24 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let answer = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(94, answer);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let answer = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let answer = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(2134, answer);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let _answer = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let _answer = Solution::part2(&input).expect("Error solving part 2");
 
     //assert_eq!(154, answer);
     todo!("assert_eq!(expected, actual);")
@@ -88,8 +88,8 @@ fn part2_example() {
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let _answer = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let _answer = Solution::part2(&parsed).expect("Error solving part 2");
 
     todo!("assert_eq!(expected, actual);")
 }
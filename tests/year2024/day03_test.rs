@@ -1,4 +1,4 @@
-use advent_of_code::year2024::day03::*;
+use advent_of_code::{solver::Solver, year2024::day03::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day03.txt");
 const EXAMPLE_INPUT_PART1: &str =
@@ -8,39 +8,39 @@ const EXAMPLE_INPUT_PART2: &str =
 
 #[test]
 fn parse_example() {
-    let actual = parse(EXAMPLE_INPUT_PART1).expect("Error parsing input");
+    let actual = Solution::parse(EXAMPLE_INPUT_PART1).expect("Error parsing input");
 
     assert_eq!(EXAMPLE_INPUT_PART1.to_string(), actual);
 }
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT_PART1).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_PART1).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(161, actual);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(188192787, actual);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT_PART2).expect("Error parsing example input");
-    let actual = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT_PART2).expect("Error parsing example input");
+    let actual = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(48, actual);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(113965544, actual);
 }
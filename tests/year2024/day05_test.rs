@@ -1,4 +1,4 @@
-use advent_of_code::year2024::day05::*;
+use advent_of_code::{solver::Solver, year2024::day05::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day05.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -33,7 +33,7 @@ const EXAMPLE_INPUT: &str = "\
 
 #[test]
 fn parse_example() {
-    let (rules, updates) = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let (rules, updates) = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     use Order::*;
     assert!(rules.get(&47).is_some());
@@ -89,32 +89,32 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(143, actual);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(4766, actual);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let actual = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let actual = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(123, actual);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let _actual = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let _actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     //assert_eq!(expected, actual);
 }
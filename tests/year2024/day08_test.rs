@@ -1,4 +1,4 @@
-use advent_of_code::year2024::day08::*;
+use advent_of_code::{solver::Solver, util::pos::Pos, year2024::day08::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day08.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -17,48 +17,59 @@ const EXAMPLE_INPUT: &str = "\
 
 #[test]
 fn parse_example() {
-    let (rows, cols, antenna) = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let (rows, cols, antenna) = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     assert_eq!(12, rows);
     assert_eq!(12, cols);
 
     let zero_antenna = antenna.get(&'0');
     assert!(zero_antenna.is_some());
-    assert_eq!(&vec![(1, 8), (2, 5), (3, 7), (4, 4)], zero_antenna.unwrap());
+    assert_eq!(
+        &vec![
+            Pos::new(1, 8),
+            Pos::new(2, 5),
+            Pos::new(3, 7),
+            Pos::new(4, 4)
+        ],
+        zero_antenna.unwrap()
+    );
 
     let a_antenna = antenna.get(&'A');
     assert!(a_antenna.is_some());
-    assert_eq!(&vec![(5, 6), (8, 8), (9, 9)], a_antenna.unwrap());
+    assert_eq!(
+        &vec![Pos::new(5, 6), Pos::new(8, 8), Pos::new(9, 9)],
+        a_antenna.unwrap()
+    );
 }
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(14, actual);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(344, actual);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let actual = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let actual = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(34, actual);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(1182, actual);
 }
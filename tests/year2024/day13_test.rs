@@ -1,4 +1,4 @@
-use advent_of_code::year2024::day13::*;
+use advent_of_code::{solver::Solver, year2024::day13::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day13.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -20,7 +20,7 @@ Prize: X=18641, Y=10279";
 
 #[test]
 fn parse_example() {
-    let actual = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
     let expected = vec![
         Game::new((94, 34), (22, 67), (8400, 5400)),
         Game::new((26, 66), (67, 21), (12748, 12176)),
@@ -33,16 +33,16 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(480, actual);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(29436, actual);
 }
@@ -50,16 +50,16 @@ fn part1_real() {
 // No example answer given
 // #[test]
 // fn part2_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-//     let _actual = part2(&input).expect("Error solving part 2");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+//     let _actual = Solution::part2(&input).expect("Error solving part 2");
 
 //     //assert_eq!(expected, actual);
 // }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(103729094227877, actual);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2024::day14::*;
+use advent_of_code::{solver::Solver, year2024::day14::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day14.txt");
 // The "example" line is to cater for this comment in the puzzle:
@@ -20,7 +20,7 @@ p=9,5 v=-3,-3";
 
 #[test]
 fn parse_example() {
-    let actual = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
     let expected = (
         vec![
             Guard::new((0, 4).into(), (3, -3).into()),
@@ -45,16 +45,16 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(12, actual);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(229868730, actual);
 }
@@ -62,16 +62,16 @@ fn part1_real() {
 // No example provided
 // #[test]
 // fn part2_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-//     let _actual = part2(&input).expect("Error solving part 2");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+//     let _actual = Solution::part2(&input).expect("Error solving part 2");
 
 //     //assert_eq!(expected, actual);
 // }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(7861, actual);
 }
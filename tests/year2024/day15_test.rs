@@ -1,4 +1,4 @@
-use advent_of_code::{util::direction::Direction, year2024::day15::*};
+use advent_of_code::{solver::Solver, util::direction::Direction, year2024::day15::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day15.txt");
 const EXAMPLE_INPUT_1: &str = "\
@@ -87,7 +87,7 @@ const EDGE_CASE: &str = "\
 fn parse_example_1() {
     use Direction::*;
 
-    let (map, steps) = parse(EXAMPLE_INPUT_1).expect("Error parsing example 1 input");
+    let (map, steps) = Solution::parse(EXAMPLE_INPUT_1).expect("Error parsing example 1 input");
     assert_eq!(Some(&'#'), map.get(0, 0));
     assert_eq!(Some(&'O'), map.get(1, 3));
     assert_eq!(Some(&'@'), map.get(2, 2));
@@ -105,7 +105,7 @@ fn parse_example_1() {
 fn parse_example_2() {
     use Direction::*;
 
-    let (map, steps) = parse(EXAMPLE_INPUT_2).expect("Error parsing example 2 input");
+    let (map, steps) = Solution::parse(EXAMPLE_INPUT_2).expect("Error parsing example 2 input");
     assert_eq!(Some(&'#'), map.get(0, 0));
     assert_eq!(Some(&'O'), map.get(1, 3));
     assert_eq!(Some(&'@'), map.get(4, 4));
@@ -127,48 +127,48 @@ fn parse_example_2() {
 
 #[test]
 fn part1_example_1() {
-    let input = parse(EXAMPLE_INPUT_1).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_1).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(2028, actual);
 }
 
 #[test]
 fn part1_example_2() {
-    let input = parse(EXAMPLE_INPUT_2).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_2).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(10092, actual);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(1526673, actual);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT_2).expect("Error parsing example input");
-    let actual = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT_2).expect("Error parsing example input");
+    let actual = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(9021, actual);
 }
 
 #[test]
 fn part2_edge_case() {
-    let input = parse(EDGE_CASE).expect("Error parsing example input");
-    let actual = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EDGE_CASE).expect("Error parsing example input");
+    let actual = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(2557, actual);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(1535509, actual);
 }
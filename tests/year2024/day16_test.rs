@@ -1,4 +1,4 @@
-use advent_of_code::year2024::day16::*;
+use advent_of_code::{solver::Solver, year2024::day16::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day16.txt");
 const EXAMPLE_INPUT_1: &str = "\
@@ -41,7 +41,7 @@ const EXAMPLE_INPUT_2: &str = "\
 
 #[test]
 fn parse_example_1() {
-    let actual = parse(EXAMPLE_INPUT_1).expect("Error parsing input");
+    let actual = Solution::parse(EXAMPLE_INPUT_1).expect("Error parsing input");
 
     assert_eq!(Some(&'#'), actual.get(0, 0));
     assert_eq!(Some(&'S'), actual.get(13, 1));
@@ -50,7 +50,7 @@ fn parse_example_1() {
 
 #[test]
 fn parse_example_2() {
-    let actual = parse(EXAMPLE_INPUT_2).expect("Error parsing input");
+    let actual = Solution::parse(EXAMPLE_INPUT_2).expect("Error parsing input");
 
     assert_eq!(Some(&'#'), actual.get(0, 0));
     assert_eq!(Some(&'S'), actual.get(15, 1));
@@ -59,24 +59,24 @@ fn parse_example_2() {
 
 #[test]
 fn part1_example_1() {
-    let input = parse(EXAMPLE_INPUT_1).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_1).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(7036, actual);
 }
 
 #[test]
 fn part1_example_2() {
-    let input = parse(EXAMPLE_INPUT_2).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT_2).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(11048, actual);
 }
 
 #[test]
 fn part1_real() {
-    let _parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    //let _actual = part1(&parsed).expect("Error solving part 1");
+    let _parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    //let _actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     // Runs in about 5 mins...
 
@@ -85,24 +85,24 @@ fn part1_real() {
 
 #[test]
 fn part2_example_1() {
-    let input = parse(EXAMPLE_INPUT_1).expect("Error parsing example input");
-    let actual = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT_1).expect("Error parsing example input");
+    let actual = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(45, actual);
 }
 
 #[test]
 fn part2_example_2() {
-    let input = parse(EXAMPLE_INPUT_2).expect("Error parsing example input");
-    let actual = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT_2).expect("Error parsing example input");
+    let actual = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!(64, actual);
 }
 
 #[test]
 fn part2_real() {
-    let _parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    //let _actual = part2(&parsed).expect("Error solving part 2");
+    let _parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    //let _actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     // Runs in about 11 mins...
 
@@ -1,4 +1,4 @@
-use advent_of_code::{util::point::Point, year2024::day18::*};
+use advent_of_code::{solver::Solver, util::point::Point, year2024::day18::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day18.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -31,7 +31,7 @@ example
 
 #[test]
 fn parse_example() {
-    let (grid, byte_locs, num_bytes) = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let (grid, byte_locs, num_bytes) = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     assert_eq!(7, grid.rows());
     assert_eq!(7, grid.cols());
@@ -71,32 +71,32 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(22, actual);
 }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(316, actual);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let actual = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let actual = Solution::part2(&input).expect("Error solving part 2");
 
     assert_eq!("6,1".to_string(), actual);
 }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!("45,18".to_string(), actual);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2024::day20::*;
+use advent_of_code::{solver::Solver, year2024::day20::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day20.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -20,7 +20,7 @@ const EXAMPLE_INPUT: &str = "\
 
 #[test]
 fn parse_example() {
-    let actual = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     assert_eq!(Some(&'#'), actual.get(0, 0));
     assert_eq!(Some(&'S'), actual.get(3, 1));
@@ -31,16 +31,16 @@ fn parse_example() {
 // No test example for 100 steps saved
 // #[test]
 // fn part1_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-//     let actual = part1(&input).expect("Error solving part 1");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+//     let actual = Solution::part1(&input).expect("Error solving part 1");
 
 //     assert_eq!(0, actual);
 // }
 
 #[test]
 fn part1_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part1(&parsed).expect("Error solving part 1");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     assert_eq!(1346, actual);
 }
@@ -48,16 +48,16 @@ fn part1_real() {
 // No test example for 100 steps saved
 // #[test]
 // fn part2_example() {
-//     let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-//     let actual = part2(&input).expect("Error solving part 2");
+//     let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+//     let actual = Solution::part2(&input).expect("Error solving part 2");
 
 //     assert_eq!(0, actual);
 // }
 
 #[test]
 fn part2_real() {
-    let parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    let actual = part2(&parsed).expect("Error solving part 2");
+    let parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     assert_eq!(985482, actual);
 }
@@ -1,4 +1,4 @@
-use advent_of_code::year2024::day21::*;
+use advent_of_code::{solver::Solver, year2024::day21::*};
 
 const PUZZLE_INPUT: &str = include_str!("../../input/year2024/day21.txt");
 const EXAMPLE_INPUT: &str = "\
@@ -10,7 +10,7 @@ const EXAMPLE_INPUT: &str = "\
 
 #[test]
 fn parse_example() {
-    let actual = parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
 
     assert_eq!(
         vec![
@@ -26,32 +26,32 @@ fn parse_example() {
 
 #[test]
 fn part1_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing input");
-    let actual = part1(&input).expect("Error solving part 1");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing input");
+    let actual = Solution::part1(&input).expect("Error solving part 1");
 
     assert_eq!(126384, actual);
 }
 
 #[test]
 fn part1_real() {
-    let _parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    //let _actual = part1(&parsed).expect("Error solving part 1");
+    let _parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    //let _actual = Solution::part1(&parsed).expect("Error solving part 1");
 
     //assert_eq!(expected, actual);
 }
 
 #[test]
 fn part2_example() {
-    let input = parse(EXAMPLE_INPUT).expect("Error parsing example input");
-    let _actual = part2(&input).expect("Error solving part 2");
+    let input = Solution::parse(EXAMPLE_INPUT).expect("Error parsing example input");
+    let _actual = Solution::part2(&input).expect("Error solving part 2");
 
     //assert_eq!(expected, actual);
 }
 
 #[test]
 fn part2_real() {
-    let _parsed = parse(PUZZLE_INPUT).expect("Error parsing input");
-    //let _actual = part2(&parsed).expect("Error solving part 2");
+    let _parsed = Solution::parse(PUZZLE_INPUT).expect("Error parsing input");
+    //let _actual = Solution::part2(&parsed).expect("Error solving part 2");
 
     //assert_eq!(expected, actual);
 }